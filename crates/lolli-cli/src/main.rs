@@ -1,13 +1,16 @@
 //! Lolli - Linear Logic Workbench CLI
 //!
 //! A toolkit for working with linear logic — parsing formulas, searching for proofs,
-//! extracting computational content, and compiling to Rust.
+//! extracting and running computational content, compiling to Rust, and
+//! checking whole problem files of named axioms, lemmas, and assertions.
+
+use std::collections::BTreeMap;
 
 use clap::{Parser, Subcommand};
 use colored::Colorize;
-use lolli_extract::{extract_term, normalize};
-use lolli_parse::{parse_formula, parse_sequent};
-use lolli_prove::Prover;
+use lolli_extract::{extract_term, normalize, parse_value, run_term};
+use lolli_parse::{parse_formula, parse_problem, parse_sequent, ParseError, StatementBody, StatementKind};
+use lolli_prove::{ProofResult, Prover};
 
 #[derive(Parser)]
 #[command(name = "lolli")]
@@ -69,6 +72,20 @@ enum Commands {
         output: Option<String>,
     },
 
+    /// Run the extracted term against concrete input values
+    Run {
+        /// Sequent to prove
+        sequent: String,
+
+        /// An input value, in antecedent order (e.g. "3", "(1, !2)", "inl ()")
+        #[arg(long = "arg")]
+        arg: Vec<String>,
+
+        /// Maximum search depth
+        #[arg(short, long, default_value = "100")]
+        depth: usize,
+    },
+
     /// Visualize a proof
     Viz {
         /// Sequent to prove
@@ -85,6 +102,16 @@ enum Commands {
 
     /// Run interactive REPL
     Repl,
+
+    /// Check a problem file of named axioms, lemmas, and assertions
+    Check {
+        /// Path to the problem file
+        file: String,
+
+        /// Maximum search depth
+        #[arg(short, long, default_value = "100")]
+        depth: usize,
+    },
 }
 
 fn main() {
@@ -141,7 +168,7 @@ fn main() {
                     );
                 }
                 Err(e) => {
-                    eprintln!("{} {}", "Error:".red().bold(), e);
+                    print_parse_error(&e, &formula);
                     std::process::exit(1);
                 }
             }
@@ -167,7 +194,7 @@ fn main() {
                     let mut prover = Prover::new(depth);
 
                     match prover.prove(&one_sided) {
-                        Some(proof) => {
+                        ProofResult::Proven(proof) => {
                             println!("{}", "✓ PROVABLE".green().bold());
                             println!();
                             println!("{}", "Proof:".cyan().bold());
@@ -187,14 +214,19 @@ fn main() {
                             println!("{} {}", "Depth:".yellow(), proof.depth());
                             println!("{} {}", "Cut count:".yellow(), proof.cut_count());
                         }
-                        None => {
-                            println!("{}", "✗ NOT PROVABLE".red().bold());
-                            println!("  (within depth limit of {})", depth);
+                        ProofResult::Disproven => {
+                            println!("{}", "✗ REFUTED".red().bold());
+                            println!("  (no proof exists for this sequent)");
+                        }
+                        ProofResult::Unknown => {
+                            println!("{}", "? UNKNOWN".yellow().bold());
+                            println!("  search hit the depth limit of {} before ruling out every branch", depth);
+                            println!("  (try raising --depth)");
                         }
                     }
                 }
                 Err(e) => {
-                    eprintln!("{} {}", "Error:".red().bold(), e);
+                    print_parse_error(&e, &sequent);
                     std::process::exit(1);
                 }
             }
@@ -212,7 +244,7 @@ fn main() {
                     let mut prover = Prover::new(100);
 
                     match prover.prove(&one_sided) {
-                        Some(proof) => {
+                        ProofResult::Proven(proof) => {
                             println!("{}", "✓ Provable".green());
                             println!();
 
@@ -229,14 +261,18 @@ fn main() {
                                 println!("  {}", normalized.pretty());
                             }
                         }
-                        None => {
-                            println!("{}", "✗ NOT PROVABLE".red().bold());
-                            println!("  Cannot extract term from unprovable sequent");
+                        ProofResult::Disproven => {
+                            println!("{}", "✗ REFUTED".red().bold());
+                            println!("  Cannot extract term: no proof exists for this sequent");
+                        }
+                        ProofResult::Unknown => {
+                            println!("{}", "? UNKNOWN".yellow().bold());
+                            println!("  search hit the depth limit before ruling out every branch");
                         }
                     }
                 }
                 Err(e) => {
-                    eprintln!("{} {}", "Error:".red().bold(), e);
+                    print_parse_error(&e, &sequent);
                     std::process::exit(1);
                 }
             }
@@ -254,7 +290,7 @@ fn main() {
                     let mut prover = Prover::new(100);
 
                     match prover.prove(&one_sided) {
-                        Some(proof) => {
+                        ProofResult::Proven(proof) => {
                             println!("{}", "✓ Provable".green());
                             println!();
 
@@ -292,14 +328,80 @@ fn main() {
                                 }
                             }
                         }
-                        None => {
-                            println!("{}", "✗ NOT PROVABLE".red().bold());
-                            println!("  Cannot generate code from unprovable sequent");
+                        ProofResult::Disproven => {
+                            println!("{}", "✗ REFUTED".red().bold());
+                            println!("  Cannot generate code: no proof exists for this sequent");
+                        }
+                        ProofResult::Unknown => {
+                            println!("{}", "? UNKNOWN".yellow().bold());
+                            println!("  search hit the depth limit before ruling out every branch");
+                        }
+                    }
+                }
+                Err(e) => {
+                    print_parse_error(&e, &sequent);
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        Commands::Run { sequent, arg, depth } => {
+            match parse_sequent(&sequent) {
+                Ok(s) => {
+                    println!("{}", "Sequent:".green().bold());
+                    println!("  {}", s.pretty());
+                    println!();
+
+                    // Convert to one-sided and prove
+                    let one_sided = s.to_one_sided();
+                    let mut prover = Prover::new(depth);
+
+                    match prover.prove(&one_sided) {
+                        ProofResult::Proven(proof) => {
+                            println!("{}", "✓ Provable".green());
+                            println!();
+
+                            // Extract and normalize the computational content
+                            let term = extract_term(&proof);
+                            let term = normalize(&term);
+
+                            let mut inputs = Vec::new();
+                            let mut bad_arg = false;
+                            for raw in &arg {
+                                match parse_value(raw) {
+                                    Ok(value) => inputs.push(value),
+                                    Err(e) => {
+                                        eprintln!(
+                                            "{} invalid --arg {:?}: {}",
+                                            "Error:".red().bold(),
+                                            raw,
+                                            e
+                                        );
+                                        bad_arg = true;
+                                    }
+                                }
+                            }
+                            if bad_arg {
+                                std::process::exit(1);
+                            }
+
+                            let result = run_term(&term, inputs);
+
+                            println!("{}", "Result:".cyan().bold());
+                            println!("  {}", result.pretty());
+                        }
+                        ProofResult::Disproven => {
+                            println!("{}", "✗ REFUTED".red().bold());
+                            println!("  Cannot run: no proof exists for this sequent");
+                        }
+                        ProofResult::Unknown => {
+                            println!("{}", "? UNKNOWN".yellow().bold());
+                            println!("  search hit the depth limit before ruling out every branch");
                         }
                     }
                 }
                 Err(e) => {
-                    eprintln!("{} {}", "Error:".red().bold(), e);
+                    print_parse_error(&e, &sequent);
                     std::process::exit(1);
                 }
             }
@@ -321,20 +423,17 @@ fn main() {
                     let mut prover = Prover::new(100);
 
                     match prover.prove(&one_sided) {
-                        Some(proof) => {
+                        ProofResult::Proven(proof) => {
                             println!("{}", "✓ Provable".green());
                             println!();
 
                             // Generate visualization
-                            use lolli_viz::{render_ascii, render_latex, render_dot};
+                            use lolli_viz::{render_ascii, render_latex, render_dot, render_svg};
 
                             let viz = match format.as_str() {
                                 "latex" => render_latex(&proof),
                                 "dot" => render_dot(&proof),
-                                "svg" => {
-                                    println!("{}", "SVG output not yet implemented".yellow());
-                                    render_dot(&proof) // Fall back to DOT
-                                }
+                                "svg" => render_svg(&proof),
                                 _ => render_ascii(&proof),
                             };
 
@@ -355,14 +454,18 @@ fn main() {
                                 }
                             }
                         }
-                        None => {
-                            println!("{}", "✗ NOT PROVABLE".red().bold());
-                            println!("  Cannot visualize unprovable sequent");
+                        ProofResult::Disproven => {
+                            println!("{}", "✗ REFUTED".red().bold());
+                            println!("  Cannot visualize: no proof exists for this sequent");
+                        }
+                        ProofResult::Unknown => {
+                            println!("{}", "? UNKNOWN".yellow().bold());
+                            println!("  search hit the depth limit before ruling out every branch");
                         }
                     }
                 }
                 Err(e) => {
-                    eprintln!("{} {}", "Error:".red().bold(), e);
+                    print_parse_error(&e, &sequent);
                     std::process::exit(1);
                 }
             }
@@ -371,10 +474,126 @@ fn main() {
         Commands::Repl => {
             run_repl();
         }
+
+        Commands::Check { file, depth } => {
+            run_check(&file, depth);
+        }
+    }
+}
+
+use lolli_core::{Formula, Proof, TwoSidedSequent};
+
+/// Print a parse error as a multi-line report: the message, the offending
+/// line of `input`, and a caret underline beneath the span.
+fn print_parse_error(e: &ParseError, input: &str) {
+    eprintln!("{} {}", "Error:".red().bold(), e.render(input));
+}
+
+/// Run the `check` subcommand: parse a problem file and prove each lemma
+/// and assertion in order, threading proven axioms and lemmas through as
+/// `!`-banged hypotheses for the statements that follow.
+///
+/// Each statement's outcome is `Prover`'s own three-way `ProofResult`, not
+/// a plain pass/fail: `Unknown` (search hit `depth` before it could rule
+/// out every branch) is reported and counted separately from `Disproven`
+/// (search genuinely exhausted every rule on every branch), since raising
+/// `--depth` can only ever change the former.
+fn run_check(path: &str, depth: usize) {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("{} failed to read {}: {}", "Error:".red().bold(), path, e);
+            std::process::exit(1);
+        }
+    };
+
+    let problem = match parse_problem(&contents) {
+        Ok(problem) => problem,
+        Err(e) => {
+            print_parse_error(&e, &contents);
+            std::process::exit(1);
+        }
+    };
+
+    let mut context: Vec<Formula> = Vec::new();
+    let mut pass_count = 0;
+    let mut fail_count = 0;
+    let mut unknown_count = 0;
+
+    for statement in &problem.statements {
+        let label = statement.name.as_deref().unwrap_or("<unnamed>");
+
+        let sequent = match &statement.body {
+            StatementBody::Formula(formula) => {
+                println!("{} {} := {}", "definition".magenta().bold(), label, formula.pretty());
+                continue;
+            }
+            StatementBody::Sequent(sequent) => sequent,
+        };
+
+        if statement.kind == StatementKind::Axiom {
+            println!("{} {}: {}", "axiom".yellow().bold(), label, sequent.pretty());
+            context.push(Formula::of_course(sequent_as_formula(sequent)));
+            continue;
+        }
+
+        let mut antecedent = context.clone();
+        antecedent.extend(sequent.antecedent.iter().cloned());
+        let with_context = TwoSidedSequent::new(antecedent, sequent.succedent.clone());
+        let one_sided = with_context.to_one_sided();
+
+        let mut prover = Prover::new(depth);
+        match prover.prove(&one_sided) {
+            ProofResult::Proven(_proof) => {
+                pass_count += 1;
+                println!("{} {}: {}", "PASS".green().bold(), label, sequent.pretty());
+                if statement.kind == StatementKind::Lemma {
+                    context.push(Formula::of_course(sequent_as_formula(sequent)));
+                }
+            }
+            ProofResult::Disproven => {
+                fail_count += 1;
+                println!("{} {}: {}", "FAIL".red().bold(), label, sequent.pretty());
+            }
+            ProofResult::Unknown => {
+                unknown_count += 1;
+                println!("{} {}: {} (search hit the depth limit)", "UNKNOWN".yellow().bold(), label, sequent.pretty());
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "{} {} passed, {} failed, {} unknown",
+        "Summary:".cyan().bold(),
+        pass_count,
+        fail_count,
+        unknown_count
+    );
+
+    if fail_count > 0 || unknown_count > 0 {
+        std::process::exit(1);
     }
 }
 
-use lolli_core::Proof;
+/// Fold a two-sided sequent's antecedent and succedent into a single
+/// formula `A1 * ... * An -o B1 par ... par Bm`, so a proven axiom or lemma
+/// can be injected as a `!`-banged hypothesis for the statements after it.
+fn sequent_as_formula(sequent: &TwoSidedSequent) -> Formula {
+    let lhs = sequent
+        .antecedent
+        .iter()
+        .cloned()
+        .reduce(Formula::tensor)
+        .unwrap_or(Formula::One);
+    let rhs = sequent
+        .succedent
+        .iter()
+        .cloned()
+        .reduce(Formula::par)
+        .unwrap_or(Formula::Bottom);
+    Formula::lolli(lhs, rhs)
+}
 
 /// Print a proof tree in ASCII format
 fn print_proof_tree(proof: &Proof, indent: usize) {
@@ -461,6 +680,43 @@ fn proof_to_dot_inner(proof: &Proof, lines: &mut Vec<String>, counter: &mut usiz
     my_id
 }
 
+/// Session state for the REPL: formulas bound with `:let` and lemmas proven
+/// with `:lemma`, accumulated across lines so later input can build on
+/// earlier input.
+#[derive(Default)]
+struct ReplSession {
+    /// Formulas bound with `:let NAME = FORMULA`.
+    definitions: BTreeMap<String, Formula>,
+    /// Lemmas proven with `:lemma NAME : SEQUENT`, in proof order, each
+    /// paired with the (unbanged) formula its sequent corresponds to.
+    lemmas: Vec<(String, Formula)>,
+}
+
+impl ReplSession {
+    /// Replace any atom bound by a previous `:let` with its formula (a
+    /// negated occurrence with the formula's negation).
+    fn expand(&self, formula: &Formula) -> Formula {
+        let map: std::collections::HashMap<String, Formula> = self
+            .definitions
+            .iter()
+            .map(|(name, def)| (name.clone(), def.clone()))
+            .collect();
+        formula.substitute(&map)
+    }
+
+    /// Expand every formula in a two-sided sequent.
+    fn expand_sequent(&self, sequent: &TwoSidedSequent) -> TwoSidedSequent {
+        let antecedent = sequent.antecedent.iter().map(|f| self.expand(f)).collect();
+        let succedent = sequent.succedent.iter().map(|f| self.expand(f)).collect();
+        TwoSidedSequent::new(antecedent, succedent)
+    }
+
+    /// The `!`-banged hypotheses contributed by every lemma proven so far.
+    fn context(&self) -> Vec<Formula> {
+        self.lemmas.iter().map(|(_, f)| Formula::of_course(f.clone())).collect()
+    }
+}
+
 /// Run the interactive REPL.
 fn run_repl() {
     use std::io::{self, Write};
@@ -472,12 +728,17 @@ fn run_repl() {
     println!("Commands:");
     println!("  {}       - Parse and analyze a formula", "formula".green());
     println!("  {}    - Prove a sequent (e.g., A, B |- A * B)", "seq |-".green());
+    println!("  {}  - Bind NAME to FORMULA", ":let NAME = FORMULA".green());
+    println!("  {}      - Prove SEQUENT and keep it as a reusable lemma", ":lemma NAME : SEQUENT".green());
+    println!("  {}           - List bindings and lemmas", ":env".yellow());
+    println!("  {}         - Reset bindings and lemmas", ":clear".yellow());
     println!("  {}           - Show this help", ":help".yellow());
     println!("  {}           - Exit the REPL", ":quit".yellow());
     println!();
 
     let stdin = io::stdin();
     let mut stdout = io::stdout();
+    let mut session = ReplSession::default();
 
     loop {
         print!("{} ", "lolli>".cyan().bold());
@@ -500,17 +761,30 @@ fn run_repl() {
 
         // Handle commands
         if input.starts_with(':') {
-            match input {
-                ":quit" | ":q" | ":exit" => {
-                    println!("{}", "Goodbye!".green());
-                    break;
-                }
-                ":help" | ":h" | ":?" => {
-                    print_repl_help();
-                }
-                _ => {
-                    println!("{} Unknown command: {}", "Error:".red(), input);
-                    println!("Type {} for help", ":help".yellow());
+            if let Some(rest) = input.strip_prefix(":let ") {
+                handle_let(rest, &mut session);
+            } else if let Some(rest) = input.strip_prefix(":lemma ") {
+                handle_lemma(rest, &mut session);
+            } else {
+                match input {
+                    ":quit" | ":q" | ":exit" => {
+                        println!("{}", "Goodbye!".green());
+                        break;
+                    }
+                    ":help" | ":h" | ":?" => {
+                        print_repl_help();
+                    }
+                    ":env" => {
+                        print_env(&session);
+                    }
+                    ":clear" => {
+                        session = ReplSession::default();
+                        println!("{}", "Environment cleared.".green());
+                    }
+                    _ => {
+                        println!("{} Unknown command: {}", "Error:".red(), input);
+                        println!("Type {} for help", ":help".yellow());
+                    }
                 }
             }
             continue;
@@ -518,9 +792,9 @@ fn run_repl() {
 
         // Check if it's a sequent (contains |- or ⊢)
         if input.contains("|-") || input.contains("⊢") {
-            handle_sequent(input);
+            handle_sequent(input, &session);
         } else {
-            handle_formula(input);
+            handle_formula(input, &session);
         }
     }
 }
@@ -546,6 +820,12 @@ fn print_repl_help() {
     println!("  A, B |- C         Two-sided sequent");
     println!("  |- A, B           One-sided sequent");
     println!();
+    println!("{}", "Session:".yellow());
+    println!("  :let NAME = A * B        Bind NAME, expanded in later input");
+    println!("  :lemma dup : !A |- A*A   Prove a sequent and keep it as a lemma");
+    println!("  :env                     List current bindings and lemmas");
+    println!("  :clear                   Forget all bindings and lemmas");
+    println!();
     println!("{}", "Examples:".yellow());
     println!("  A -o B            Parse a formula");
     println!("  A, B |- A * B     Prove tensor introduction");
@@ -553,9 +833,99 @@ fn print_repl_help() {
     println!();
 }
 
-fn handle_formula(input: &str) {
+/// List the REPL session's current `:let` bindings and proven `:lemma`s.
+fn print_env(session: &ReplSession) {
+    println!();
+    println!("{}", "Definitions:".yellow());
+    if session.definitions.is_empty() {
+        println!("  (none)");
+    } else {
+        for (name, formula) in &session.definitions {
+            println!("  {} = {}", name, formula.pretty());
+        }
+    }
+    println!();
+    println!("{}", "Lemmas:".yellow());
+    if session.lemmas.is_empty() {
+        println!("  (none)");
+    } else {
+        for (name, formula) in &session.lemmas {
+            println!("  {}: {}", name, formula.pretty());
+        }
+    }
+    println!();
+}
+
+/// Handle `:let NAME = FORMULA`: bind `NAME` to the parsed (and
+/// previously-bound-name-expanded) formula.
+fn handle_let(rest: &str, session: &mut ReplSession) {
+    let (name, formula_text) = match rest.split_once('=') {
+        Some((name, formula_text)) => (name.trim(), formula_text.trim()),
+        None => {
+            println!("{} expected :let NAME = FORMULA", "Error:".red().bold());
+            return;
+        }
+    };
+
+    match parse_formula(formula_text) {
+        Ok(formula) => {
+            let expanded = session.expand(&formula);
+            println!("{} {} = {}", "let".cyan(), name, expanded.pretty());
+            session.definitions.insert(name.to_string(), expanded);
+        }
+        Err(e) => println!("{} {}", "Parse error:".red(), e.render(formula_text)),
+    }
+}
+
+/// Handle `:lemma NAME : SEQUENT`: prove the sequent (with previously
+/// proven lemmas available as `!`-hypotheses) and, if it holds, keep it
+/// as a lemma available to later statements.
+fn handle_lemma(rest: &str, session: &mut ReplSession) {
+    let (name, sequent_text) = match rest.split_once(':') {
+        Some((name, sequent_text)) => (name.trim(), sequent_text.trim()),
+        None => {
+            println!("{} expected :lemma NAME : SEQUENT", "Error:".red().bold());
+            return;
+        }
+    };
+
+    let sequent = match parse_sequent(sequent_text) {
+        Ok(sequent) => session.expand_sequent(&sequent),
+        Err(e) => {
+            println!("{} {}", "Parse error:".red(), e.render(sequent_text));
+            return;
+        }
+    };
+
+    let mut antecedent = session.context();
+    antecedent.extend(sequent.antecedent.iter().cloned());
+    let with_context = TwoSidedSequent::new(antecedent, sequent.succedent.clone());
+    let one_sided = with_context.to_one_sided();
+
+    let mut prover = Prover::new(100);
+    match prover.prove(&one_sided) {
+        ProofResult::Proven(_proof) => {
+            println!("{} {}: {}", "PASS".green().bold(), name, sequent.pretty());
+            session.lemmas.push((name.to_string(), sequent_as_formula(&sequent)));
+        }
+        ProofResult::Disproven => {
+            println!("{} {}: {}", "FAIL".red().bold(), name, sequent.pretty());
+        }
+        ProofResult::Unknown => {
+            println!(
+                "{} {}: {} (search hit the depth limit)",
+                "UNKNOWN".yellow().bold(),
+                name,
+                sequent.pretty()
+            );
+        }
+    }
+}
+
+fn handle_formula(input: &str, session: &ReplSession) {
     match parse_formula(input) {
         Ok(f) => {
+            let f = session.expand(&f);
             println!();
             println!("{} {}", "Parsed:".green(), f.pretty());
             println!("{} {}", "Desugared:".cyan(), f.desugar().pretty());
@@ -567,22 +937,26 @@ fn handle_formula(input: &str) {
             println!();
         }
         Err(e) => {
-            println!("{} {}", "Parse error:".red(), e);
+            println!("{} {}", "Parse error:".red(), e.render(input));
         }
     }
 }
 
-fn handle_sequent(input: &str) {
+fn handle_sequent(input: &str, session: &ReplSession) {
     match parse_sequent(input) {
         Ok(s) => {
+            let s = session.expand_sequent(&s);
             println!();
             println!("{} {}", "Sequent:".green(), s.pretty());
 
-            let one_sided = s.to_one_sided();
+            let mut antecedent = session.context();
+            antecedent.extend(s.antecedent.iter().cloned());
+            let with_context = TwoSidedSequent::new(antecedent, s.succedent.clone());
+            let one_sided = with_context.to_one_sided();
             let mut prover = Prover::new(100);
 
             match prover.prove(&one_sided) {
-                Some(proof) => {
+                ProofResult::Proven(proof) => {
                     println!("{}", "✓ PROVABLE".green().bold());
                     println!();
 
@@ -602,15 +976,20 @@ fn handle_sequent(input: &str) {
                     }
                     println!();
                 }
-                None => {
-                    println!("{}", "✗ NOT PROVABLE".red().bold());
+                ProofResult::Disproven => {
+                    println!("{}", "✗ REFUTED".red().bold());
                     println!("  (in linear logic without contraction/weakening)");
                     println!();
                 }
+                ProofResult::Unknown => {
+                    println!("{}", "? UNKNOWN".yellow().bold());
+                    println!("  search hit the depth limit before ruling out every branch");
+                    println!();
+                }
             }
         }
         Err(e) => {
-            println!("{} {}", "Parse error:".red(), e);
+            println!("{} {}", "Parse error:".red(), e.render(input));
         }
     }
 }
@@ -3,6 +3,68 @@
 //! This module provides the [`Formula`] enum representing linear logic formulas
 //! with all standard connectives.
 
+use std::collections::{BTreeSet, HashMap};
+
+/// A first-order term: a variable or a function application.
+///
+/// This is first-order-logic syntax (the arguments of a [`Formula::Predicate`]),
+/// distinct from the extracted lambda-calculus `Term` produced by `lolli-extract`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FolTerm {
+    /// A variable reference.
+    Var(String),
+    /// A function application `f(t1, ..., tn)`.
+    App(String, Vec<FolTerm>),
+}
+
+impl FolTerm {
+    /// Create a variable term.
+    pub fn var(name: impl Into<String>) -> Self {
+        FolTerm::Var(name.into())
+    }
+
+    /// Create a function application term.
+    pub fn app(name: impl Into<String>, args: Vec<FolTerm>) -> Self {
+        FolTerm::App(name.into(), args)
+    }
+
+    /// Pretty print the term.
+    pub fn pretty(&self) -> String {
+        match self {
+            FolTerm::Var(name) => name.clone(),
+            FolTerm::App(name, args) => format!(
+                "{}({})",
+                name,
+                args.iter().map(FolTerm::pretty).collect::<Vec<_>>().join(", ")
+            ),
+        }
+    }
+
+    /// Collect the free variable names occurring in this term.
+    pub fn free_vars(&self) -> BTreeSet<String> {
+        match self {
+            FolTerm::Var(name) => {
+                let mut vars = BTreeSet::new();
+                vars.insert(name.clone());
+                vars
+            }
+            FolTerm::App(_, args) => args.iter().flat_map(FolTerm::free_vars).collect(),
+        }
+    }
+
+    /// Substitute every occurrence of the variable `var` with `replacement`.
+    pub fn substitute(&self, var: &str, replacement: &FolTerm) -> FolTerm {
+        match self {
+            FolTerm::Var(name) if name == var => replacement.clone(),
+            FolTerm::Var(name) => FolTerm::Var(name.clone()),
+            FolTerm::App(name, args) => FolTerm::App(
+                name.clone(),
+                args.iter().map(|t| t.substitute(var, replacement)).collect(),
+            ),
+        }
+    }
+}
+
 /// A linear logic formula.
 ///
 /// Linear logic has a rich set of connectives split into multiplicative and additive families,
@@ -15,6 +77,16 @@ pub enum Formula {
     /// Negated atomic proposition (A⊥)
     NegAtom(String),
 
+    // First-order
+    /// Predicate applied to first-order terms, e.g. `P(t1, ..., tn)`
+    Predicate(String, Vec<FolTerm>),
+    /// Negated predicate (P(t1, ..., tn))⊥
+    NegPredicate(String, Vec<FolTerm>),
+    /// Linear universal quantifier (∀x. A), binding `x` over the body
+    Forall(String, Box<Formula>),
+    /// Linear existential quantifier (∃x. A), binding `x` over the body
+    Exists(String, Box<Formula>),
+
     // Multiplicatives
     /// Tensor product (A ⊗ B) - "both A and B independently"
     Tensor(Box<Formula>, Box<Formula>),
@@ -65,6 +137,11 @@ impl Formula {
             Formula::Atom(a) => Formula::NegAtom(a.clone()),
             Formula::NegAtom(a) => Formula::Atom(a.clone()),
 
+            Formula::Predicate(name, args) => Formula::NegPredicate(name.clone(), args.clone()),
+            Formula::NegPredicate(name, args) => Formula::Predicate(name.clone(), args.clone()),
+            Formula::Forall(var, body) => Formula::Exists(var.clone(), Box::new(body.negate())),
+            Formula::Exists(var, body) => Formula::Forall(var.clone(), Box::new(body.negate())),
+
             Formula::Tensor(a, b) => {
                 Formula::Par(Box::new(a.negate()), Box::new(b.negate()))
             }
@@ -113,10 +190,101 @@ impl Formula {
             }
             Formula::OfCourse(a) => Formula::OfCourse(Box::new(a.desugar())),
             Formula::WhyNot(a) => Formula::WhyNot(Box::new(a.desugar())),
+            Formula::Forall(var, body) => Formula::Forall(var.clone(), Box::new(body.desugar())),
+            Formula::Exists(var, body) => Formula::Exists(var.clone(), Box::new(body.desugar())),
             _ => self.clone(),
         }
     }
 
+    /// Simplify the formula bottom-up using the unit and annihilator laws
+    /// that hold as provable equivalences in linear logic:
+    ///
+    /// - `A ⊗ 1 = 1 ⊗ A = A` (1 is the tensor unit)
+    /// - `A ⅋ ⊥ = ⊥ ⅋ A = A` (⊥ is the par unit)
+    /// - `A & ⊤ = ⊤ & A = A` (⊤ is the with unit)
+    /// - `A ⊕ 0 = 0 ⊕ A = A` (0 is the plus unit)
+    /// - `A ⊗ 0 = 0 ⊗ A = 0` (0 annihilates ⊗)
+    /// - `A ⅋ ⊤ = ⊤ ⅋ A = A ⊕ ⊤ = ⊤ ⊕ A = ⊤` (⊤ annihilates ⅋ and ⊕)
+    /// - `!1 = 1` and `?⊥ = ⊥`
+    /// - `A ⊸ ⊥ = A⊥` (the reduction `A⊥ ⅋ ⊥ = A⊥` that `desugar` implies)
+    ///
+    /// Note `A & 0` is deliberately *not* rewritten to `0`: unlike the
+    /// classical case, `&` does not absorb `0` in linear logic. This pass is
+    /// idempotent: re-simplifying an already-simplified formula is a no-op.
+    pub fn simplify(&self) -> Formula {
+        match self {
+            Formula::Atom(_)
+            | Formula::NegAtom(_)
+            | Formula::Predicate(_, _)
+            | Formula::NegPredicate(_, _)
+            | Formula::One
+            | Formula::Bottom
+            | Formula::Top
+            | Formula::Zero => self.clone(),
+            Formula::Forall(var, body) => Formula::Forall(var.clone(), Box::new(body.simplify())),
+            Formula::Exists(var, body) => Formula::Exists(var.clone(), Box::new(body.simplify())),
+            Formula::Tensor(a, b) => {
+                let (a, b) = (a.simplify(), b.simplify());
+                match (&a, &b) {
+                    (Formula::One, _) => b,
+                    (_, Formula::One) => a,
+                    (Formula::Zero, _) | (_, Formula::Zero) => Formula::Zero,
+                    _ => Formula::Tensor(Box::new(a), Box::new(b)),
+                }
+            }
+            Formula::Par(a, b) => {
+                let (a, b) = (a.simplify(), b.simplify());
+                match (&a, &b) {
+                    (Formula::Bottom, _) => b,
+                    (_, Formula::Bottom) => a,
+                    (Formula::Top, _) | (_, Formula::Top) => Formula::Top,
+                    _ => Formula::Par(Box::new(a), Box::new(b)),
+                }
+            }
+            Formula::With(a, b) => {
+                let (a, b) = (a.simplify(), b.simplify());
+                match (&a, &b) {
+                    (Formula::Top, _) => b,
+                    (_, Formula::Top) => a,
+                    _ => Formula::With(Box::new(a), Box::new(b)),
+                }
+            }
+            Formula::Plus(a, b) => {
+                let (a, b) = (a.simplify(), b.simplify());
+                match (&a, &b) {
+                    (Formula::Zero, _) => b,
+                    (_, Formula::Zero) => a,
+                    (Formula::Top, _) | (_, Formula::Top) => Formula::Top,
+                    _ => Formula::Plus(Box::new(a), Box::new(b)),
+                }
+            }
+            Formula::OfCourse(a) => {
+                let a = a.simplify();
+                if a == Formula::One {
+                    Formula::One
+                } else {
+                    Formula::OfCourse(Box::new(a))
+                }
+            }
+            Formula::WhyNot(a) => {
+                let a = a.simplify();
+                if a == Formula::Bottom {
+                    Formula::Bottom
+                } else {
+                    Formula::WhyNot(Box::new(a))
+                }
+            }
+            Formula::Lolli(a, b) => {
+                let (a, b) = (a.simplify(), b.simplify());
+                if b == Formula::Bottom {
+                    a.negate().simplify()
+                } else {
+                    Formula::Lolli(Box::new(a), Box::new(b))
+                }
+            }
+        }
+    }
+
     /// Returns true if this formula is positive (async/eager).
     ///
     /// Positive formulas: ⊗, 1, ⊕, 0, !, atoms
@@ -124,6 +292,8 @@ impl Formula {
         matches!(
             self,
             Formula::Atom(_)
+                | Formula::Predicate(_, _)
+                | Formula::Exists(_, _)
                 | Formula::Tensor(_, _)
                 | Formula::One
                 | Formula::Plus(_, _)
@@ -139,18 +309,188 @@ impl Formula {
         !self.is_positive()
     }
 
-    /// Pretty print the formula with Unicode symbols.
+    /// Collect every atom name appearing in the formula, whether it occurs
+    /// positively (`Atom`) or negatively (`NegAtom`).
+    ///
+    /// Predicates are first-order syntax, not propositional atoms, so
+    /// `Predicate`/`NegPredicate` names are not included.
+    pub fn atoms(&self) -> BTreeSet<String> {
+        match self {
+            Formula::Atom(a) | Formula::NegAtom(a) => {
+                let mut names = BTreeSet::new();
+                names.insert(a.clone());
+                names
+            }
+            Formula::Predicate(_, _) | Formula::NegPredicate(_, _) => BTreeSet::new(),
+            Formula::Forall(_, body) | Formula::Exists(_, body) => body.atoms(),
+            Formula::Tensor(a, b)
+            | Formula::Par(a, b)
+            | Formula::With(a, b)
+            | Formula::Plus(a, b)
+            | Formula::Lolli(a, b) => a.atoms().into_iter().chain(b.atoms()).collect(),
+            Formula::OfCourse(a) | Formula::WhyNot(a) => a.atoms(),
+            Formula::One | Formula::Bottom | Formula::Top | Formula::Zero => BTreeSet::new(),
+        }
+    }
+
+    /// Substitute atoms by formulas according to `map`.
+    ///
+    /// Each `Atom(name)` is replaced by `map[name]` (left unchanged if
+    /// `name` isn't in `map`), and each `NegAtom(name)` is replaced by the
+    /// *negation* of `map[name]`, so substitution commutes with
+    /// [`Formula::negate`]: `f.negate().substitute(map) == f.substitute(map).negate()`.
+    ///
+    /// A `Forall`/`Exists` whose bound first-order variable would be
+    /// captured by a free variable of some `map[name]` is alpha-renamed
+    /// first, the same way [`Formula::substitute_term`] avoids capture at
+    /// the term level.
+    pub fn substitute(&self, map: &HashMap<String, Formula>) -> Formula {
+        match self {
+            Formula::Atom(a) => map.get(a).cloned().unwrap_or_else(|| self.clone()),
+            Formula::NegAtom(a) => map
+                .get(a)
+                .map(Formula::negate)
+                .unwrap_or_else(|| self.clone()),
+            Formula::Predicate(_, _) | Formula::NegPredicate(_, _) => self.clone(),
+            Formula::Forall(var, body) => {
+                substitute_atoms_under_binder(var, body, map, Formula::Forall)
+            }
+            Formula::Exists(var, body) => {
+                substitute_atoms_under_binder(var, body, map, Formula::Exists)
+            }
+            Formula::Tensor(a, b) => {
+                Formula::Tensor(Box::new(a.substitute(map)), Box::new(b.substitute(map)))
+            }
+            Formula::Par(a, b) => {
+                Formula::Par(Box::new(a.substitute(map)), Box::new(b.substitute(map)))
+            }
+            Formula::With(a, b) => {
+                Formula::With(Box::new(a.substitute(map)), Box::new(b.substitute(map)))
+            }
+            Formula::Plus(a, b) => {
+                Formula::Plus(Box::new(a.substitute(map)), Box::new(b.substitute(map)))
+            }
+            Formula::Lolli(a, b) => {
+                Formula::Lolli(Box::new(a.substitute(map)), Box::new(b.substitute(map)))
+            }
+            Formula::OfCourse(a) => Formula::OfCourse(Box::new(a.substitute(map))),
+            Formula::WhyNot(a) => Formula::WhyNot(Box::new(a.substitute(map))),
+            Formula::One | Formula::Bottom | Formula::Top | Formula::Zero => self.clone(),
+        }
+    }
+
+    /// Collect the first-order variable names free in this formula, i.e.
+    /// not bound by an enclosing [`Formula::Forall`]/[`Formula::Exists`].
+    pub fn free_vars(&self) -> BTreeSet<String> {
+        match self {
+            Formula::Atom(_)
+            | Formula::NegAtom(_)
+            | Formula::One
+            | Formula::Bottom
+            | Formula::Top
+            | Formula::Zero => BTreeSet::new(),
+            Formula::Predicate(_, args) | Formula::NegPredicate(_, args) => {
+                args.iter().flat_map(FolTerm::free_vars).collect()
+            }
+            Formula::Forall(var, body) | Formula::Exists(var, body) => {
+                let mut vars = body.free_vars();
+                vars.remove(var);
+                vars
+            }
+            Formula::Tensor(a, b)
+            | Formula::Par(a, b)
+            | Formula::With(a, b)
+            | Formula::Plus(a, b)
+            | Formula::Lolli(a, b) => a.free_vars().into_iter().chain(b.free_vars()).collect(),
+            Formula::OfCourse(a) | Formula::WhyNot(a) => a.free_vars(),
+        }
+    }
+
+    /// Capture-avoiding substitution of the first-order variable `var` by
+    /// `replacement` in every [`Formula::Predicate`]/[`Formula::NegPredicate`]
+    /// argument list, integrating with the atom-substitution API above: it
+    /// operates at the term level the way [`Formula::substitute`] operates
+    /// at the atom level, and the two compose freely since they rewrite
+    /// disjoint parts of a formula (terms vs. propositional atoms).
+    ///
+    /// A quantifier that rebinds `var` shadows it, so its body is left
+    /// alone; a quantifier whose bound variable would be captured by a free
+    /// variable of `replacement` is alpha-renamed first.
+    pub fn substitute_term(&self, var: &str, replacement: &FolTerm) -> Formula {
+        match self {
+            Formula::Atom(_)
+            | Formula::NegAtom(_)
+            | Formula::One
+            | Formula::Bottom
+            | Formula::Top
+            | Formula::Zero => self.clone(),
+            Formula::Predicate(name, args) => Formula::Predicate(
+                name.clone(),
+                args.iter().map(|t| t.substitute(var, replacement)).collect(),
+            ),
+            Formula::NegPredicate(name, args) => Formula::NegPredicate(
+                name.clone(),
+                args.iter().map(|t| t.substitute(var, replacement)).collect(),
+            ),
+            Formula::Forall(bound, body) => {
+                substitute_under_binder(bound, body, var, replacement, Formula::Forall)
+            }
+            Formula::Exists(bound, body) => {
+                substitute_under_binder(bound, body, var, replacement, Formula::Exists)
+            }
+            Formula::Tensor(a, b) => Formula::Tensor(
+                Box::new(a.substitute_term(var, replacement)),
+                Box::new(b.substitute_term(var, replacement)),
+            ),
+            Formula::Par(a, b) => Formula::Par(
+                Box::new(a.substitute_term(var, replacement)),
+                Box::new(b.substitute_term(var, replacement)),
+            ),
+            Formula::With(a, b) => Formula::With(
+                Box::new(a.substitute_term(var, replacement)),
+                Box::new(b.substitute_term(var, replacement)),
+            ),
+            Formula::Plus(a, b) => Formula::Plus(
+                Box::new(a.substitute_term(var, replacement)),
+                Box::new(b.substitute_term(var, replacement)),
+            ),
+            Formula::Lolli(a, b) => Formula::Lolli(
+                Box::new(a.substitute_term(var, replacement)),
+                Box::new(b.substitute_term(var, replacement)),
+            ),
+            Formula::OfCourse(a) => Formula::OfCourse(Box::new(a.substitute_term(var, replacement))),
+            Formula::WhyNot(a) => Formula::WhyNot(Box::new(a.substitute_term(var, replacement))),
+        }
+    }
+
+    /// Pretty print the formula with Unicode symbols, omitting parentheses
+    /// that the precedence/associativity table in [`requires_parens`] shows
+    /// are redundant (e.g. `A ⊗ B ⊗ C` rather than `((A ⊗ B) ⊗ C)`).
+    ///
+    /// Use [`Formula::pretty_verbose`] for the fully-parenthesized form.
     pub fn pretty(&self) -> String {
         match self {
             Formula::Atom(a) => a.clone(),
             Formula::NegAtom(a) => format!("{}⊥", a),
-            Formula::Tensor(a, b) => format!("({} ⊗ {})", a.pretty(), b.pretty()),
-            Formula::Par(a, b) => format!("({} ⅋ {})", a.pretty(), b.pretty()),
-            Formula::Lolli(a, b) => format!("({} ⊸ {})", a.pretty(), b.pretty()),
-            Formula::With(a, b) => format!("({} & {})", a.pretty(), b.pretty()),
-            Formula::Plus(a, b) => format!("({} ⊕ {})", a.pretty(), b.pretty()),
-            Formula::OfCourse(a) => format!("!{}", a.pretty()),
-            Formula::WhyNot(a) => format!("?{}", a.pretty()),
+            Formula::Predicate(name, args) => format!(
+                "{}({})",
+                name,
+                args.iter().map(FolTerm::pretty).collect::<Vec<_>>().join(", ")
+            ),
+            Formula::NegPredicate(name, args) => format!(
+                "{}({})⊥",
+                name,
+                args.iter().map(FolTerm::pretty).collect::<Vec<_>>().join(", ")
+            ),
+            Formula::Forall(var, body) => format!("∀{}.{}", var, body.pretty()),
+            Formula::Exists(var, body) => format!("∃{}.{}", var, body.pretty()),
+            Formula::Tensor(a, b) => fmt_binary_prec(a, b, "⊗", 3, Assoc::Left, Formula::pretty),
+            Formula::Par(a, b) => fmt_binary_prec(a, b, "⅋", 2, Assoc::Left, Formula::pretty),
+            Formula::Lolli(a, b) => fmt_binary_prec(a, b, "⊸", 1, Assoc::Right, Formula::pretty),
+            Formula::With(a, b) => fmt_binary_prec(a, b, "&", 5, Assoc::Left, Formula::pretty),
+            Formula::Plus(a, b) => fmt_binary_prec(a, b, "⊕", 4, Assoc::Left, Formula::pretty),
+            Formula::OfCourse(a) => fmt_prefix(a, "!", Formula::pretty),
+            Formula::WhyNot(a) => fmt_prefix(a, "?", Formula::pretty),
             Formula::One => "1".to_string(),
             Formula::Bottom => "⊥".to_string(),
             Formula::Top => "⊤".to_string(),
@@ -158,18 +498,51 @@ impl Formula {
         }
     }
 
-    /// Pretty print the formula with ASCII symbols.
+    /// Pretty print the formula with Unicode symbols, fully parenthesizing
+    /// every binary connective regardless of whether the parentheses are
+    /// needed to disambiguate. See [`Formula::pretty`] for the terser form.
+    pub fn pretty_verbose(&self) -> String {
+        match self {
+            Formula::Tensor(a, b) => format!("({} ⊗ {})", a.pretty_verbose(), b.pretty_verbose()),
+            Formula::Par(a, b) => format!("({} ⅋ {})", a.pretty_verbose(), b.pretty_verbose()),
+            Formula::Lolli(a, b) => format!("({} ⊸ {})", a.pretty_verbose(), b.pretty_verbose()),
+            Formula::With(a, b) => format!("({} & {})", a.pretty_verbose(), b.pretty_verbose()),
+            Formula::Plus(a, b) => format!("({} ⊕ {})", a.pretty_verbose(), b.pretty_verbose()),
+            Formula::OfCourse(a) => format!("!{}", a.pretty_verbose()),
+            Formula::WhyNot(a) => format!("?{}", a.pretty_verbose()),
+            Formula::Forall(var, body) => format!("∀{}.{}", var, body.pretty_verbose()),
+            Formula::Exists(var, body) => format!("∃{}.{}", var, body.pretty_verbose()),
+            _ => self.pretty(),
+        }
+    }
+
+    /// Pretty print the formula with ASCII symbols, omitting redundant
+    /// parentheses the same way [`Formula::pretty`] does.
+    ///
+    /// Use [`Formula::pretty_ascii_verbose`] for the fully-parenthesized form.
     pub fn pretty_ascii(&self) -> String {
         match self {
             Formula::Atom(a) => a.clone(),
             Formula::NegAtom(a) => format!("{}^", a),
-            Formula::Tensor(a, b) => format!("({} * {})", a.pretty_ascii(), b.pretty_ascii()),
-            Formula::Par(a, b) => format!("({} | {})", a.pretty_ascii(), b.pretty_ascii()),
-            Formula::Lolli(a, b) => format!("({} -o {})", a.pretty_ascii(), b.pretty_ascii()),
-            Formula::With(a, b) => format!("({} & {})", a.pretty_ascii(), b.pretty_ascii()),
-            Formula::Plus(a, b) => format!("({} + {})", a.pretty_ascii(), b.pretty_ascii()),
-            Formula::OfCourse(a) => format!("!{}", a.pretty_ascii()),
-            Formula::WhyNot(a) => format!("?{}", a.pretty_ascii()),
+            Formula::Predicate(name, args) => format!(
+                "{}({})",
+                name,
+                args.iter().map(FolTerm::pretty).collect::<Vec<_>>().join(", ")
+            ),
+            Formula::NegPredicate(name, args) => format!(
+                "{}({})^",
+                name,
+                args.iter().map(FolTerm::pretty).collect::<Vec<_>>().join(", ")
+            ),
+            Formula::Forall(var, body) => format!("forall {}. {}", var, body.pretty_ascii()),
+            Formula::Exists(var, body) => format!("exists {}. {}", var, body.pretty_ascii()),
+            Formula::Tensor(a, b) => fmt_binary_prec(a, b, "*", 3, Assoc::Left, Formula::pretty_ascii),
+            Formula::Par(a, b) => fmt_binary_prec(a, b, "|", 2, Assoc::Left, Formula::pretty_ascii),
+            Formula::Lolli(a, b) => fmt_binary_prec(a, b, "-o", 1, Assoc::Right, Formula::pretty_ascii),
+            Formula::With(a, b) => fmt_binary_prec(a, b, "&", 5, Assoc::Left, Formula::pretty_ascii),
+            Formula::Plus(a, b) => fmt_binary_prec(a, b, "+", 4, Assoc::Left, Formula::pretty_ascii),
+            Formula::OfCourse(a) => fmt_prefix(a, "!", Formula::pretty_ascii),
+            Formula::WhyNot(a) => fmt_prefix(a, "?", Formula::pretty_ascii),
             Formula::One => "1".to_string(),
             Formula::Bottom => "bot".to_string(),
             Formula::Top => "top".to_string(),
@@ -177,32 +550,81 @@ impl Formula {
         }
     }
 
-    /// Pretty print the formula for LaTeX.
+    /// Pretty print the formula with ASCII symbols, fully parenthesizing
+    /// every binary connective. See [`Formula::pretty_ascii`] for the terser form.
+    pub fn pretty_ascii_verbose(&self) -> String {
+        match self {
+            Formula::Tensor(a, b) => format!("({} * {})", a.pretty_ascii_verbose(), b.pretty_ascii_verbose()),
+            Formula::Par(a, b) => format!("({} | {})", a.pretty_ascii_verbose(), b.pretty_ascii_verbose()),
+            Formula::Lolli(a, b) => format!("({} -o {})", a.pretty_ascii_verbose(), b.pretty_ascii_verbose()),
+            Formula::With(a, b) => format!("({} & {})", a.pretty_ascii_verbose(), b.pretty_ascii_verbose()),
+            Formula::Plus(a, b) => format!("({} + {})", a.pretty_ascii_verbose(), b.pretty_ascii_verbose()),
+            Formula::OfCourse(a) => format!("!{}", a.pretty_ascii_verbose()),
+            Formula::WhyNot(a) => format!("?{}", a.pretty_ascii_verbose()),
+            Formula::Forall(var, body) => format!("forall {}. {}", var, body.pretty_ascii_verbose()),
+            Formula::Exists(var, body) => format!("exists {}. {}", var, body.pretty_ascii_verbose()),
+            _ => self.pretty_ascii(),
+        }
+    }
+
+    /// Pretty print the formula for LaTeX, omitting redundant parentheses
+    /// the same way [`Formula::pretty`] does.
+    ///
+    /// Use [`Formula::pretty_latex_verbose`] for the fully-parenthesized form.
     pub fn pretty_latex(&self) -> String {
         match self {
             Formula::Atom(a) => a.clone(),
             Formula::NegAtom(a) => format!("{}^{{\\bot}}", a),
+            Formula::Predicate(name, args) => format!(
+                "{}({})",
+                name,
+                args.iter().map(FolTerm::pretty).collect::<Vec<_>>().join(", ")
+            ),
+            Formula::NegPredicate(name, args) => format!(
+                "{}({})^{{\\bot}}",
+                name,
+                args.iter().map(FolTerm::pretty).collect::<Vec<_>>().join(", ")
+            ),
+            Formula::Forall(var, body) => format!("\\forall {}.\\,{}", var, body.pretty_latex()),
+            Formula::Exists(var, body) => format!("\\exists {}.\\,{}", var, body.pretty_latex()),
+            Formula::Tensor(a, b) => fmt_binary_prec(a, b, "\\otimes", 3, Assoc::Left, Formula::pretty_latex),
+            Formula::Par(a, b) => fmt_binary_prec(a, b, "\\parr", 2, Assoc::Left, Formula::pretty_latex),
+            Formula::Lolli(a, b) => fmt_binary_prec(a, b, "\\multimap", 1, Assoc::Right, Formula::pretty_latex),
+            Formula::With(a, b) => fmt_binary_prec(a, b, "\\with", 5, Assoc::Left, Formula::pretty_latex),
+            Formula::Plus(a, b) => fmt_binary_prec(a, b, "\\oplus", 4, Assoc::Left, Formula::pretty_latex),
+            Formula::OfCourse(a) => fmt_prefix(a, "{!}", Formula::pretty_latex),
+            Formula::WhyNot(a) => fmt_prefix(a, "{?}", Formula::pretty_latex),
+            Formula::One => "\\mathbf{1}".to_string(),
+            Formula::Bottom => "\\bot".to_string(),
+            Formula::Top => "\\top".to_string(),
+            Formula::Zero => "\\mathbf{0}".to_string(),
+        }
+    }
+
+    /// Pretty print the formula for LaTeX, fully parenthesizing every
+    /// binary connective. See [`Formula::pretty_latex`] for the terser form.
+    pub fn pretty_latex_verbose(&self) -> String {
+        match self {
             Formula::Tensor(a, b) => {
-                format!("({} \\otimes {})", a.pretty_latex(), b.pretty_latex())
+                format!("({} \\otimes {})", a.pretty_latex_verbose(), b.pretty_latex_verbose())
             }
             Formula::Par(a, b) => {
-                format!("({} \\parr {})", a.pretty_latex(), b.pretty_latex())
+                format!("({} \\parr {})", a.pretty_latex_verbose(), b.pretty_latex_verbose())
             }
             Formula::Lolli(a, b) => {
-                format!("({} \\multimap {})", a.pretty_latex(), b.pretty_latex())
+                format!("({} \\multimap {})", a.pretty_latex_verbose(), b.pretty_latex_verbose())
             }
             Formula::With(a, b) => {
-                format!("({} \\with {})", a.pretty_latex(), b.pretty_latex())
+                format!("({} \\with {})", a.pretty_latex_verbose(), b.pretty_latex_verbose())
             }
             Formula::Plus(a, b) => {
-                format!("({} \\oplus {})", a.pretty_latex(), b.pretty_latex())
+                format!("({} \\oplus {})", a.pretty_latex_verbose(), b.pretty_latex_verbose())
             }
-            Formula::OfCourse(a) => format!("{{!}}{}", a.pretty_latex()),
-            Formula::WhyNot(a) => format!("{{?}}{}", a.pretty_latex()),
-            Formula::One => "\\mathbf{1}".to_string(),
-            Formula::Bottom => "\\bot".to_string(),
-            Formula::Top => "\\top".to_string(),
-            Formula::Zero => "\\mathbf{0}".to_string(),
+            Formula::OfCourse(a) => format!("{{!}}{}", a.pretty_latex_verbose()),
+            Formula::WhyNot(a) => format!("{{?}}{}", a.pretty_latex_verbose()),
+            Formula::Forall(var, body) => format!("\\forall {}.\\,{}", var, body.pretty_latex_verbose()),
+            Formula::Exists(var, body) => format!("\\exists {}.\\,{}", var, body.pretty_latex_verbose()),
+            _ => self.pretty_latex(),
         }
     }
 
@@ -250,6 +672,176 @@ impl Formula {
     pub fn why_not(a: Formula) -> Self {
         Formula::WhyNot(Box::new(a))
     }
+
+    /// Create a predicate applied to first-order terms.
+    pub fn predicate(name: impl Into<String>, args: Vec<FolTerm>) -> Self {
+        Formula::Predicate(name.into(), args)
+    }
+
+    /// Create a universal quantifier (∀x. A).
+    pub fn forall(var: impl Into<String>, body: Formula) -> Self {
+        Formula::Forall(var.into(), Box::new(body))
+    }
+
+    /// Create an existential quantifier (∃x. A).
+    pub fn exists(var: impl Into<String>, body: Formula) -> Self {
+        Formula::Exists(var.into(), Box::new(body))
+    }
+}
+
+/// Substitute `var` by `replacement` through a quantifier's body, handling
+/// the two capture cases: a rebinding quantifier shadows `var` and is left
+/// untouched, while a quantifier whose bound variable would otherwise
+/// capture a free variable of `replacement` is alpha-renamed first.
+fn substitute_under_binder(
+    bound: &str,
+    body: &Formula,
+    var: &str,
+    replacement: &FolTerm,
+    mk: fn(String, Box<Formula>) -> Formula,
+) -> Formula {
+    if bound == var {
+        return mk(bound.to_string(), Box::new(body.clone()));
+    }
+    if replacement.free_vars().contains(bound) {
+        let mut avoid = body.free_vars();
+        avoid.extend(replacement.free_vars());
+        avoid.insert(var.to_string());
+        let fresh = fresh_var_name(bound, &avoid);
+        let renamed_body = body.substitute_term(bound, &FolTerm::Var(fresh.clone()));
+        mk(fresh, Box::new(renamed_body.substitute_term(var, replacement)))
+    } else {
+        mk(bound.to_string(), Box::new(body.substitute_term(var, replacement)))
+    }
+}
+
+/// Capture-avoiding counterpart of [`substitute_under_binder`] for
+/// [`Formula::substitute`]'s atom-to-formula map: if the bound variable
+/// would be captured by a free first-order variable of some formula in
+/// `map`, the binder is alpha-renamed before recursing into the body.
+fn substitute_atoms_under_binder(
+    bound: &str,
+    body: &Formula,
+    map: &HashMap<String, Formula>,
+    mk: fn(String, Box<Formula>) -> Formula,
+) -> Formula {
+    if map.values().any(|f| f.free_vars().contains(bound)) {
+        let mut avoid = body.free_vars();
+        for f in map.values() {
+            avoid.extend(f.free_vars());
+        }
+        let fresh = fresh_var_name(bound, &avoid);
+        let renamed_body = body.substitute_term(bound, &FolTerm::Var(fresh.clone()));
+        mk(fresh, Box::new(renamed_body.substitute(map)))
+    } else {
+        mk(bound.to_string(), Box::new(body.substitute(map)))
+    }
+}
+
+/// Generate a variant of `base` not present in `avoid`, by appending `'`
+/// until the name is unused.
+fn fresh_var_name(base: &str, avoid: &BTreeSet<String>) -> String {
+    let mut candidate = format!("{base}'");
+    while avoid.contains(&candidate) {
+        candidate.push('\'');
+    }
+    candidate
+}
+
+/// Associativity of a binary connective, used by [`requires_parens`] to decide
+/// which side of an equal-precedence operator needs disambiguating parentheses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Assoc {
+    Left,
+    Right,
+}
+
+/// Which side of a binary connective a child formula occupies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+}
+
+/// Binding strength of a formula's outermost connective, loosest to tightest.
+///
+/// Mirrors the precedence actually implemented by `lolli-parse`'s grammar
+/// (`quantifier < iff < lolli < par < tensor < plus < with < unary`), so
+/// that `parse(f.pretty_ascii()) == f` keeps holding once redundant
+/// parentheses are dropped. `Forall`/`Exists` bind loosest of all: the
+/// grammar's `quant_expr` sits below even `iff`, and `unary_expr` can only
+/// recurse into `primary_expr` (never `quant_expr`), so a quantifier
+/// appearing under any other connective — including a bare `!`/`?` — must
+/// always be parenthesized to parse back to the same tree.
+fn prec_of(f: &Formula) -> u8 {
+    match f {
+        Formula::Forall(..) | Formula::Exists(..) => 0,
+        Formula::Lolli(..) => 1,
+        Formula::Par(..) => 2,
+        Formula::Tensor(..) => 3,
+        Formula::Plus(..) => 4,
+        Formula::With(..) => 5,
+        _ => 6,
+    }
+}
+
+/// Whether `child`, printed on `side` of a connective with precedence
+/// `parent_prec` and associativity `parent_assoc`, needs wrapping
+/// parentheses to reproduce the same parse tree.
+fn requires_parens(child: &Formula, parent_prec: u8, parent_assoc: Assoc, side: Side) -> bool {
+    let child_prec = prec_of(child);
+    if child_prec < parent_prec {
+        return true;
+    }
+    if child_prec > parent_prec {
+        return false;
+    }
+    // Equal precedence: only the side that matches the operator's own
+    // associativity can be printed unparenthesized (e.g. `A ⊗ B ⊗ C` is
+    // `(A ⊗ B) ⊗ C`, so the left child of a left-assoc tensor is safe but
+    // the right child is not).
+    match (parent_assoc, side) {
+        (Assoc::Left, Side::Left) => false,
+        (Assoc::Right, Side::Right) => false,
+        _ => true,
+    }
+}
+
+/// Render a binary connective `a <op> b` of precedence `prec` and
+/// associativity `assoc`, adding parentheses around either side only when
+/// needed to preserve the parse tree. `render` is the pretty-printing
+/// method of the variant being formatted (`pretty`, `pretty_ascii`, or
+/// `pretty_latex`), threaded through as a function pointer so the three
+/// printers can share this one parenthesization rule.
+fn fmt_binary_prec(
+    a: &Formula,
+    b: &Formula,
+    op: &str,
+    prec: u8,
+    assoc: Assoc,
+    render: fn(&Formula) -> String,
+) -> String {
+    let left = if requires_parens(a, prec, assoc, Side::Left) {
+        format!("({})", render(a))
+    } else {
+        render(a)
+    };
+    let right = if requires_parens(b, prec, assoc, Side::Right) {
+        format!("({})", render(b))
+    } else {
+        render(b)
+    };
+    format!("{} {} {}", left, op, right)
+}
+
+/// Render a prefix connective (`!`/`?`) with parentheses around the operand
+/// only when the operand binds looser than unary prefix connectives.
+fn fmt_prefix(a: &Formula, op: &str, render: fn(&Formula) -> String) -> String {
+    if prec_of(a) < 6 {
+        format!("{op}({})", render(a))
+    } else {
+        format!("{op}{}", render(a))
+    }
 }
 
 #[cfg(test)]
@@ -384,16 +976,110 @@ mod tests {
     #[test]
     fn test_pretty_print() {
         let f = Formula::lolli(Formula::atom("A"), Formula::atom("B"));
-        assert_eq!(f.pretty(), "(A ⊸ B)");
-        assert_eq!(f.pretty_ascii(), "(A -o B)");
-        assert_eq!(f.pretty_latex(), "(A \\multimap B)");
+        assert_eq!(f.pretty(), "A ⊸ B");
+        assert_eq!(f.pretty_ascii(), "A -o B");
+        assert_eq!(f.pretty_latex(), "A \\multimap B");
+        assert_eq!(f.pretty_verbose(), "(A ⊸ B)");
+        assert_eq!(f.pretty_ascii_verbose(), "(A -o B)");
+        assert_eq!(f.pretty_latex_verbose(), "(A \\multimap B)");
 
         let f = Formula::tensor(
             Formula::of_course(Formula::atom("A")),
             Formula::why_not(Formula::atom("B")),
         );
-        assert_eq!(f.pretty(), "(!A ⊗ ?B)");
-        assert_eq!(f.pretty_ascii(), "(!A * ?B)");
+        assert_eq!(f.pretty(), "!A ⊗ ?B");
+        assert_eq!(f.pretty_ascii(), "!A * ?B");
+        assert_eq!(f.pretty_verbose(), "(!A ⊗ ?B)");
+    }
+
+    #[test]
+    fn test_pretty_omits_redundant_parens() {
+        // A ⊗ B ⊗ C is left-associative tensor, so no parens are needed at all.
+        let f = Formula::tensor(
+            Formula::tensor(Formula::atom("A"), Formula::atom("B")),
+            Formula::atom("C"),
+        );
+        assert_eq!(f.pretty_ascii(), "A * B * C");
+
+        // A ⊗ (B ⊕ C) still needs parens: ⊕ binds looser than ⊗.
+        let f = Formula::tensor(
+            Formula::atom("A"),
+            Formula::plus(Formula::atom("B"), Formula::atom("C")),
+        );
+        assert_eq!(f.pretty_ascii(), "A * (B + C)");
+
+        // -o is right-associative, so A -o B -o C needs no parens, but
+        // (A -o B) -o C does (it's on the "wrong side" of -o's associativity).
+        let right_assoc = Formula::lolli(
+            Formula::atom("A"),
+            Formula::lolli(Formula::atom("B"), Formula::atom("C")),
+        );
+        assert_eq!(right_assoc.pretty_ascii(), "A -o B -o C");
+
+        let left_nested = Formula::lolli(
+            Formula::lolli(Formula::atom("A"), Formula::atom("B")),
+            Formula::atom("C"),
+        );
+        assert_eq!(left_nested.pretty_ascii(), "(A -o B) -o C");
+    }
+
+    #[test]
+    fn test_quantifier_negation() {
+        // (∀x.P(x))⊥ = ∃x.P(x)⊥
+        let forall = Formula::forall("x", Formula::predicate("P", vec![FolTerm::var("x")]));
+        let expected = Formula::exists(
+            "x",
+            Formula::NegPredicate("P".to_string(), vec![FolTerm::var("x")]),
+        );
+        assert_eq!(forall.negate(), expected);
+
+        // Involutive
+        assert_eq!(forall.negate().negate(), forall);
+    }
+
+    #[test]
+    fn test_quantifier_polarity() {
+        let exists = Formula::exists("x", Formula::predicate("P", vec![FolTerm::var("x")]));
+        assert!(exists.is_positive());
+
+        let forall = Formula::forall("x", Formula::predicate("P", vec![FolTerm::var("x")]));
+        assert!(forall.is_negative());
+    }
+
+    #[test]
+    fn test_quantifier_pretty_roundtrips_names() {
+        let f = Formula::forall(
+            "x",
+            Formula::lolli(
+                Formula::predicate("P", vec![FolTerm::var("x")]),
+                Formula::predicate("Q", vec![FolTerm::var("x")]),
+            ),
+        );
+        assert_eq!(f.pretty(), "∀x.P(x) ⊸ Q(x)");
+        assert_eq!(f.pretty_ascii(), "forall x. P(x) -o Q(x)");
+
+        // Nested identical binders keep the inner one shadowing
+        let nested = Formula::forall("x", Formula::forall("x", Formula::predicate("P", vec![FolTerm::var("x")])));
+        assert_eq!(nested.pretty(), "∀x.∀x.P(x)");
+    }
+
+    #[test]
+    fn test_quantifier_under_bang_is_parenthesized() {
+        // A quantifier binds loosest of all (it consumes the rest of the
+        // formula), but `!`/`?` bind tightest, and the grammar's
+        // `unary_expr` can only recurse into `primary_expr`, never
+        // `quant_expr`. Printing `!∀x.P(x)` without parens would leave
+        // `forall`/`∀` to be re-parsed as a bare atom, so it must come out
+        // parenthesized to round-trip.
+        let f = Formula::of_course(Formula::forall("x", Formula::predicate("P", vec![FolTerm::var("x")])));
+        assert_eq!(f.pretty(), "!(∀x.P(x))");
+        assert_eq!(f.pretty_ascii(), "!(forall x. P(x))");
+    }
+
+    #[test]
+    fn test_fol_term_application() {
+        let t = FolTerm::app("f", vec![FolTerm::var("x"), FolTerm::var("y")]);
+        assert_eq!(t.pretty(), "f(x, y)");
     }
 
     #[test]
@@ -408,4 +1094,195 @@ mod tests {
         assert_eq!(Formula::Top.pretty_ascii(), "top");
         assert_eq!(Formula::Zero.pretty_ascii(), "0");
     }
+
+    #[test]
+    fn test_atoms_collects_positive_and_negative_occurrences() {
+        let f = Formula::tensor(
+            Formula::atom("A"),
+            Formula::par(Formula::neg_atom("B"), Formula::of_course(Formula::atom("A"))),
+        );
+        let expected: BTreeSet<String> = ["A", "B"].into_iter().map(String::from).collect();
+        assert_eq!(f.atoms(), expected);
+    }
+
+    #[test]
+    fn test_atoms_ignores_predicates_and_bound_variables() {
+        let f = Formula::forall("x", Formula::predicate("P", vec![FolTerm::var("x")]));
+        assert!(f.atoms().is_empty());
+    }
+
+    #[test]
+    fn test_substitute_replaces_atoms_and_neg_atoms() {
+        let mut map = HashMap::new();
+        map.insert("A".to_string(), Formula::tensor(Formula::atom("X"), Formula::atom("Y")));
+
+        let f = Formula::with(Formula::atom("A"), Formula::neg_atom("A"));
+        let result = f.substitute(&map);
+        assert_eq!(
+            result,
+            Formula::with(
+                Formula::tensor(Formula::atom("X"), Formula::atom("Y")),
+                Formula::par(Formula::neg_atom("X"), Formula::neg_atom("Y")),
+            )
+        );
+    }
+
+    #[test]
+    fn test_substitute_leaves_unmapped_atoms_unchanged() {
+        let map = HashMap::new();
+        let f = Formula::tensor(Formula::atom("A"), Formula::neg_atom("B"));
+        assert_eq!(f.substitute(&map), f);
+    }
+
+    #[test]
+    fn test_substitute_avoids_capture_of_quantified_variable() {
+        // forall x. (P(x) (x) A) [A := Q(x)] must not let the replacement's
+        // free `x` fall under the forall; the bound `x` is alpha-renamed
+        // first, the same way substitute_term does for a single variable.
+        let map = {
+            let mut map = HashMap::new();
+            map.insert(
+                "A".to_string(),
+                Formula::predicate("Q", vec![FolTerm::var("x")]),
+            );
+            map
+        };
+        let f = Formula::forall(
+            "x",
+            Formula::tensor(
+                Formula::predicate("P", vec![FolTerm::var("x")]),
+                Formula::atom("A"),
+            ),
+        );
+        let result = f.substitute(&map);
+
+        match &result {
+            Formula::Forall(bound, body) => {
+                assert_ne!(bound, "x");
+                assert_eq!(
+                    **body,
+                    Formula::tensor(
+                        Formula::predicate("P", vec![FolTerm::var(bound.clone())]),
+                        Formula::predicate("Q", vec![FolTerm::var("x")]),
+                    )
+                );
+            }
+            _ => panic!("expected Forall"),
+        }
+    }
+
+    #[test]
+    fn test_simplify_unit_laws() {
+        let a = Formula::atom("A");
+        assert_eq!(Formula::tensor(a.clone(), Formula::One).simplify(), a);
+        assert_eq!(Formula::tensor(Formula::One, a.clone()).simplify(), a);
+        assert_eq!(Formula::par(a.clone(), Formula::Bottom).simplify(), a);
+        assert_eq!(Formula::par(Formula::Bottom, a.clone()).simplify(), a);
+        assert_eq!(Formula::with(a.clone(), Formula::Top).simplify(), a);
+        assert_eq!(Formula::with(Formula::Top, a.clone()).simplify(), a);
+        assert_eq!(Formula::plus(a.clone(), Formula::Zero).simplify(), a);
+        assert_eq!(Formula::plus(Formula::Zero, a.clone()).simplify(), a);
+    }
+
+    #[test]
+    fn test_simplify_annihilators() {
+        let a = Formula::atom("A");
+        assert_eq!(Formula::tensor(a.clone(), Formula::Zero).simplify(), Formula::Zero);
+        assert_eq!(Formula::tensor(Formula::Zero, a.clone()).simplify(), Formula::Zero);
+        assert_eq!(Formula::par(a.clone(), Formula::Top).simplify(), Formula::Top);
+        assert_eq!(Formula::plus(a.clone(), Formula::Top).simplify(), Formula::Top);
+
+        // Unlike classical conjunction, `&` does not absorb `0`.
+        let with_zero = Formula::with(a.clone(), Formula::Zero);
+        assert_eq!(with_zero.simplify(), with_zero);
+    }
+
+    #[test]
+    fn test_simplify_exponential_units_and_lolli_bottom() {
+        assert_eq!(Formula::of_course(Formula::One).simplify(), Formula::One);
+        assert_eq!(Formula::why_not(Formula::Bottom).simplify(), Formula::Bottom);
+
+        let a = Formula::atom("A");
+        assert_eq!(
+            Formula::lolli(a.clone(), Formula::Bottom).simplify(),
+            a.negate()
+        );
+    }
+
+    #[test]
+    fn test_simplify_is_idempotent() {
+        let f = Formula::tensor(
+            Formula::with(Formula::atom("A"), Formula::Top),
+            Formula::tensor(Formula::One, Formula::plus(Formula::atom("B"), Formula::Zero)),
+        );
+        let once = f.simplify();
+        assert_eq!(once.simplify(), once);
+    }
+
+    #[test]
+    fn test_simplify_recurses_bottom_up() {
+        // Nested redex only becomes visible after the inner one collapses.
+        let f = Formula::tensor(Formula::tensor(Formula::atom("A"), Formula::One), Formula::Zero);
+        assert_eq!(f.simplify(), Formula::Zero);
+    }
+
+    #[test]
+    fn test_substitute_commutes_with_negate() {
+        let mut map = HashMap::new();
+        map.insert("A".to_string(), Formula::with(Formula::atom("X"), Formula::atom("Y")));
+
+        let f = Formula::of_course(Formula::atom("A"));
+        assert_eq!(f.negate().substitute(&map), f.substitute(&map).negate());
+    }
+
+    #[test]
+    fn test_substitute_term_rewrites_predicate_arguments() {
+        let f = Formula::predicate("P", vec![FolTerm::var("x")]);
+        let replacement = FolTerm::app("f", vec![FolTerm::var("y")]);
+        assert_eq!(
+            f.substitute_term("x", &replacement),
+            Formula::predicate("P", vec![FolTerm::app("f", vec![FolTerm::var("y")])])
+        );
+    }
+
+    #[test]
+    fn test_substitute_term_is_shadowed_by_rebinding_quantifier() {
+        let f = Formula::forall("x", Formula::predicate("P", vec![FolTerm::var("x")]));
+        let replacement = FolTerm::var("y");
+        // `x` is rebound by the forall, so the substitution must not reach inside.
+        assert_eq!(f.substitute_term("x", &replacement), f);
+    }
+
+    #[test]
+    fn test_substitute_term_avoids_capture() {
+        // exists y. P(x, y) [x := y] must not turn the free `y` into the
+        // bound one; the bound `y` is alpha-renamed first.
+        let f = Formula::exists(
+            "y",
+            Formula::predicate("P", vec![FolTerm::var("x"), FolTerm::var("y")]),
+        );
+        let replacement = FolTerm::var("y");
+        let result = f.substitute_term("x", &replacement);
+
+        match &result {
+            Formula::Exists(bound, body) => {
+                assert_ne!(bound, "y");
+                assert_eq!(
+                    **body,
+                    Formula::predicate("P", vec![FolTerm::var("y"), FolTerm::var(bound.clone())])
+                );
+            }
+            _ => panic!("expected Exists"),
+        }
+    }
+
+    #[test]
+    fn test_free_vars() {
+        let f = Formula::forall(
+            "x",
+            Formula::predicate("P", vec![FolTerm::var("x"), FolTerm::var("y")]),
+        );
+        let expected: BTreeSet<String> = ["y"].into_iter().map(String::from).collect();
+        assert_eq!(f.free_vars(), expected);
+    }
 }
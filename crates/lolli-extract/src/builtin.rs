@@ -0,0 +1,229 @@
+//! Primitive ("builtin") data and delta reduction.
+//!
+//! Every other value in this language is built from the logical connectives
+//! (`Pair`, `Inl`/`Inr`, `Promote`, ...); this module adds an escape hatch
+//! for primitive constants, in the spirit of Dhall's `apply_builtin`: a
+//! [`Builtin`] is applied exactly like an ordinary function (as a spine of
+//! [`Term::App`]s), but instead of a bound variable for a body, it carries a
+//! fixed arity and a single argument index that must be forced to a
+//! [`Literal`] before its delta rule can pattern-match and fire.
+//!
+//! [`step`](crate::step) is the only reduction engine that currently fires
+//! these rules; the other strategies in [`normalize.rs`](crate::Strategy)
+//! treat an unsaturated or unevaluated builtin application as an ordinary
+//! stuck term.
+
+use std::rc::Rc;
+
+use lolli_core::Term;
+
+/// A primitive operation on [`Literal`]s, applied like an ordinary function
+/// via a spine of [`Term::App`]s with a `Term::Builtin` at the head.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Builtin {
+    /// `is_zero : Nat -> (1 ⊕ Nat)` — `inl ⟨⟩` for `0`, `inr` of the
+    /// predecessor for a positive literal.
+    IsZero,
+    /// `succ : Nat -> Nat`
+    Succ,
+    /// `pred : Nat -> Nat`, saturating at `0`.
+    Pred,
+    /// `fold : Nat -> (A -> A) -> A -> A` — applies its function argument to
+    /// its seed argument as many times as the literal says, bounded by
+    /// [`FOLD_BOUND`] so that firing the rule is itself a single
+    /// terminating step regardless of how large a literal a term carries.
+    Fold,
+}
+
+impl Builtin {
+    /// How many arguments must be supplied before this builtin's delta rule
+    /// can fire.
+    pub fn arity(self) -> usize {
+        match self {
+            Builtin::IsZero | Builtin::Succ | Builtin::Pred => 1,
+            Builtin::Fold => 3,
+        }
+    }
+
+    /// The index (into the arguments collected so far) of the one argument
+    /// that must be forced to a [`Literal`] before the delta rule can
+    /// pattern-match on it. Mirrors Dhall's `(len_consumption, arg_to_eval)`
+    /// bookkeeping: only this argument is forced, the rest are consumed
+    /// structurally in the result.
+    fn arg_to_eval(self) -> usize {
+        match self {
+            Builtin::IsZero | Builtin::Succ | Builtin::Pred | Builtin::Fold => 0,
+        }
+    }
+}
+
+/// A primitive constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Literal {
+    /// A natural number.
+    Nat(u64),
+}
+
+/// The most times [`Builtin::Fold`] will unroll its application in a single
+/// delta reduction.
+pub const FOLD_BOUND: u64 = 10_000;
+
+/// Walk an application spine, collecting the builtin at its head (if any)
+/// and its arguments so far, outermost-applied first. Returns `None` if
+/// `term`'s head is not a `Builtin`.
+fn builtin_spine(term: &Term) -> Option<(Builtin, Vec<Term>)> {
+    fn go(term: &Term, args: &mut Vec<Term>) -> Option<Builtin> {
+        match term {
+            Term::Builtin(b) => Some(*b),
+            Term::App(f, arg) => {
+                let b = go(f, args)?;
+                args.push(arg.as_ref().clone());
+                Some(b)
+            }
+            _ => None,
+        }
+    }
+
+    let mut args = Vec::new();
+    let b = go(term, &mut args)?;
+    Some((b, args))
+}
+
+/// Attempt one delta reduction: if `term` is a builtin applied to at least
+/// as many arguments as its arity, force the one argument it needs (via
+/// `eval`) and, if that argument reduces to the [`Literal`] shape the rule
+/// needs, fire it. Returns `None` if `term` isn't a builtin application, if
+/// it doesn't have enough arguments yet, if the forced argument isn't a
+/// literal, or if a [`Builtin::Fold`] literal exceeds [`FOLD_BOUND`].
+///
+/// `eval` is supplied by the caller (normally [`normalize`](crate::normalize))
+/// so this module stays agnostic of which reduction engine is driving it.
+pub(crate) fn apply_builtin(term: &Term, eval: impl Fn(&Term) -> Term) -> Option<Term> {
+    let (b, args) = builtin_spine(term)?;
+    if args.len() < b.arity() {
+        return None;
+    }
+
+    let Term::Lit(Literal::Nat(n)) = eval(&args[b.arg_to_eval()]) else {
+        return None;
+    };
+
+    let result = match b {
+        Builtin::IsZero => {
+            if n == 0 {
+                Term::Inl(Rc::new(Term::Trivial))
+            } else {
+                Term::Inr(Rc::new(Term::Lit(Literal::Nat(n - 1))))
+            }
+        }
+        Builtin::Succ => Term::Lit(Literal::Nat(n + 1)),
+        Builtin::Pred => Term::Lit(Literal::Nat(n.saturating_sub(1))),
+        Builtin::Fold => {
+            if n > FOLD_BOUND {
+                return None;
+            }
+            let f = &args[1];
+            let mut acc = args[2].clone();
+            for _ in 0..n {
+                acc = Term::App(Rc::new(f.clone()), Rc::new(acc));
+            }
+            acc
+        }
+    };
+
+    // Any arguments beyond this builtin's arity are still pending
+    // application to the delta-reduction result.
+    Some(args[b.arity()..].iter().fold(result, |acc, arg| {
+        Term::App(Rc::new(acc), Rc::new(arg.clone()))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builtin_app(b: Builtin, args: Vec<Term>) -> Term {
+        args.into_iter()
+            .fold(Term::Builtin(b), |acc, arg| Term::App(Rc::new(acc), Rc::new(arg)))
+    }
+
+    #[test]
+    fn test_is_zero_of_zero_is_inl_trivial() {
+        let t = builtin_app(Builtin::IsZero, vec![Term::Lit(Literal::Nat(0))]);
+        assert_eq!(apply_builtin(&t, |t| t.clone()), Some(Term::Inl(Rc::new(Term::Trivial))));
+    }
+
+    #[test]
+    fn test_is_zero_of_positive_is_inr_predecessor() {
+        let t = builtin_app(Builtin::IsZero, vec![Term::Lit(Literal::Nat(3))]);
+        assert_eq!(
+            apply_builtin(&t, |t| t.clone()),
+            Some(Term::Inr(Rc::new(Term::Lit(Literal::Nat(2)))))
+        );
+    }
+
+    #[test]
+    fn test_succ_and_pred() {
+        let succ = builtin_app(Builtin::Succ, vec![Term::Lit(Literal::Nat(4))]);
+        assert_eq!(apply_builtin(&succ, |t| t.clone()), Some(Term::Lit(Literal::Nat(5))));
+
+        let pred = builtin_app(Builtin::Pred, vec![Term::Lit(Literal::Nat(4))]);
+        assert_eq!(apply_builtin(&pred, |t| t.clone()), Some(Term::Lit(Literal::Nat(3))));
+
+        let pred_zero = builtin_app(Builtin::Pred, vec![Term::Lit(Literal::Nat(0))]);
+        assert_eq!(apply_builtin(&pred_zero, |t| t.clone()), Some(Term::Lit(Literal::Nat(0))));
+    }
+
+    #[test]
+    fn test_fold_applies_function_n_times() {
+        // fold 3 succ 0 → succ (succ (succ 0))
+        let t = builtin_app(
+            Builtin::Fold,
+            vec![Term::Lit(Literal::Nat(3)), Term::Builtin(Builtin::Succ), Term::Lit(Literal::Nat(0))],
+        );
+        let expected = Term::App(
+            Rc::new(Term::Builtin(Builtin::Succ)),
+            Rc::new(Term::App(
+                Rc::new(Term::Builtin(Builtin::Succ)),
+                Rc::new(Term::App(
+                    Rc::new(Term::Builtin(Builtin::Succ)),
+                    Rc::new(Term::Lit(Literal::Nat(0))),
+                )),
+            )),
+        );
+        assert_eq!(apply_builtin(&t, |t| t.clone()), Some(expected));
+    }
+
+    #[test]
+    fn test_fold_beyond_bound_does_not_fire() {
+        let t = builtin_app(
+            Builtin::Fold,
+            vec![
+                Term::Lit(Literal::Nat(FOLD_BOUND + 1)),
+                Term::Builtin(Builtin::Succ),
+                Term::Lit(Literal::Nat(0)),
+            ],
+        );
+        assert_eq!(apply_builtin(&t, |t| t.clone()), None);
+    }
+
+    #[test]
+    fn test_under_applied_builtin_does_not_fire() {
+        let t = Term::Builtin(Builtin::IsZero);
+        assert_eq!(apply_builtin(&t, |t| t.clone()), None);
+    }
+
+    #[test]
+    fn test_forces_only_the_argument_it_needs() {
+        // `eval` is called with the one argument `is_zero` needs; it must
+        // not be asked to force anything else.
+        let t = builtin_app(Builtin::IsZero, vec![Term::Var("unrelated".to_string())]);
+        let calls = std::cell::RefCell::new(Vec::new());
+        let result = apply_builtin(&t, |arg| {
+            calls.borrow_mut().push(arg.clone());
+            arg.clone()
+        });
+        assert_eq!(result, None);
+        assert_eq!(calls.into_inner(), vec![Term::Var("unrelated".to_string())]);
+    }
+}
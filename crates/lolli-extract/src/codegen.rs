@@ -0,0 +1,496 @@
+//! Code generation from extracted terms.
+//!
+//! This module compiles a [`Term`] into executable source, analogous to how
+//! Coq's extraction plugin turns proof-derived functions into runnable code.
+//! The generated code discharges linearity once and for all: `Promote` and
+//! `Derelict` become no-ops, and `Copy`/`Discard` become ordinary `clone`s
+//! and `drop`s, since the target language's runtime no longer needs to
+//! track usage counts.
+
+use std::rc::Rc;
+
+use lolli_core::{Formula, Proof, Term};
+
+use crate::builtin::{Builtin, Literal};
+
+/// Rust source for the `Either` type used to encode `⊕` (linear sum) in
+/// generated code. Any program emitted by [`emit_rust`] that contains an
+/// `Inl`/`Inr`/`Case` term needs this definition in scope to compile.
+pub const EITHER_PRELUDE: &str =
+    "#[derive(Clone, Debug)]\nenum Either<L, R> {\n    Left(L),\n    Right(R),\n}\n";
+
+/// Compile a [`Term`] into an executable Rust expression.
+///
+/// `Abs`/`App` become closures, `Pair`/`LetPair` become tuples and
+/// destructuring `let`, `Inl`/`Inr`/`Case` become [`EITHER_PRELUDE`]'s
+/// `Either` enum and a `match`, and `Promote`/`Derelict`/`Copy`/`Discard`
+/// become ordinary clones and drops now that linearity has been discharged.
+///
+/// # Example
+///
+/// ```
+/// use lolli_extract::emit_rust;
+/// use lolli_core::Term;
+/// use std::rc::Rc;
+///
+/// let identity = Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())));
+/// assert_eq!(emit_rust(&identity), "move |x| x");
+/// ```
+pub fn emit_rust(term: &Term) -> String {
+    match term {
+        Term::Var(name) => name.clone(),
+        Term::Unit | Term::Trivial => "()".to_string(),
+        Term::Abs(var, body) => format!("move |{}| {}", var, emit_rust(body)),
+        Term::App(f, arg) => format!("({})({})", emit_rust(f), emit_rust(arg)),
+        Term::Pair(a, b) => format!("({}, {})", emit_rust(a), emit_rust(b)),
+        Term::LetPair(x, y, producer, consumer) => format!(
+            "{{ let ({}, {}) = {}; {} }}",
+            x,
+            y,
+            emit_rust(producer),
+            emit_rust(consumer)
+        ),
+        Term::Inl(inner) => format!("Either::Left({})", emit_rust(inner)),
+        Term::Inr(inner) => format!("Either::Right({})", emit_rust(inner)),
+        Term::Case(subject, x, left, y, right) => format!(
+            "match {} {{ Either::Left({}) => {}, Either::Right({}) => {} }}",
+            emit_rust(subject),
+            x,
+            emit_rust(left),
+            y,
+            emit_rust(right)
+        ),
+        Term::Fst(pair) => format!("({}).0", emit_rust(pair)),
+        Term::Snd(pair) => format!("({}).1", emit_rust(pair)),
+        Term::Promote(inner) | Term::Derelict(inner) => emit_rust(inner),
+        Term::Copy(src, x, y, body) => format!(
+            "{{ let __copy_src = {}; let {} = __copy_src.clone(); let {} = __copy_src; {} }}",
+            emit_rust(src),
+            x,
+            y,
+            emit_rust(body)
+        ),
+        Term::Discard(value, body) => format!("{{ drop({}); {} }}", emit_rust(value), emit_rust(body)),
+        Term::Abort(inner) => format!("unreachable!(\"absurd: {{:?}}\", {})", emit_rust(inner)),
+        Term::Lit(Literal::Nat(n)) => n.to_string(),
+        Term::Builtin(b) => builtin_name(*b).to_string(),
+    }
+}
+
+/// Compile a [`Term`] into an executable OCaml expression, mirroring
+/// [`emit_rust`]'s mapping (`Either` is OCaml's built-in `Left`/`Right`
+/// variant type, so no prelude is needed).
+///
+/// # Example
+///
+/// ```
+/// use lolli_extract::emit_ocaml;
+/// use lolli_core::Term;
+/// use std::rc::Rc;
+///
+/// let identity = Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())));
+/// assert_eq!(emit_ocaml(&identity), "(fun x -> x)");
+/// ```
+pub fn emit_ocaml(term: &Term) -> String {
+    match term {
+        Term::Var(name) => name.clone(),
+        Term::Unit | Term::Trivial => "()".to_string(),
+        Term::Abs(var, body) => format!("(fun {} -> {})", var, emit_ocaml(body)),
+        Term::App(f, arg) => format!("({} {})", emit_ocaml(f), emit_ocaml(arg)),
+        Term::Pair(a, b) => format!("({}, {})", emit_ocaml(a), emit_ocaml(b)),
+        Term::LetPair(x, y, producer, consumer) => format!(
+            "(let ({}, {}) = {} in {})",
+            x,
+            y,
+            emit_ocaml(producer),
+            emit_ocaml(consumer)
+        ),
+        Term::Inl(inner) => format!("(Left {})", emit_ocaml(inner)),
+        Term::Inr(inner) => format!("(Right {})", emit_ocaml(inner)),
+        Term::Case(subject, x, left, y, right) => format!(
+            "(match {} with Left {} -> {} | Right {} -> {})",
+            emit_ocaml(subject),
+            x,
+            emit_ocaml(left),
+            y,
+            emit_ocaml(right)
+        ),
+        Term::Fst(pair) => format!("(fst {})", emit_ocaml(pair)),
+        Term::Snd(pair) => format!("(snd {})", emit_ocaml(pair)),
+        Term::Promote(inner) | Term::Derelict(inner) => emit_ocaml(inner),
+        Term::Copy(src, x, y, body) => format!(
+            "(let __copy_src = {} in let {} = __copy_src in let {} = __copy_src in {})",
+            emit_ocaml(src),
+            x,
+            y,
+            emit_ocaml(body)
+        ),
+        Term::Discard(value, body) => format!("(ignore ({}); {})", emit_ocaml(value), emit_ocaml(body)),
+        Term::Abort(inner) => format!("(assert false (* absurd: {} *))", emit_ocaml(inner)),
+        Term::Lit(Literal::Nat(n)) => n.to_string(),
+        Term::Builtin(b) => builtin_name(*b).to_string(),
+    }
+}
+
+/// The target-language identifier a [`Builtin`] compiles to: callers must
+/// supply a function of this name with the builtin's arity and semantics in
+/// scope, the same way [`EITHER_PRELUDE`] is supplied for `Inl`/`Inr`/`Case`.
+fn builtin_name(b: Builtin) -> &'static str {
+    match b {
+        Builtin::IsZero => "is_zero",
+        Builtin::Succ => "succ",
+        Builtin::Pred => "pred",
+        Builtin::Fold => "fold",
+    }
+}
+
+/// Wrap [`emit_rust`]'s output in a named, compilable Rust function, with a
+/// parameter list inferred from `proof`'s free atoms (the negative atoms in
+/// its conclusion, which the one-sided calculus uses to represent
+/// hypotheses). Prepends [`EITHER_PRELUDE`] if `term` needs it.
+///
+/// # Example
+///
+/// ```
+/// use lolli_extract::emit_rust_fn;
+/// use lolli_core::{Formula, Proof, Rule, Sequent, Term};
+/// use std::rc::Rc;
+///
+/// let proof = Proof {
+///     conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+///     rule: Rule::Axiom,
+///     premises: vec![],
+/// };
+/// let term = Term::Var("a".to_string());
+///
+/// let source = emit_rust_fn("identity", &proof, &term);
+/// assert!(source.contains("fn identity"));
+/// ```
+pub fn emit_rust_fn(name: &str, proof: &Proof, term: &Term) -> String {
+    let params = free_atom_names(proof);
+    let param_list = params
+        .iter()
+        .map(|p| format!("{}: impl Clone", p))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let function = format!(
+        "fn {}({}) -> impl Clone {{\n    {}\n}}\n",
+        name,
+        param_list,
+        emit_rust(term)
+    );
+
+    if term_uses_sum(term) {
+        format!("{}\n{}", EITHER_PRELUDE, function)
+    } else {
+        function
+    }
+}
+
+/// The lowercased names of the negative atoms in `proof`'s conclusion, in
+/// order of first appearance and without duplicates — these stand for the
+/// hypotheses a generated function needs as arguments.
+fn free_atom_names(proof: &Proof) -> Vec<String> {
+    let mut names = Vec::new();
+    for formula in &proof.conclusion.linear {
+        if let Formula::NegAtom(name) = formula {
+            let name = name.to_lowercase();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    names
+}
+
+/// Target language for [`CodeGen`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    /// Generate Rust source via [`emit_rust`].
+    Rust,
+    /// Generate OCaml source via [`emit_ocaml`].
+    OCaml,
+}
+
+/// A code generator fixed to one [`Target`] language.
+///
+/// This is a thin, stateless wrapper over [`emit_rust`]/[`emit_ocaml`] that
+/// lets callers select the target language as data (e.g. from a CLI flag)
+/// rather than choosing between two free functions at the call site.
+///
+/// # Example
+///
+/// ```
+/// use lolli_extract::{CodeGen, Target};
+/// use lolli_core::Term;
+/// use std::rc::Rc;
+///
+/// let identity = Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())));
+/// assert_eq!(CodeGen::new(Target::Rust).emit(&identity), "move |x| x");
+/// assert_eq!(CodeGen::new(Target::OCaml).emit(&identity), "(fun x -> x)");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CodeGen {
+    target: Target,
+}
+
+impl CodeGen {
+    /// Create a code generator for `target`.
+    pub fn new(target: Target) -> Self {
+        CodeGen { target }
+    }
+
+    /// The target language this generator emits.
+    pub fn target(&self) -> Target {
+        self.target
+    }
+
+    /// Compile `term` into source text for this generator's target.
+    pub fn emit(&self, term: &Term) -> String {
+        match self.target {
+            Target::Rust => emit_rust(term),
+            Target::OCaml => emit_ocaml(term),
+        }
+    }
+}
+
+/// Whether `term` contains an `Inl`, `Inr`, or `Case`, and so needs the
+/// `Either` prelude to compile.
+fn term_uses_sum(term: &Term) -> bool {
+    match term {
+        Term::Var(_) | Term::Unit | Term::Trivial | Term::Lit(_) | Term::Builtin(_) => false,
+        Term::Inl(_) | Term::Inr(_) | Term::Case(..) => true,
+        Term::Abs(_, body) | Term::Promote(body) | Term::Derelict(body) | Term::Fst(body) | Term::Snd(body) | Term::Abort(body) => {
+            term_uses_sum(body)
+        }
+        Term::App(a, b) | Term::Pair(a, b) | Term::Discard(a, b) => term_uses_sum(a) || term_uses_sum(b),
+        Term::LetPair(_, _, a, b) => term_uses_sum(a) || term_uses_sum(b),
+        Term::Copy(src, _, _, body) => term_uses_sum(src) || term_uses_sum(body),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lolli_core::{Formula, Rule, Sequent};
+
+    #[test]
+    fn test_emit_rust_identity() {
+        let term = Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())));
+        assert_eq!(emit_rust(&term), "move |x| x");
+    }
+
+    #[test]
+    fn test_emit_rust_pair_and_let_pair() {
+        let pair = Term::Pair(Rc::new(Term::Unit), Rc::new(Term::Trivial));
+        assert_eq!(emit_rust(&pair), "((), ())");
+
+        let let_pair = Term::LetPair(
+            "a".to_string(),
+            "b".to_string(),
+            Rc::new(pair),
+            Rc::new(Term::Var("a".to_string())),
+        );
+        assert_eq!(emit_rust(&let_pair), "{ let (a, b) = ((), ()); a }");
+    }
+
+    #[test]
+    fn test_emit_rust_case_uses_either() {
+        let term = Term::Case(
+            Rc::new(Term::Inl(Rc::new(Term::Unit))),
+            "x".to_string(),
+            Rc::new(Term::Var("x".to_string())),
+            "y".to_string(),
+            Rc::new(Term::Var("y".to_string())),
+        );
+
+        let output = emit_rust(&term);
+        assert!(output.contains("Either::Left(())"));
+        assert!(output.contains("match Either::Left(())"));
+        assert!(output.contains("Either::Left(x) =>"));
+        assert!(output.contains("Either::Right(y) =>"));
+    }
+
+    #[test]
+    fn test_emit_rust_copy_does_not_double_evaluate() {
+        // Copy must bind its source once, not inline it twice, or a
+        // side-effecting or move-only source would be evaluated/consumed
+        // twice in the generated code.
+        let term = Term::Copy(
+            Rc::new(Term::Var("input".to_string())),
+            "a".to_string(),
+            "b".to_string(),
+            Rc::new(Term::Pair(
+                Rc::new(Term::Var("a".to_string())),
+                Rc::new(Term::Var("b".to_string())),
+            )),
+        );
+
+        let output = emit_rust(&term);
+        assert_eq!(output.matches("input").count(), 1);
+        assert!(output.contains("__copy_src.clone()"));
+    }
+
+    #[test]
+    fn test_emit_rust_discard() {
+        let term = Term::Discard(Rc::new(Term::Var("unused".to_string())), Rc::new(Term::Unit));
+        assert_eq!(emit_rust(&term), "{ drop(unused); () }");
+    }
+
+    #[test]
+    fn test_emit_rust_literal_and_builtin() {
+        assert_eq!(emit_rust(&Term::Lit(Literal::Nat(7))), "7");
+
+        let applied = Term::App(Rc::new(Term::Builtin(Builtin::IsZero)), Rc::new(Term::Lit(Literal::Nat(0))));
+        assert_eq!(emit_rust(&applied), "(is_zero)(0)");
+    }
+
+    #[test]
+    fn test_emit_ocaml_identity() {
+        let term = Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())));
+        assert_eq!(emit_ocaml(&term), "(fun x -> x)");
+    }
+
+    #[test]
+    fn test_emit_ocaml_case_uses_left_right() {
+        let term = Term::Case(
+            Rc::new(Term::Inr(Rc::new(Term::Unit))),
+            "x".to_string(),
+            Rc::new(Term::Var("x".to_string())),
+            "y".to_string(),
+            Rc::new(Term::Var("y".to_string())),
+        );
+
+        let output = emit_ocaml(&term);
+        assert!(output.contains("(Right ())"));
+        assert!(output.contains("Left x -> x"));
+        assert!(output.contains("Right y -> y"));
+    }
+
+    #[test]
+    fn test_emit_rust_fn_infers_params_from_free_atoms() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let term = Term::Abs("a".to_string(), Rc::new(Term::Var("a".to_string())));
+
+        let source = emit_rust_fn("identity", &proof, &term);
+        assert!(source.contains("fn identity(a: impl Clone)"));
+        assert!(!source.contains("enum Either"));
+    }
+
+    #[test]
+    fn test_emit_rust_fn_prepends_either_prelude_when_needed() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let term = Term::Inl(Rc::new(Term::Unit));
+
+        let source = emit_rust_fn("pick", &proof, &term);
+        assert!(source.contains("enum Either"));
+    }
+
+    #[test]
+    fn test_codegen_rust_matches_emit_rust() {
+        let term = Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())));
+        assert_eq!(CodeGen::new(Target::Rust).emit(&term), emit_rust(&term));
+    }
+
+    #[test]
+    fn test_codegen_ocaml_matches_emit_ocaml() {
+        let term = Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())));
+        assert_eq!(CodeGen::new(Target::OCaml).emit(&term), emit_ocaml(&term));
+    }
+
+    /// Shells out to `rustc` to confirm generated source is actually valid
+    /// Rust, not just a string we hope looks right.
+    fn assert_rust_compiles(source: &str) {
+        use std::process::Command;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        let mut src_path = std::env::temp_dir();
+        src_path.push(format!("lolli_codegen_check_{}_{}.rs", std::process::id(), n));
+        std::fs::write(&src_path, source).expect("write temp rust source");
+
+        let mut out_path = std::env::temp_dir();
+        out_path.push(format!("lolli_codegen_check_{}_{}.out", std::process::id(), n));
+
+        let output = Command::new("rustc")
+            .args(["--edition", "2021", "--crate-type", "lib", "-A", "warnings"])
+            .arg("-o")
+            .arg(&out_path)
+            .arg(&src_path)
+            .output();
+
+        let _ = std::fs::remove_file(&src_path);
+        let _ = std::fs::remove_file(&out_path);
+
+        let output = match output {
+            Ok(output) => output,
+            Err(_) => return, // no rustc on PATH in this environment; skip.
+        };
+
+        assert!(
+            output.status.success(),
+            "generated Rust failed to compile:\n{}\n---\n{}",
+            source,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn test_emitted_rust_identity_fn_compiles() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        // What `Extractor::extract` gives for this axiom: the hypothesis `a`
+        // itself, which becomes the function's parameter.
+        let term = Term::Var("a".to_string());
+
+        assert_rust_compiles(&emit_rust_fn("identity", &proof, &term));
+    }
+
+    #[test]
+    fn test_emitted_rust_case_fn_compiles() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let term = Term::Case(
+            Rc::new(Term::Inl(Rc::new(Term::Unit))),
+            "x".to_string(),
+            Rc::new(Term::Var("x".to_string())),
+            "y".to_string(),
+            Rc::new(Term::Var("y".to_string())),
+        );
+
+        assert_rust_compiles(&emit_rust_fn("pick", &proof, &term));
+    }
+
+    /// Unlike `test_emitted_rust_case_fn_compiles`, where both `Case` arms
+    /// unify to `()` before ever reaching the return type, this pins a
+    /// function's result to a concrete, un-eliminated `Either<i32, i32>`
+    /// and returns it as `impl Clone` — the exact shape `emit_rust_fn`
+    /// produces for a term whose value is an unconsumed `Inl`/`Inr`. It
+    /// only compiles because `EITHER_PRELUDE` derives `Clone` for `Either`.
+    #[test]
+    fn test_either_prelude_enum_is_clone() {
+        let source = format!(
+            "{}\nfn make_either() -> Either<i32, i32> {{\n    Either::Left(1)\n}}\n\nfn pick() -> impl Clone {{\n    make_either()\n}}\n",
+            EITHER_PRELUDE
+        );
+
+        assert_rust_compiles(&source);
+    }
+}
@@ -0,0 +1,448 @@
+//! De Bruijn-indexed term representation, used internally to make
+//! substitution capture-avoiding.
+//!
+//! `Term`'s bound variables are plain `String` names, so substituting a
+//! replacement term that has a free variable sharing a name with an
+//! enclosing binder risks that variable being silently captured. This
+//! module sidesteps the problem entirely: names are converted to de Bruijn
+//! indices ([`to_debruijn`]) before substitution and back to readable names
+//! ([`from_debruijn`]) afterward, following the `shift`/`subst` discipline
+//! (Pierce, *TAPL* ch. 6; also used by Dhall's normalizer). A free variable
+//! becomes [`DTerm::Free`] and is never touched by `subst`, so it can never
+//! be captured no matter what index is being substituted.
+//!
+//! Binder names are kept on the [`DTerm`] nodes purely as labels for
+//! [`from_debruijn`] to reuse when reconstructing a `Term` — they play no
+//! role in resolving variable references, which is what makes this
+//! representation capture-avoiding in the first place.
+
+use std::collections::BTreeSet;
+use std::rc::Rc;
+
+use lolli_core::Term;
+
+/// A [`Term`] with bound variables replaced by de Bruijn indices.
+///
+/// Binders that introduce two names at once (`LetPair`, `Copy`) bind index
+/// `1` to the first name and index `0` to the second, i.e. as if they were
+/// two nested single-variable binders with the second name innermost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DTerm {
+    /// A variable bound by an enclosing binder, referred to by the number
+    /// of binders between this occurrence and its own.
+    Bound(usize),
+    /// A variable with no enclosing binder — never touched by `subst`.
+    Free(String),
+    Unit,
+    Trivial,
+    Abs(String, Box<DTerm>),
+    App(Box<DTerm>, Box<DTerm>),
+    Pair(Box<DTerm>, Box<DTerm>),
+    LetPair(String, String, Box<DTerm>, Box<DTerm>),
+    Inl(Box<DTerm>),
+    Inr(Box<DTerm>),
+    Case(Box<DTerm>, String, Box<DTerm>, String, Box<DTerm>),
+    Fst(Box<DTerm>),
+    Snd(Box<DTerm>),
+    Promote(Box<DTerm>),
+    Derelict(Box<DTerm>),
+    Copy(Box<DTerm>, String, String, Box<DTerm>),
+    Discard(Box<DTerm>, Box<DTerm>),
+    Abort(Box<DTerm>),
+}
+
+/// Convert a named `Term` to a `DTerm`, resolving each `Var` against `ctx`
+/// (a stack of in-scope binder names, innermost last).
+fn to_debruijn(term: &Term, ctx: &mut Vec<String>) -> DTerm {
+    match term {
+        Term::Var(name) => match ctx.iter().rev().position(|bound| bound == name) {
+            Some(index) => DTerm::Bound(index),
+            None => DTerm::Free(name.clone()),
+        },
+        Term::Unit => DTerm::Unit,
+        Term::Trivial => DTerm::Trivial,
+        Term::Abs(x, body) => {
+            ctx.push(x.clone());
+            let body = to_debruijn(body, ctx);
+            ctx.pop();
+            DTerm::Abs(x.clone(), Box::new(body))
+        }
+        Term::App(f, a) => DTerm::App(Box::new(to_debruijn(f, ctx)), Box::new(to_debruijn(a, ctx))),
+        Term::Pair(a, b) => DTerm::Pair(Box::new(to_debruijn(a, ctx)), Box::new(to_debruijn(b, ctx))),
+        Term::LetPair(x, y, pair, body) => {
+            let pair = to_debruijn(pair, ctx);
+            ctx.push(x.clone());
+            ctx.push(y.clone());
+            let body = to_debruijn(body, ctx);
+            ctx.pop();
+            ctx.pop();
+            DTerm::LetPair(x.clone(), y.clone(), Box::new(pair), Box::new(body))
+        }
+        Term::Inl(e) => DTerm::Inl(Box::new(to_debruijn(e, ctx))),
+        Term::Inr(e) => DTerm::Inr(Box::new(to_debruijn(e, ctx))),
+        Term::Case(scrut, x, left, y, right) => {
+            let scrut = to_debruijn(scrut, ctx);
+            ctx.push(x.clone());
+            let left = to_debruijn(left, ctx);
+            ctx.pop();
+            ctx.push(y.clone());
+            let right = to_debruijn(right, ctx);
+            ctx.pop();
+            DTerm::Case(Box::new(scrut), x.clone(), Box::new(left), y.clone(), Box::new(right))
+        }
+        Term::Fst(e) => DTerm::Fst(Box::new(to_debruijn(e, ctx))),
+        Term::Snd(e) => DTerm::Snd(Box::new(to_debruijn(e, ctx))),
+        Term::Promote(e) => DTerm::Promote(Box::new(to_debruijn(e, ctx))),
+        Term::Derelict(e) => DTerm::Derelict(Box::new(to_debruijn(e, ctx))),
+        Term::Copy(src, x, y, body) => {
+            let src = to_debruijn(src, ctx);
+            ctx.push(x.clone());
+            ctx.push(y.clone());
+            let body = to_debruijn(body, ctx);
+            ctx.pop();
+            ctx.pop();
+            DTerm::Copy(Box::new(src), x.clone(), y.clone(), Box::new(body))
+        }
+        Term::Discard(discarded, body) => DTerm::Discard(
+            Box::new(to_debruijn(discarded, ctx)),
+            Box::new(to_debruijn(body, ctx)),
+        ),
+        Term::Abort(e) => DTerm::Abort(Box::new(to_debruijn(e, ctx))),
+    }
+}
+
+/// Convert a `DTerm` back to a named `Term`, resolving each `Bound` index
+/// against `ctx` (the same binder-name stack discipline as [`to_debruijn`]).
+fn from_debruijn(term: &DTerm, ctx: &mut Vec<String>) -> Term {
+    match term {
+        DTerm::Free(name) => Term::Var(name.clone()),
+        DTerm::Bound(index) => Term::Var(ctx[ctx.len() - 1 - index].clone()),
+        DTerm::Unit => Term::Unit,
+        DTerm::Trivial => Term::Trivial,
+        DTerm::Abs(x, body) => {
+            let x = rebind_name(x, body, ctx.as_slice());
+            ctx.push(x.clone());
+            let body = from_debruijn(body, ctx);
+            ctx.pop();
+            Term::Abs(x, Rc::new(body))
+        }
+        DTerm::App(f, a) => Term::App(Rc::new(from_debruijn(f, ctx)), Rc::new(from_debruijn(a, ctx))),
+        DTerm::Pair(a, b) => Term::Pair(Rc::new(from_debruijn(a, ctx)), Rc::new(from_debruijn(b, ctx))),
+        DTerm::LetPair(x, y, pair, body) => {
+            let pair_term = from_debruijn(pair, ctx);
+            let x = rebind_name(x, body, ctx.as_slice());
+            ctx.push(x.clone());
+            let y = rebind_name(y, body, ctx.as_slice());
+            ctx.push(y.clone());
+            let body = from_debruijn(body, ctx);
+            ctx.pop();
+            ctx.pop();
+            Term::LetPair(x, y, Rc::new(pair_term), Rc::new(body))
+        }
+        DTerm::Inl(e) => Term::Inl(Rc::new(from_debruijn(e, ctx))),
+        DTerm::Inr(e) => Term::Inr(Rc::new(from_debruijn(e, ctx))),
+        DTerm::Case(scrut, x, left, y, right) => {
+            let scrut_term = from_debruijn(scrut, ctx);
+            let x = rebind_name(x, left, ctx.as_slice());
+            ctx.push(x.clone());
+            let left = from_debruijn(left, ctx);
+            ctx.pop();
+            let y = rebind_name(y, right, ctx.as_slice());
+            ctx.push(y.clone());
+            let right = from_debruijn(right, ctx);
+            ctx.pop();
+            Term::Case(Rc::new(scrut_term), x, Rc::new(left), y, Rc::new(right))
+        }
+        DTerm::Fst(e) => Term::Fst(Rc::new(from_debruijn(e, ctx))),
+        DTerm::Snd(e) => Term::Snd(Rc::new(from_debruijn(e, ctx))),
+        DTerm::Promote(e) => Term::Promote(Rc::new(from_debruijn(e, ctx))),
+        DTerm::Derelict(e) => Term::Derelict(Rc::new(from_debruijn(e, ctx))),
+        DTerm::Copy(src, x, y, body) => {
+            let src_term = from_debruijn(src, ctx);
+            let x = rebind_name(x, body, ctx.as_slice());
+            ctx.push(x.clone());
+            let y = rebind_name(y, body, ctx.as_slice());
+            ctx.push(y.clone());
+            let body = from_debruijn(body, ctx);
+            ctx.pop();
+            ctx.pop();
+            Term::Copy(Rc::new(src_term), x, y, Rc::new(body))
+        }
+        DTerm::Discard(discarded, body) => Term::Discard(
+            Rc::new(from_debruijn(discarded, ctx)),
+            Rc::new(from_debruijn(body, ctx)),
+        ),
+        DTerm::Abort(e) => Term::Abort(Rc::new(from_debruijn(e, ctx))),
+    }
+}
+
+/// Pick the name to use for a binder being reconstructed by [`from_debruijn`].
+///
+/// Reusing a de Bruijn term's original binder label verbatim would be
+/// unsound here: index resolution only cares about position, but a `Free`
+/// variable occurring in `scope` is resolved by name once converted back to
+/// a `Term`, so if it happens to share `label` with this binder, the binder
+/// would silently (and wrongly) capture it. Renaming the binder to a name
+/// that appears nowhere free in `scope` or the enclosing `ctx` keeps the
+/// reconstructed `Term` exactly as capture-free as the indices already are.
+fn rebind_name(label: &str, scope: &DTerm, ctx: &[String]) -> String {
+    let mut avoid = free_names(scope);
+    avoid.extend(ctx.iter().cloned());
+    fresh_name(label, &avoid)
+}
+
+/// Collect every name occurring as [`DTerm::Free`] within `t`.
+fn free_names(t: &DTerm) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    collect_free_names(t, &mut names);
+    names
+}
+
+fn collect_free_names(t: &DTerm, acc: &mut BTreeSet<String>) {
+    match t {
+        DTerm::Free(name) => {
+            acc.insert(name.clone());
+        }
+        DTerm::Bound(_) | DTerm::Unit | DTerm::Trivial => {}
+        DTerm::Abs(_, body)
+        | DTerm::Inl(body)
+        | DTerm::Inr(body)
+        | DTerm::Fst(body)
+        | DTerm::Snd(body)
+        | DTerm::Promote(body)
+        | DTerm::Derelict(body)
+        | DTerm::Abort(body) => collect_free_names(body, acc),
+        DTerm::App(a, b) | DTerm::Pair(a, b) | DTerm::Discard(a, b) => {
+            collect_free_names(a, acc);
+            collect_free_names(b, acc);
+        }
+        DTerm::LetPair(_, _, pair, body) => {
+            collect_free_names(pair, acc);
+            collect_free_names(body, acc);
+        }
+        DTerm::Copy(src, _, _, body) => {
+            collect_free_names(src, acc);
+            collect_free_names(body, acc);
+        }
+        DTerm::Case(scrut, _, left, _, right) => {
+            collect_free_names(scrut, acc);
+            collect_free_names(left, acc);
+            collect_free_names(right, acc);
+        }
+    }
+}
+
+/// Append `'` to `base` until the result no longer collides with `avoid`.
+fn fresh_name(base: &str, avoid: &BTreeSet<String>) -> String {
+    let mut candidate = base.to_string();
+    while avoid.contains(&candidate) {
+        candidate.push('\'');
+    }
+    candidate
+}
+
+/// Add `d` to every `Bound` index `>= cutoff`, incrementing `cutoff` by one
+/// (or two, for the two-variable binders) each time a binder is crossed.
+fn shift(d: isize, cutoff: usize, t: &DTerm) -> DTerm {
+    match t {
+        DTerm::Bound(k) => {
+            if *k >= cutoff {
+                DTerm::Bound((*k as isize + d) as usize)
+            } else {
+                DTerm::Bound(*k)
+            }
+        }
+        DTerm::Free(name) => DTerm::Free(name.clone()),
+        DTerm::Unit => DTerm::Unit,
+        DTerm::Trivial => DTerm::Trivial,
+        DTerm::Abs(x, body) => DTerm::Abs(x.clone(), Box::new(shift(d, cutoff + 1, body))),
+        DTerm::App(f, a) => DTerm::App(Box::new(shift(d, cutoff, f)), Box::new(shift(d, cutoff, a))),
+        DTerm::Pair(a, b) => DTerm::Pair(Box::new(shift(d, cutoff, a)), Box::new(shift(d, cutoff, b))),
+        DTerm::LetPair(x, y, pair, body) => DTerm::LetPair(
+            x.clone(),
+            y.clone(),
+            Box::new(shift(d, cutoff, pair)),
+            Box::new(shift(d, cutoff + 2, body)),
+        ),
+        DTerm::Inl(e) => DTerm::Inl(Box::new(shift(d, cutoff, e))),
+        DTerm::Inr(e) => DTerm::Inr(Box::new(shift(d, cutoff, e))),
+        DTerm::Case(scrut, x, left, y, right) => DTerm::Case(
+            Box::new(shift(d, cutoff, scrut)),
+            x.clone(),
+            Box::new(shift(d, cutoff + 1, left)),
+            y.clone(),
+            Box::new(shift(d, cutoff + 1, right)),
+        ),
+        DTerm::Fst(e) => DTerm::Fst(Box::new(shift(d, cutoff, e))),
+        DTerm::Snd(e) => DTerm::Snd(Box::new(shift(d, cutoff, e))),
+        DTerm::Promote(e) => DTerm::Promote(Box::new(shift(d, cutoff, e))),
+        DTerm::Derelict(e) => DTerm::Derelict(Box::new(shift(d, cutoff, e))),
+        DTerm::Copy(src, x, y, body) => DTerm::Copy(
+            Box::new(shift(d, cutoff, src)),
+            x.clone(),
+            y.clone(),
+            Box::new(shift(d, cutoff + 2, body)),
+        ),
+        DTerm::Discard(discarded, body) => DTerm::Discard(
+            Box::new(shift(d, cutoff, discarded)),
+            Box::new(shift(d, cutoff, body)),
+        ),
+        DTerm::Abort(e) => DTerm::Abort(Box::new(shift(d, cutoff, e))),
+    }
+}
+
+/// Replace the variable with index `j` by `s` throughout `t`, shifting `s`
+/// by one (or two) each time the substitution descends under a binder.
+fn subst(j: usize, s: &DTerm, t: &DTerm) -> DTerm {
+    match t {
+        DTerm::Bound(k) => {
+            if *k == j {
+                s.clone()
+            } else {
+                DTerm::Bound(*k)
+            }
+        }
+        DTerm::Free(name) => DTerm::Free(name.clone()),
+        DTerm::Unit => DTerm::Unit,
+        DTerm::Trivial => DTerm::Trivial,
+        DTerm::Abs(x, body) => {
+            DTerm::Abs(x.clone(), Box::new(subst(j + 1, &shift(1, 0, s), body)))
+        }
+        DTerm::App(f, a) => DTerm::App(Box::new(subst(j, s, f)), Box::new(subst(j, s, a))),
+        DTerm::Pair(a, b) => DTerm::Pair(Box::new(subst(j, s, a)), Box::new(subst(j, s, b))),
+        DTerm::LetPair(x, y, pair, body) => DTerm::LetPair(
+            x.clone(),
+            y.clone(),
+            Box::new(subst(j, s, pair)),
+            Box::new(subst(j + 2, &shift(2, 0, s), body)),
+        ),
+        DTerm::Inl(e) => DTerm::Inl(Box::new(subst(j, s, e))),
+        DTerm::Inr(e) => DTerm::Inr(Box::new(subst(j, s, e))),
+        DTerm::Case(scrut, x, left, y, right) => DTerm::Case(
+            Box::new(subst(j, s, scrut)),
+            x.clone(),
+            Box::new(subst(j + 1, &shift(1, 0, s), left)),
+            y.clone(),
+            Box::new(subst(j + 1, &shift(1, 0, s), right)),
+        ),
+        DTerm::Fst(e) => DTerm::Fst(Box::new(subst(j, s, e))),
+        DTerm::Snd(e) => DTerm::Snd(Box::new(subst(j, s, e))),
+        DTerm::Promote(e) => DTerm::Promote(Box::new(subst(j, s, e))),
+        DTerm::Derelict(e) => DTerm::Derelict(Box::new(subst(j, s, e))),
+        DTerm::Copy(src, x, y, body) => DTerm::Copy(
+            Box::new(subst(j, s, src)),
+            x.clone(),
+            y.clone(),
+            Box::new(subst(j + 2, &shift(2, 0, s), body)),
+        ),
+        DTerm::Discard(discarded, body) => {
+            DTerm::Discard(Box::new(subst(j, s, discarded)), Box::new(subst(j, s, body)))
+        }
+        DTerm::Abort(e) => DTerm::Abort(Box::new(subst(j, s, e))),
+    }
+}
+
+/// Capture-avoiding substitution for a single-variable binder (`Abs`): the
+/// de Bruijn analog of `body.substitute(var, replacement)`, implementing
+/// `shift(-1, 0, subst(0, shift(1, 0, replacement), body))`.
+pub(crate) fn substitute1(body: &Term, var: &str, replacement: &Term) -> Term {
+    let mut body_ctx = vec![var.to_string()];
+    let body = to_debruijn(body, &mut body_ctx);
+    let replacement = to_debruijn(replacement, &mut Vec::new());
+
+    let substituted = subst(0, &shift(1, 0, &replacement), &body);
+    from_debruijn(&shift(-1, 0, &substituted), &mut Vec::new())
+}
+
+/// Capture-avoiding simultaneous substitution for a two-variable binder
+/// (`LetPair`, `Copy`): `x` (bound one level out) is replaced by `a`, `y`
+/// (bound innermost) by `b`, generalizing [`substitute1`] to two binders
+/// removed at once.
+pub(crate) fn substitute2(body: &Term, x: &str, a: &Term, y: &str, b: &Term) -> Term {
+    let mut body_ctx = vec![x.to_string(), y.to_string()];
+    let body = to_debruijn(body, &mut body_ctx);
+    let a = to_debruijn(a, &mut Vec::new());
+    let b = to_debruijn(b, &mut Vec::new());
+
+    let substituted = subst(1, &shift(2, 0, &a), &subst(0, &shift(2, 0, &b), &body));
+    from_debruijn(&shift(-2, 0, &substituted), &mut Vec::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_preserves_free_and_bound_names() {
+        let t = Term::Abs(
+            "x".to_string(),
+            Rc::new(Term::App(
+                Rc::new(Term::Var("x".to_string())),
+                Rc::new(Term::Var("y".to_string())),
+            )),
+        );
+        let d = to_debruijn(&t, &mut Vec::new());
+        assert_eq!(
+            d,
+            DTerm::Abs(
+                "x".to_string(),
+                Box::new(DTerm::App(
+                    Box::new(DTerm::Bound(0)),
+                    Box::new(DTerm::Free("y".to_string())),
+                ))
+            )
+        );
+        assert_eq!(from_debruijn(&d, &mut Vec::new()), t);
+    }
+
+    #[test]
+    fn test_substitute1_beta_reduces() {
+        // (λx. x) applied with `y` substituted for `x` → `y`.
+        let body = Term::Var("x".to_string());
+        let result = substitute1(&body, "x", &Term::Var("y".to_string()));
+        assert_eq!(result, Term::Var("y".to_string()));
+    }
+
+    #[test]
+    fn test_substitute1_avoids_capture() {
+        // λx. (λy. x) applied at the outer `x` with a replacement whose free
+        // variable is named `y` must not let that `y` get captured by the
+        // inner binder: substituting into `λy. x` for `x := y` should yield
+        // `λy'. y` (or any inner binder distinct from the substituted `y`),
+        // never `λy. y`.
+        let inner_abs = Term::Abs("y".to_string(), Rc::new(Term::Var("x".to_string())));
+        let replacement = Term::Var("y".to_string());
+        let result = substitute1(&inner_abs, "x", &replacement);
+
+        match result {
+            Term::Abs(bound, body) => {
+                assert_ne!(bound, "y", "the inner binder must not swallow the substituted free `y`");
+                assert_eq!(*body, Term::Var("y".to_string()));
+            }
+            other => panic!("expected Abs, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_substitute2_simultaneous_binding() {
+        // let (x, y) = ... in (x, y) [x := a, y := b] → (a, b)
+        let body = Term::Pair(
+            Rc::new(Term::Var("x".to_string())),
+            Rc::new(Term::Var("y".to_string())),
+        );
+        let result = substitute2(
+            &body,
+            "x",
+            &Term::Var("a".to_string()),
+            "y",
+            &Term::Var("b".to_string()),
+        );
+        assert_eq!(
+            result,
+            Term::Pair(
+                Rc::new(Term::Var("a".to_string())),
+                Rc::new(Term::Var("b".to_string())),
+            )
+        );
+    }
+}
@@ -3,8 +3,12 @@
 //! This module implements the Curry-Howard correspondence for linear logic,
 //! extracting computational terms from cut-free proofs.
 
+use std::rc::Rc;
+
 use lolli_core::{Formula, Proof, Rule, Term};
 
+use crate::typecheck::{self, TypeError};
+
 /// Term extractor using Curry-Howard correspondence.
 ///
 /// Extracts linear lambda terms from linear logic proofs.
@@ -112,9 +116,9 @@ impl Extractor {
                 // Plus left corresponds to: inl a
                 if !proof.premises.is_empty() {
                     let inner = self.extract_with_env(&proof.premises[0], vars);
-                    Term::Inl(Box::new(inner))
+                    Term::Inl(Rc::new(inner))
                 } else {
-                    Term::Inl(Box::new(Term::Unit))
+                    Term::Inl(Rc::new(Term::Unit))
                 }
             }
 
@@ -123,9 +127,9 @@ impl Extractor {
                 // Plus right corresponds to: inr b
                 if !proof.premises.is_empty() {
                     let inner = self.extract_with_env(&proof.premises[0], vars);
-                    Term::Inr(Box::new(inner))
+                    Term::Inr(Rc::new(inner))
                 } else {
-                    Term::Inr(Box::new(Term::Unit))
+                    Term::Inr(Rc::new(Term::Unit))
                 }
             }
 
@@ -134,9 +138,9 @@ impl Extractor {
                 // Promote the term to be copyable
                 if !proof.premises.is_empty() {
                     let inner = self.extract_with_env(&proof.premises[0], vars);
-                    Term::Promote(Box::new(inner))
+                    Term::Promote(Rc::new(inner))
                 } else {
-                    Term::Promote(Box::new(Term::Unit))
+                    Term::Promote(Rc::new(Term::Unit))
                 }
             }
 
@@ -154,7 +158,7 @@ impl Extractor {
                 // Discard a resource
                 if !proof.premises.is_empty() {
                     let body = self.extract_with_env(&proof.premises[0], vars);
-                    Term::Discard(Box::new(Term::Unit), Box::new(body))
+                    Term::Discard(Rc::new(Term::Unit), Rc::new(body))
                 } else {
                     Term::Unit
                 }
@@ -168,7 +172,7 @@ impl Extractor {
                     let y = self.fresh_var();
                     let src = Term::Var(self.fresh_var());
                     let body = self.extract_with_env(&proof.premises[0], vars);
-                    Term::Copy(Box::new(src), x, y, Box::new(body))
+                    Term::Copy(Rc::new(src), x, y, Rc::new(body))
                 } else {
                     Term::Unit
                 }
@@ -178,9 +182,9 @@ impl Extractor {
                 // Use !A as A
                 if !proof.premises.is_empty() {
                     let inner = self.extract_with_env(&proof.premises[0], vars);
-                    Term::Derelict(Box::new(inner))
+                    Term::Derelict(Rc::new(inner))
                 } else {
-                    Term::Derelict(Box::new(Term::Unit))
+                    Term::Derelict(Rc::new(Term::Unit))
                 }
             }
 
@@ -201,6 +205,17 @@ impl Extractor {
         }
     }
 
+    /// Assert that `term` is a linearly well-typed realizer of `proof`'s
+    /// conclusion: an optional post-condition on [`Extractor::extract`].
+    ///
+    /// `extract` doesn't run this itself, since trusting the extraction is
+    /// cheaper than re-checking it; call this when that trust needs
+    /// verifying, e.g. before handing the term to [`crate::emit_rust`] or
+    /// bundling it for export with `lolli-xml`.
+    pub fn check_extraction(proof: &Proof, term: &Term) -> Result<(), TypeError> {
+        typecheck::check_proof(term, &proof.conclusion)
+    }
+
     /// Extract term for axiom rule.
     fn extract_axiom(&mut self, proof: &Proof, vars: &[(Formula, String)]) -> Term {
         // The axiom ⊢ A⊥, A represents identity
@@ -238,7 +253,7 @@ impl Extractor {
 
         // Default: create identity function
         let var = self.fresh_var();
-        Term::Abs(var.clone(), Box::new(Term::Var(var)))
+        Term::Abs(var.clone(), Rc::new(Term::Var(var)))
     }
 
     /// Extract term for tensor introduction.
@@ -246,7 +261,7 @@ impl Extractor {
         if proof.premises.len() == 2 {
             let left = self.extract_with_env(&proof.premises[0], vars);
             let right = self.extract_with_env(&proof.premises[1], vars);
-            Term::Pair(Box::new(left), Box::new(right))
+            Term::Pair(Rc::new(left), Rc::new(right))
         } else if proof.premises.len() == 1 {
             self.extract_with_env(&proof.premises[0], vars)
         } else {
@@ -257,10 +272,16 @@ impl Extractor {
     /// Extract term for with introduction.
     fn extract_with(&mut self, proof: &Proof, vars: &mut Vec<(Formula, String)>) -> Term {
         if proof.premises.len() == 2 {
-            let left = self.extract_with_env(&proof.premises[0], vars);
-            let right = self.extract_with_env(&proof.premises[1], vars);
-            // With creates a pair that can be projected
-            Term::Pair(Box::new(left), Box::new(right))
+            // `&` is additive: both premises derive the same context, but
+            // only one side is ever forced, so extract each from its own
+            // copy of the environment rather than threading one through
+            // both in sequence.
+            let mut left_vars = vars.clone();
+            let left = self.extract_with_env(&proof.premises[0], &mut left_vars);
+            let mut right_vars = vars.clone();
+            let right = self.extract_with_env(&proof.premises[1], &mut right_vars);
+            // With creates a lazy pair; `Fst`/`Snd` project out each side.
+            Term::Pair(Rc::new(left), Rc::new(right))
         } else if proof.premises.len() == 1 {
             self.extract_with_env(&proof.premises[0], vars)
         } else {
@@ -285,34 +306,64 @@ impl Extractor {
         // Extract the term that produces the cut formula
         let producer = self.extract_with_env(&proof.premises[0], vars);
 
-        // Add the cut variable to the environment for the consumer
-        vars.push((cut_formula.clone(), cut_var.clone()));
-        let consumer = self.extract_with_env(&proof.premises[1], vars);
-        vars.pop();
-
-        // Cut corresponds to application or let-binding depending on the formula
+        // Cut corresponds to application, let-binding, or a case/pair
+        // destructor depending on the formula
         match cut_formula {
             Formula::Tensor(_, _) => {
+                vars.push((cut_formula.clone(), cut_var.clone()));
+                let consumer = self.extract_with_env(&proof.premises[1], vars);
+                vars.pop();
+
                 // Tensor cut becomes let-pair
                 let x = self.fresh_var();
                 let y = self.fresh_var();
-                Term::LetPair(x, y, Box::new(producer), Box::new(consumer))
+                Term::LetPair(x, y, Rc::new(producer), Rc::new(consumer))
             }
             Formula::Plus(_, _) => {
-                // Plus cut becomes case
-                let x = self.fresh_var();
-                let y = self.fresh_var();
+                // Plus cut becomes case, eliminating a choice between two
+                // injections. Each arm gets its own extraction of the
+                // consumer (not one body shared by cloning) bound under
+                // the cut variable itself, so whichever injection fires,
+                // the consumer's own references to it resolve correctly.
+                vars.push((cut_formula.clone(), cut_var.clone()));
+                let left = self.extract_with_env(&proof.premises[1], vars);
+                let right = self.extract_with_env(&proof.premises[1], vars);
+                vars.pop();
+
                 Term::Case(
-                    Box::new(producer),
-                    x,
-                    Box::new(consumer.clone()),
-                    y,
-                    Box::new(consumer),
+                    Rc::new(producer),
+                    cut_var.clone(),
+                    Rc::new(left),
+                    cut_var,
+                    Rc::new(right),
                 )
             }
+            Formula::With(_, _) => {
+                // Unlike `Plus`, where the *producer* picks a branch and
+                // the consumer must be ready for either, here the
+                // *consumer* picks which projection it needs. So there's
+                // only one consumer to extract (as in the `Tensor`/default
+                // cases below) — the cut variable is substituted by the pair
+                // of the producer's two projections, so its own references
+                // to it reach whichever `Fst`/`Snd` of the producer it
+                // actually uses.
+                vars.push((cut_formula.clone(), cut_var.clone()));
+                let consumer = self.extract_with_env(&proof.premises[1], vars);
+                vars.pop();
+
+                let projections = Term::Pair(
+                    Rc::new(Term::Fst(Rc::new(producer.clone()))),
+                    Rc::new(Term::Snd(Rc::new(producer))),
+                );
+                consumer.substitute(&cut_var, &projections)
+            }
             _ => {
+                vars.push((cut_formula.clone(), cut_var.clone()));
+                let consumer = self.extract_with_env(&proof.premises[1], vars);
+                vars.pop();
+
                 // Default: application
-                Term::App(Box::new(consumer), Box::new(producer))
+                Term::App(Rc::new(consumer), Rc::new(producer))
             }
         }
     }
@@ -467,6 +518,91 @@ mod tests {
         assert!(matches!(term, Term::Pair(_, _)));
     }
 
+    #[test]
+    fn test_extract_cut_plus_binds_same_variable_in_both_case_arms() {
+        let inner = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let producer = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::neg_atom("A"),
+                Formula::plus(Formula::atom("A"), Formula::atom("B")),
+            ]),
+            rule: Rule::PlusIntroLeft,
+            premises: vec![inner],
+        };
+        let consumer = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("C"), Formula::atom("C")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("C")]),
+            rule: Rule::Cut(Formula::plus(Formula::atom("A"), Formula::atom("B"))),
+            premises: vec![producer, consumer],
+        };
+
+        let mut extractor = Extractor::new();
+        let term = extractor.extract(&proof);
+
+        // The two arms must share one binder: each is a separately
+        // extracted continuation for its own injection, not a clone of a
+        // single body bound under unrelated fresh names.
+        match term {
+            Term::Case(_, x, _, y, _) => assert_eq!(x, y),
+            other => panic!("expected a Case term, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_extract_cut_with_projects_via_fst_and_snd() {
+        let p = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("P")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let q = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("Q")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let producer = Proof {
+            conclusion: Sequent::new(vec![Formula::with(Formula::atom("P"), Formula::atom("Q"))]),
+            rule: Rule::WithIntro,
+            premises: vec![p, q],
+        };
+        // Its name is chosen to land exactly on the cut variable the
+        // extractor generates for this (compound, so counter-named) cut
+        // formula, so its extraction substitutes visibly.
+        let consumer = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("X0")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("X0")]),
+            rule: Rule::Cut(Formula::with(Formula::atom("P"), Formula::atom("Q"))),
+            premises: vec![producer, consumer],
+        };
+
+        let mut extractor = Extractor::new();
+        let term = extractor.extract(&proof);
+
+        let pair = Term::Pair(
+            Rc::new(Term::Var("p".to_string())),
+            Rc::new(Term::Var("q".to_string())),
+        );
+        assert_eq!(
+            term,
+            Term::Pair(
+                Rc::new(Term::Fst(Rc::new(pair.clone()))),
+                Rc::new(Term::Snd(Rc::new(pair))),
+            )
+        );
+    }
+
     #[test]
     fn test_extract_promote() {
         let inner = Proof {
@@ -487,6 +623,34 @@ mod tests {
         assert!(matches!(term, Term::Promote(_)));
     }
 
+    #[test]
+    fn test_check_extraction_accepts_a_sound_tensor_proof() {
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("B"), Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::neg_atom("A"),
+                Formula::neg_atom("B"),
+                Formula::tensor(Formula::atom("A"), Formula::atom("B")),
+            ]),
+            rule: Rule::TensorIntro,
+            premises: vec![left, right],
+        };
+
+        let mut extractor = Extractor::new();
+        let term = extractor.extract(&proof);
+
+        assert_eq!(Extractor::check_extraction(&proof, &term), Ok(()));
+    }
+
     #[test]
     fn test_pretty_print() {
         let proof = Proof {
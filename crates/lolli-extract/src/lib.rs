@@ -43,11 +43,25 @@
 
 pub use lolli_core::{Formula, Proof, Rule, Sequent, Term};
 
+mod builtin;
+mod codegen;
+mod debruijn;
 mod extract;
+mod nd;
 mod normalize;
+mod run;
+mod typecheck;
 
+pub use builtin::{Builtin, Literal, FOLD_BOUND};
+pub use codegen::{emit_ocaml, emit_rust, emit_rust_fn, CodeGen, Target, EITHER_PRELUDE};
 pub use extract::Extractor;
-pub use normalize::{is_normal, normalize, normalize_bounded, step};
+pub use nd::{to_natural_deduction, NdJudgment, NdProof, NdRule, TranslationError};
+pub use normalize::{
+    is_normal, normalize, normalize_bounded, normalize_trace, normalize_with, step, step_with,
+    whnf, PathStep, ReductionStep, RuleKind, Strategy,
+};
+pub use run::{parse_value, run_term, ValueError};
+pub use typecheck::{check, check_proof, TypeError};
 
 /// Extract a term from a proof (convenience function).
 ///
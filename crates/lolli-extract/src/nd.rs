@@ -0,0 +1,498 @@
+//! Sequent-to-natural-deduction translation.
+//!
+//! LinearOne's sequent-calculus [`Proof`]s are one-sided: a node's
+//! conclusion is a single list of formulas, all on the same side of the
+//! turnstile. This module re-orients such a proof into a two-sided
+//! intuitionistic/linear natural-deduction judgment `Γ ⊢ A`, by treating
+//! every non-principal formula as a negative-polarity hypothesis (moved to
+//! `Γ` via [`Formula::negate`]) and the rule's principal formula as the
+//! goal `A`. Introduction rules for ⊗/⊸/&/⊕/! become the matching
+//! constructor/abstraction steps, and axiom becomes hypothesis/identity.
+//!
+//! Not every one-sided proof has a natural-deduction image: a genuinely
+//! classical use of `⅋` (one that is not `⊸`'s `A⊥ ⅋ B` desugaring) has no
+//! intuitionistic elimination rule, and a conclusion with more than one
+//! positive-polarity formula has no single goal to re-orient onto.
+//! [`to_natural_deduction`] reports these as a [`TranslationError`] rather
+//! than guessing.
+//!
+//! The resulting [`NdProof`] reuses [`Extractor`] for each node's term, so
+//! the natural-deduction derivation and the extracted lambda term stay in
+//! the same correspondence [`Extractor::extract`] already establishes for
+//! the underlying sequent proof.
+
+use lolli_core::{Formula, Proof, Rule, Sequent, Term};
+
+use crate::extract::Extractor;
+
+/// A two-sided natural-deduction judgment: hypotheses `context` entail `goal`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NdJudgment {
+    /// The hypotheses available to this judgment.
+    pub context: Vec<Formula>,
+    /// The formula this judgment concludes.
+    pub goal: Formula,
+}
+
+impl NdJudgment {
+    /// Pretty-print as `A, B ⊢ C`.
+    pub fn pretty(&self) -> String {
+        let context = self
+            .context
+            .iter()
+            .map(Formula::pretty)
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{} ⊢ {}", context, self.goal.pretty())
+    }
+}
+
+/// Which natural-deduction introduction rule a [`NdProof`] node applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NdRule {
+    /// Hypothesis/identity (the one-sided axiom, re-oriented).
+    Hyp,
+    /// `1`-introduction.
+    OneIntro,
+    /// `⊤`-introduction.
+    TopIntro,
+    /// `⊥`-introduction.
+    BottomIntro,
+    /// `⊗`-introduction (pairing).
+    TensorIntro,
+    /// `⊸`-introduction (hypothesis discharge / abstraction).
+    LolliIntro,
+    /// `&`-introduction (lazy pairing).
+    WithIntro,
+    /// `⊕`-introduction, left injection.
+    PlusIntroLeft,
+    /// `⊕`-introduction, right injection.
+    PlusIntroRight,
+    /// `!`-introduction (promotion).
+    OfCourseIntro,
+    /// `?`-introduction.
+    WhyNotIntro,
+}
+
+impl NdRule {
+    /// A short rule label, as would appear to the right of an inference bar.
+    pub fn label(&self) -> &'static str {
+        match self {
+            NdRule::Hyp => "Hyp",
+            NdRule::OneIntro => "1I",
+            NdRule::TopIntro => "⊤I",
+            NdRule::BottomIntro => "⊥I",
+            NdRule::TensorIntro => "⊗I",
+            NdRule::LolliIntro => "⊸I",
+            NdRule::WithIntro => "&I",
+            NdRule::PlusIntroLeft => "⊕I_L",
+            NdRule::PlusIntroRight => "⊕I_R",
+            NdRule::OfCourseIntro => "!I",
+            NdRule::WhyNotIntro => "?I",
+        }
+    }
+}
+
+/// A natural-deduction derivation tree, translated from a sequent-calculus
+/// [`Proof`] by [`to_natural_deduction`].
+#[derive(Debug, Clone)]
+pub struct NdProof {
+    /// The judgment this node concludes.
+    pub judgment: NdJudgment,
+    /// The introduction rule justifying that judgment.
+    pub rule: NdRule,
+    /// The extracted lambda term realizing this judgment (see module docs).
+    pub term: Term,
+    /// This node's sub-derivations.
+    pub premises: Vec<NdProof>,
+}
+
+impl NdProof {
+    /// Pretty-print the derivation tree, indenting each premise under its
+    /// conclusion.
+    pub fn pretty(&self) -> String {
+        let mut out = String::new();
+        self.pretty_into(0, &mut out);
+        out
+    }
+
+    fn pretty_into(&self, depth: usize, out: &mut String) {
+        let indent = "  ".repeat(depth);
+        out.push_str(&format!(
+            "{}{}   [{}]\n",
+            indent,
+            self.judgment.pretty(),
+            self.rule.label()
+        ));
+        for premise in &self.premises {
+            premise.pretty_into(depth + 1, out);
+        }
+    }
+}
+
+/// A sequent-calculus fragment with no natural-deduction image.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum TranslationError {
+    /// The conclusion has more than one (or zero) positive-polarity
+    /// formulas, so there's no single goal to re-orient the rest of the
+    /// context onto.
+    #[error("conclusion {conclusion} has no single positive formula to use as a natural-deduction goal")]
+    NotSingleConclusion {
+        /// The conclusion that couldn't be re-oriented.
+        conclusion: String,
+    },
+
+    /// A `⅋`-introduction whose left operand isn't a discharged
+    /// hypothesis (i.e. isn't `⊸`'s `A⊥ ⅋ B` desugaring): a genuinely
+    /// classical use of par, with no intuitionistic elimination rule.
+    #[error("par-introduction for {conclusion} is not `-o` sugar and has no natural-deduction image")]
+    UnsupportedPar {
+        /// The conclusion containing the unsupported par.
+        conclusion: String,
+    },
+
+    /// This rule has no direct natural-deduction introduction-rule
+    /// counterpart (structural exponential rules, cut, and the prover's
+    /// internal focusing rules all fall here).
+    #[error("rule {rule:?} has no natural-deduction image")]
+    UnsupportedRule {
+        /// The rule that couldn't be translated.
+        rule: Rule,
+    },
+}
+
+/// Translate a verified sequent-calculus proof into a natural-deduction
+/// derivation.
+///
+/// # Errors
+///
+/// Returns a [`TranslationError`] if `proof` (or one of its subproofs) uses
+/// a fragment with no natural-deduction image; see the module docs.
+pub fn to_natural_deduction(proof: &Proof) -> Result<NdProof, TranslationError> {
+    let mut extractor = Extractor::new();
+    translate(proof, &mut extractor)
+}
+
+fn translate(proof: &Proof, extractor: &mut Extractor) -> Result<NdProof, TranslationError> {
+    let (context, goal) = reorient(proof)?;
+    let rule = nd_rule(&proof.rule)?;
+    let term = extractor.extract(proof);
+
+    let mut premises = Vec::with_capacity(proof.premises.len());
+    for premise in &proof.premises {
+        premises.push(translate(premise, extractor)?);
+    }
+
+    Ok(NdProof {
+        judgment: NdJudgment { context, goal },
+        rule,
+        term,
+        premises,
+    })
+}
+
+fn nd_rule(rule: &Rule) -> Result<NdRule, TranslationError> {
+    match rule {
+        Rule::Axiom => Ok(NdRule::Hyp),
+        Rule::OneIntro => Ok(NdRule::OneIntro),
+        Rule::TopIntro => Ok(NdRule::TopIntro),
+        Rule::BottomIntro => Ok(NdRule::BottomIntro),
+        Rule::TensorIntro => Ok(NdRule::TensorIntro),
+        Rule::ParIntro => Ok(NdRule::LolliIntro),
+        Rule::WithIntro => Ok(NdRule::WithIntro),
+        Rule::PlusIntroLeft => Ok(NdRule::PlusIntroLeft),
+        Rule::PlusIntroRight => Ok(NdRule::PlusIntroRight),
+        Rule::OfCourseIntro => Ok(NdRule::OfCourseIntro),
+        Rule::WhyNotIntro => Ok(NdRule::WhyNotIntro),
+        other => Err(TranslationError::UnsupportedRule { rule: other.clone() }),
+    }
+}
+
+/// Re-orient a proof's one-sided conclusion into a two-sided judgment: find
+/// the rule's principal formula as the goal, and negate every other
+/// formula into a hypothesis.
+fn reorient(proof: &Proof) -> Result<(Vec<Formula>, Formula), TranslationError> {
+    let seq = &proof.conclusion;
+    let linear = &seq.linear;
+
+    match &proof.rule {
+        Rule::Axiom => {
+            let positive = linear.iter().position(|f| f.is_positive());
+            let Some(i) = positive else {
+                return Err(TranslationError::NotSingleConclusion {
+                    conclusion: seq.pretty(),
+                });
+            };
+            principal_at(linear, seq, i)
+        }
+        Rule::OneIntro => principal_matching(linear, seq, |f| matches!(f, Formula::One)),
+        Rule::TopIntro => reorient_top(linear, seq),
+        Rule::BottomIntro => principal_matching(linear, seq, |f| matches!(f, Formula::Bottom)),
+        Rule::TensorIntro => principal_matching(linear, seq, |f| matches!(f, Formula::Tensor(_, _))),
+        Rule::WithIntro => principal_matching(linear, seq, |f| matches!(f, Formula::With(_, _))),
+        Rule::PlusIntroLeft | Rule::PlusIntroRight => {
+            principal_matching(linear, seq, |f| matches!(f, Formula::Plus(_, _)))
+        }
+        Rule::OfCourseIntro => principal_matching(linear, seq, |f| matches!(f, Formula::OfCourse(_))),
+        Rule::WhyNotIntro => principal_matching(linear, seq, |f| matches!(f, Formula::WhyNot(_))),
+        Rule::ParIntro => reorient_par(linear, seq),
+        other => Err(TranslationError::UnsupportedRule { rule: other.clone() }),
+    }
+}
+
+/// Find the (first) formula in `linear` matching `is_principal`, treat it
+/// as the goal, and negate the rest into hypotheses.
+fn principal_matching(
+    linear: &[Formula],
+    seq: &Sequent,
+    is_principal: impl Fn(&Formula) -> bool,
+) -> Result<(Vec<Formula>, Formula), TranslationError> {
+    let i = linear
+        .iter()
+        .position(|f| is_principal(f))
+        .ok_or_else(|| TranslationError::NotSingleConclusion {
+            conclusion: seq.pretty(),
+        })?;
+    principal_at(linear, seq, i)
+}
+
+/// Treat `linear[i]` as the goal and negate every other formula into a
+/// hypothesis, failing if one of them is itself positive-polarity (and so
+/// has no business being a hypothesis).
+fn principal_at(
+    linear: &[Formula],
+    seq: &Sequent,
+    i: usize,
+) -> Result<(Vec<Formula>, Formula), TranslationError> {
+    let goal = linear[i].clone();
+    let rest: Vec<Formula> = linear
+        .iter()
+        .enumerate()
+        .filter(|(j, _)| *j != i)
+        .map(|(_, f)| f.clone())
+        .collect();
+    let context = context_from_rest(&rest, seq)?;
+    Ok((context, goal))
+}
+
+/// Re-orient a `⊤`-introduction. `⊤` discharges *any* leftover context
+/// unconditionally — that's the whole point of the rule (see `verify_proof`
+/// and `test_verify_top` in `lolli-prove`, which accept `⊢ A, ⊤` for an
+/// arbitrary, even unprovable, positive atom `A`) — so unlike every other
+/// rule here, the rest of the conclusion isn't required to be
+/// hypothesis-shaped (negative-polarity); it's simply discarded.
+fn reorient_top(linear: &[Formula], seq: &Sequent) -> Result<(Vec<Formula>, Formula), TranslationError> {
+    let i = linear
+        .iter()
+        .position(|f| matches!(f, Formula::Top))
+        .ok_or_else(|| TranslationError::NotSingleConclusion {
+            conclusion: seq.pretty(),
+        })?;
+
+    let goal = linear[i].clone();
+    let context = linear
+        .iter()
+        .enumerate()
+        .filter(|(j, _)| *j != i)
+        .map(|(_, f)| f.negate())
+        .collect();
+    Ok((context, goal))
+}
+
+/// Negate every formula in `rest` into a hypothesis, rejecting any that is
+/// itself positive-polarity: such a formula is a second, unrelated
+/// conclusion this one-sided proof happens to carry alongside the real
+/// goal, which a single-conclusion natural-deduction judgment can't
+/// represent.
+fn context_from_rest(rest: &[Formula], seq: &Sequent) -> Result<Vec<Formula>, TranslationError> {
+    let mut context = Vec::with_capacity(rest.len());
+    for formula in rest {
+        if !formula.is_negative() {
+            return Err(TranslationError::NotSingleConclusion {
+                conclusion: seq.pretty(),
+            });
+        }
+        context.push(formula.negate());
+    }
+    Ok(context)
+}
+
+/// Re-orient a `⅋`-introduction. Only supports the case where it is `⊸`'s
+/// `A⊥ ⅋ B` desugaring (left operand is a discharged hypothesis); a
+/// genuine multiplicative disjunction between two positive formulas has no
+/// natural-deduction image.
+fn reorient_par(linear: &[Formula], seq: &Sequent) -> Result<(Vec<Formula>, Formula), TranslationError> {
+    let i = linear
+        .iter()
+        .position(|f| matches!(f, Formula::Par(_, _)))
+        .ok_or_else(|| TranslationError::NotSingleConclusion {
+            conclusion: seq.pretty(),
+        })?;
+
+    let Formula::Par(a, b) = &linear[i] else {
+        unreachable!("position() just matched a Par formula")
+    };
+
+    if !a.is_negative() {
+        return Err(TranslationError::UnsupportedPar {
+            conclusion: seq.pretty(),
+        });
+    }
+
+    let rest: Vec<Formula> = linear
+        .iter()
+        .enumerate()
+        .filter(|(j, _)| *j != i)
+        .map(|(_, f)| f.clone())
+        .collect();
+    let context = context_from_rest(&rest, seq)?;
+    let goal = Formula::Lolli(Box::new(a.negate()), b.clone());
+    Ok((context, goal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lolli_core::Sequent;
+
+    #[test]
+    fn test_axiom_becomes_hypothesis() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+
+        let nd = to_natural_deduction(&proof).unwrap();
+        assert_eq!(nd.rule, NdRule::Hyp);
+        assert_eq!(nd.judgment.context, vec![Formula::atom("A")]);
+        assert_eq!(nd.judgment.goal, Formula::atom("A"));
+    }
+
+    #[test]
+    fn test_top_discharges_a_positive_leftover() {
+        // ⊢ A, ⊤ for an arbitrary (even unprovable) positive atom A — the
+        // same baseline-valid proof lolli-prove's test_verify_top accepts.
+        // ⊤ discharges A unconditionally; it must not be rejected for not
+        // being hypothesis-shaped (negative-polarity), the way a genuine
+        // multi-conclusion rule's leftover context would be.
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A"), Formula::Top]),
+            rule: Rule::TopIntro,
+            premises: vec![],
+        };
+
+        let nd = to_natural_deduction(&proof).unwrap();
+        assert_eq!(nd.rule, NdRule::TopIntro);
+        assert_eq!(nd.judgment.goal, Formula::Top);
+        assert_eq!(nd.term, Term::Trivial);
+    }
+
+    #[test]
+    fn test_tensor_introduces_a_pair() {
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("B"), Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::neg_atom("A"),
+                Formula::neg_atom("B"),
+                Formula::tensor(Formula::atom("A"), Formula::atom("B")),
+            ]),
+            rule: Rule::TensorIntro,
+            premises: vec![left, right],
+        };
+
+        let nd = to_natural_deduction(&proof).unwrap();
+        assert_eq!(nd.rule, NdRule::TensorIntro);
+        assert_eq!(nd.judgment.goal, Formula::tensor(Formula::atom("A"), Formula::atom("B")));
+        assert_eq!(nd.premises.len(), 2);
+        assert!(matches!(nd.term, Term::Pair(_, _)));
+    }
+
+    #[test]
+    fn test_lolli_sugar_par_becomes_lolli_intro() {
+        // ⊢ A⊥ ⅋ B from ⊢ A⊥, B (a ⊸-intro in disguise: discharging A⊥'s
+        // dual hypothesis A).
+        let inner = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::neg_atom("A"),
+                Formula::atom("B"),
+            ]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::par(Formula::neg_atom("A"), Formula::atom("B"))]),
+            rule: Rule::ParIntro,
+            premises: vec![inner],
+        };
+
+        let nd = to_natural_deduction(&proof).unwrap();
+        assert_eq!(nd.rule, NdRule::LolliIntro);
+        assert_eq!(
+            nd.judgment.goal,
+            Formula::Lolli(Box::new(Formula::atom("A")), Box::new(Formula::atom("B")))
+        );
+        assert!(nd.judgment.context.is_empty());
+    }
+
+    #[test]
+    fn test_genuine_par_is_rejected() {
+        // Par of two positive formulas is not ⊸ sugar: no ND image.
+        let inner = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A"), Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::par(Formula::atom("A"), Formula::atom("B"))]),
+            rule: Rule::ParIntro,
+            premises: vec![inner],
+        };
+
+        assert!(matches!(
+            to_natural_deduction(&proof),
+            Err(TranslationError::UnsupportedPar { .. })
+        ));
+    }
+
+    #[test]
+    fn test_unsupported_rule_is_rejected() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A")]),
+            rule: Rule::Weakening,
+            premises: vec![Proof {
+                conclusion: Sequent::new(vec![]),
+                rule: Rule::OneIntro,
+                premises: vec![],
+            }],
+        };
+
+        assert!(matches!(
+            to_natural_deduction(&proof),
+            Err(TranslationError::UnsupportedRule { .. })
+        ));
+    }
+
+    #[test]
+    fn test_pretty_includes_context_goal_and_rule_label() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+
+        let nd = to_natural_deduction(&proof).unwrap();
+        let pretty = nd.pretty();
+        assert!(pretty.contains('⊢'));
+        assert!(pretty.contains("[Hyp]"));
+    }
+}
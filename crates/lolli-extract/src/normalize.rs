@@ -2,9 +2,30 @@
 //!
 //! This module provides normalization (beta reduction) for linear lambda terms.
 //! Since terms are linear, reduction is strongly normalizing.
+//!
+//! Extracting from a proof that still contains `Cut`s yields a term with
+//! redexes at the corresponding positions (an `App` of an `Abs`, a `Case` of
+//! an `Inl`/`Inr`, and so on); normalizing that term performs cut-elimination
+//! on the computational side, so a proof with cuts and its cut-free
+//! counterpart extract to the same normal form.
+//!
+//! [`step`] additionally performs delta reduction on saturated
+//! [`Builtin`](crate::builtin::Builtin) applications — see
+//! [`crate::builtin`] for the primitive data layer this extends the
+//! otherwise purely logical term language with.
+//!
+//! `Term`'s recursive fields hold their subterms behind `Rc`, not `Box`, so
+//! every `.clone()` below (`arg.clone()`, `body.clone()`, ...) is a pointer
+//! bump, not a deep copy of the subtree it refers to. A structural copy only
+//! happens where a redex actually rewrites a node, via `Rc::new`.
+
+use std::rc::Rc;
 
 use lolli_core::Term;
 
+use crate::builtin::apply_builtin;
+use crate::debruijn::{substitute1, substitute2};
+
 /// Perform one step of reduction, if possible.
 ///
 /// Returns `Some(reduced)` if a reduction was performed, `None` if the term is normal.
@@ -14,10 +35,11 @@ use lolli_core::Term;
 /// ```
 /// use lolli_extract::step;
 /// use lolli_core::Term;
+/// use std::rc::Rc;
 ///
 /// let t = Term::App(
-///     Box::new(Term::Abs("x".to_string(), Box::new(Term::Var("x".to_string())))),
-///     Box::new(Term::Unit),
+///     Rc::new(Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())))),
+///     Rc::new(Term::Unit),
 /// );
 ///
 /// let reduced = step(&t);
@@ -28,13 +50,19 @@ pub fn step(term: &Term) -> Option<Term> {
         // Beta reduction: (λx. e) v → e[v/x]
         Term::App(f, arg) => {
             if let Term::Abs(x, body) = f.as_ref() {
-                Some(body.substitute(x, arg))
+                Some(substitute1(body, x, arg))
+            } else if let Some(reduced) = apply_builtin(term, normalize) {
+                // Delta reduction: a saturated builtin application, e.g.
+                // `is_zero 0 → inl ⟨⟩`. `apply_builtin` forces only the one
+                // argument the builtin needs and leaves everything else
+                // (including under-applied builtins) untouched.
+                Some(reduced)
             } else {
                 // Try to reduce the function
                 if let Some(f_reduced) = step(f) {
-                    Some(Term::App(Box::new(f_reduced), arg.clone()))
+                    Some(Term::App(Rc::new(f_reduced), arg.clone()))
                 } else if let Some(arg_reduced) = step(arg) {
-                    Some(Term::App(f.clone(), Box::new(arg_reduced)))
+                    Some(Term::App(f.clone(), Rc::new(arg_reduced)))
                 } else {
                     None
                 }
@@ -44,13 +72,13 @@ pub fn step(term: &Term) -> Option<Term> {
         // Let-pair reduction: let (x, y) = (a, b) in e → e[a/x][b/y]
         Term::LetPair(x, y, pair, body) => {
             if let Term::Pair(a, b) = pair.as_ref() {
-                let substituted = body.substitute(x, a).substitute(y, b);
+                let substituted = substitute2(body, x, a, y, b);
                 Some(substituted)
             } else if let Some(pair_reduced) = step(pair) {
                 Some(Term::LetPair(
                     x.clone(),
                     y.clone(),
-                    Box::new(pair_reduced),
+                    Rc::new(pair_reduced),
                     body.clone(),
                 ))
             } else if let Some(body_reduced) = step(body) {
@@ -58,7 +86,7 @@ pub fn step(term: &Term) -> Option<Term> {
                     x.clone(),
                     y.clone(),
                     pair.clone(),
-                    Box::new(body_reduced),
+                    Rc::new(body_reduced),
                 ))
             } else {
                 None
@@ -68,19 +96,29 @@ pub fn step(term: &Term) -> Option<Term> {
         // Case reduction: case inl v of { inl x => e1 | inr y => e2 } → e1[v/x]
         Term::Case(scrut, x, left, y, right) => {
             match scrut.as_ref() {
-                Term::Inl(v) => Some(left.substitute(x, v)),
-                Term::Inr(v) => Some(right.substitute(y, v)),
+                Term::Inl(v) => Some(substitute1(left, x, v)),
+                Term::Inr(v) => Some(substitute1(right, y, v)),
                 _ => {
                     if let Some(scrut_reduced) = step(scrut) {
                         Some(Term::Case(
-                            Box::new(scrut_reduced),
+                            Rc::new(scrut_reduced),
                             x.clone(),
                             left.clone(),
                             y.clone(),
                             right.clone(),
                         ))
+                    } else if let Some(left_reduced) = step(left) {
+                        Some(Term::Case(
+                            scrut.clone(),
+                            x.clone(),
+                            Rc::new(left_reduced),
+                            y.clone(),
+                            right.clone(),
+                        ))
                     } else {
-                        None
+                        step(right).map(|right_reduced| {
+                            Term::Case(scrut.clone(), x.clone(), left.clone(), y.clone(), Rc::new(right_reduced))
+                        })
                     }
                 }
             }
@@ -91,7 +129,7 @@ pub fn step(term: &Term) -> Option<Term> {
             if let Term::Pair(a, _) = pair.as_ref() {
                 Some(a.as_ref().clone())
             } else if let Some(pair_reduced) = step(pair) {
-                Some(Term::Fst(Box::new(pair_reduced)))
+                Some(Term::Fst(Rc::new(pair_reduced)))
             } else {
                 None
             }
@@ -102,7 +140,7 @@ pub fn step(term: &Term) -> Option<Term> {
             if let Term::Pair(_, b) = pair.as_ref() {
                 Some(b.as_ref().clone())
             } else if let Some(pair_reduced) = step(pair) {
-                Some(Term::Snd(Box::new(pair_reduced)))
+                Some(Term::Snd(Rc::new(pair_reduced)))
             } else {
                 None
             }
@@ -113,7 +151,7 @@ pub fn step(term: &Term) -> Option<Term> {
             if let Term::Promote(v) = e.as_ref() {
                 Some(v.as_ref().clone())
             } else if let Some(e_reduced) = step(e) {
-                Some(Term::Derelict(Box::new(e_reduced)))
+                Some(Term::Derelict(Rc::new(e_reduced)))
             } else {
                 None
             }
@@ -123,17 +161,17 @@ pub fn step(term: &Term) -> Option<Term> {
         Term::Copy(src, x, y, body) => {
             if let Term::Promote(v) = src.as_ref() {
                 let promoted = Term::Promote(v.clone());
-                let substituted = body.substitute(x, &promoted).substitute(y, &promoted);
+                let substituted = substitute2(body, x, &promoted, y, &promoted);
                 Some(substituted)
             } else if let Some(src_reduced) = step(src) {
                 Some(Term::Copy(
-                    Box::new(src_reduced),
+                    Rc::new(src_reduced),
                     x.clone(),
                     y.clone(),
                     body.clone(),
                 ))
             } else {
-                None
+                step(body).map(|body_reduced| Term::Copy(src.clone(), x.clone(), y.clone(), Rc::new(body_reduced)))
             }
         }
 
@@ -142,9 +180,9 @@ pub fn step(term: &Term) -> Option<Term> {
             if matches!(discarded.as_ref(), Term::Promote(_)) {
                 Some(body.as_ref().clone())
             } else if let Some(discarded_reduced) = step(discarded) {
-                Some(Term::Discard(Box::new(discarded_reduced), body.clone()))
+                Some(Term::Discard(Rc::new(discarded_reduced), body.clone()))
             } else if let Some(body_reduced) = step(body) {
-                Some(Term::Discard(discarded.clone(), Box::new(body_reduced)))
+                Some(Term::Discard(discarded.clone(), Rc::new(body_reduced)))
             } else {
                 None
             }
@@ -152,27 +190,155 @@ pub fn step(term: &Term) -> Option<Term> {
 
         // Reduce inside abstractions
         Term::Abs(x, body) => {
-            step(body).map(|reduced| Term::Abs(x.clone(), Box::new(reduced)))
+            step(body).map(|reduced| Term::Abs(x.clone(), Rc::new(reduced)))
         }
 
         // Reduce inside pairs
         Term::Pair(a, b) => {
             if let Some(a_reduced) = step(a) {
-                Some(Term::Pair(Box::new(a_reduced), b.clone()))
+                Some(Term::Pair(Rc::new(a_reduced), b.clone()))
             } else {
-                step(b).map(|b_reduced| Term::Pair(a.clone(), Box::new(b_reduced)))
+                step(b).map(|b_reduced| Term::Pair(a.clone(), Rc::new(b_reduced)))
             }
         }
 
         // Reduce inside injections
-        Term::Inl(e) => step(e).map(|reduced| Term::Inl(Box::new(reduced))),
-        Term::Inr(e) => step(e).map(|reduced| Term::Inr(Box::new(reduced))),
+        Term::Inl(e) => step(e).map(|reduced| Term::Inl(Rc::new(reduced))),
+        Term::Inr(e) => step(e).map(|reduced| Term::Inr(Rc::new(reduced))),
 
         // Reduce inside promote
-        Term::Promote(e) => step(e).map(|reduced| Term::Promote(Box::new(reduced))),
+        Term::Promote(e) => step(e).map(|reduced| Term::Promote(Rc::new(reduced))),
+
+        // Abort, literals, and (under-applied) builtins are already normal
+        Term::Var(_) | Term::Unit | Term::Trivial | Term::Abort(_) | Term::Builtin(_) | Term::Lit(_) => None,
+    }
+}
+
+/// Reduce a term to weak head normal form: fire the head redex repeatedly,
+/// but stop as soon as the head is a value former, without descending into
+/// its subterms.
+///
+/// This is cheaper than [`normalize`] when a caller only needs to test the
+/// outermost shape of a term (e.g. "is this an `Inl` or `Inr`?") — the
+/// payload is left unreduced, unlike `normalize`, which also reduces inside
+/// pairs, injections, abstractions, and `Promote`.
+///
+/// # Example
+///
+/// ```
+/// use lolli_extract::whnf;
+/// use lolli_core::Term;
+/// use std::rc::Rc;
+///
+/// // (λx. inl x) () → inl (), and whnf stops there without touching the
+/// // payload `()`.
+/// let t = Term::App(
+///     Rc::new(Term::Abs(
+///         "x".to_string(),
+///         Rc::new(Term::Inl(Rc::new(Term::Var("x".to_string())))),
+///     )),
+///     Rc::new(Term::Unit),
+/// );
+///
+/// assert_eq!(whnf(&t), Term::Inl(Rc::new(Term::Unit)));
+/// ```
+pub fn whnf(term: &Term) -> Term {
+    match term {
+        // Beta reduction: (λx. e) v → e[v/x]
+        Term::App(f, arg) => {
+            let f = whnf(f);
+            if let Term::Abs(x, body) = &f {
+                whnf(&substitute1(body, x, arg))
+            } else {
+                Term::App(Rc::new(f), arg.clone())
+            }
+        }
+
+        // Let-pair reduction: let (x, y) = (a, b) in e → e[a/x][b/y]
+        Term::LetPair(x, y, pair, body) => {
+            let pair = whnf(pair);
+            if let Term::Pair(a, b) = &pair {
+                whnf(&substitute2(body, x, a, y, b))
+            } else {
+                Term::LetPair(x.clone(), y.clone(), Rc::new(pair), body.clone())
+            }
+        }
+
+        // Case reduction: case inl v of { inl x => e1 | inr y => e2 } → e1[v/x]
+        Term::Case(scrut, x, left, y, right) => {
+            let scrut = whnf(scrut);
+            match &scrut {
+                Term::Inl(v) => whnf(&substitute1(left, x, v)),
+                Term::Inr(v) => whnf(&substitute1(right, y, v)),
+                _ => Term::Case(Rc::new(scrut), x.clone(), left.clone(), y.clone(), right.clone()),
+            }
+        }
+
+        // Fst reduction: fst (a, b) → a
+        Term::Fst(pair) => {
+            let pair = whnf(pair);
+            if let Term::Pair(a, _) = &pair {
+                whnf(a)
+            } else {
+                Term::Fst(Rc::new(pair))
+            }
+        }
+
+        // Snd reduction: snd (a, b) → b
+        Term::Snd(pair) => {
+            let pair = whnf(pair);
+            if let Term::Pair(_, b) = &pair {
+                whnf(b)
+            } else {
+                Term::Snd(Rc::new(pair))
+            }
+        }
+
+        // Dereliction: derelict (!v) → v
+        Term::Derelict(e) => {
+            let e = whnf(e);
+            if let Term::Promote(v) = &e {
+                whnf(v)
+            } else {
+                Term::Derelict(Rc::new(e))
+            }
+        }
+
+        // Copy reduction: copy !v as (x, y) in e → e[!v/x][!v/y]
+        Term::Copy(src, x, y, body) => {
+            let src = whnf(src);
+            if let Term::Promote(v) = &src {
+                let promoted = Term::Promote(v.clone());
+                whnf(&substitute2(body, x, &promoted, y, &promoted))
+            } else {
+                Term::Copy(Rc::new(src), x.clone(), y.clone(), body.clone())
+            }
+        }
+
+        // Discard reduction: discard !v in e → e
+        Term::Discard(discarded, body) => {
+            let discarded = whnf(discarded);
+            if matches!(discarded, Term::Promote(_)) {
+                whnf(body)
+            } else {
+                Term::Discard(Rc::new(discarded), body.clone())
+            }
+        }
 
-        // Abort and values are already normal
-        Term::Var(_) | Term::Unit | Term::Trivial | Term::Abort(_) => None,
+        // Value formers: the head is already exposed, so stop here without
+        // reducing inside. An under-applied builtin is left exactly as is,
+        // since `whnf` doesn't perform delta reduction.
+        Term::Abs(_, _)
+        | Term::Pair(_, _)
+        | Term::Inl(_)
+        | Term::Inr(_)
+        | Term::Promote(_)
+        | Term::Unit
+        | Term::Trivial
+        | Term::Var(_)
+        | Term::Abort(_)
+        | Term::Builtin(_)
+        | Term::Lit(_) => term.clone(),
     }
 }
 
@@ -185,10 +351,11 @@ pub fn step(term: &Term) -> Option<Term> {
 /// ```
 /// use lolli_extract::normalize;
 /// use lolli_core::Term;
+/// use std::rc::Rc;
 ///
 /// let t = Term::App(
-///     Box::new(Term::Abs("x".to_string(), Box::new(Term::Var("x".to_string())))),
-///     Box::new(Term::Unit),
+///     Rc::new(Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())))),
+///     Rc::new(Term::Unit),
 /// );
 ///
 /// let normal = normalize(&t);
@@ -222,6 +389,666 @@ pub fn is_normal(term: &Term) -> bool {
     step(term).is_none()
 }
 
+/// Which reduction rule fired during a [`ReductionStep`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleKind {
+    /// `(λx. e) v → e[v/x]`
+    Beta,
+    /// `let (x, y) = (a, b) in e → e[a/x][b/y]`
+    LetPair,
+    /// `case inl v of { inl x => e1 | inr y => e2 } → e1[v/x]`
+    CaseInl,
+    /// `case inr v of { inl x => e1 | inr y => e2 } → e2[v/y]`
+    CaseInr,
+    /// `fst (a, b) → a`
+    Fst,
+    /// `snd (a, b) → b`
+    Snd,
+    /// `derelict (!v) → v`
+    Dereliction,
+    /// `copy !v as (x, y) in e → e[!v/x][!v/y]`
+    Copy,
+    /// `discard !v in e → e`
+    Discard,
+}
+
+/// One step of descent into a term while locating the contracted redex,
+/// following the same traversal that [`step`] uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathStep {
+    /// An application's function position.
+    Func,
+    /// An application's argument position.
+    Arg,
+    /// The scrutinee of a `LetPair`/`Case`, or the source of a
+    /// `Copy`/`Discard`.
+    Scrutinee,
+    /// The body of a `LetPair`/`Copy`/`Discard`, or an `Abs`'s body.
+    Body,
+    /// A `Pair`'s left component, or a `Case`'s `inl` branch.
+    Left,
+    /// A `Pair`'s right component, or a `Case`'s `inr` branch.
+    Right,
+    /// The sole subterm of `Fst`, `Snd`, `Derelict`, `Inl`, `Inr`, or
+    /// `Promote`.
+    Inner,
+}
+
+/// A single reduction step recorded by [`normalize_trace`]: the term before
+/// the step, which rule fired and where, and the term that results.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReductionStep {
+    /// The term immediately before this step.
+    pub before: Term,
+    /// The rule that was contracted.
+    pub rule: RuleKind,
+    /// The path from `before`'s root down to the contracted redex.
+    pub path: Vec<PathStep>,
+    /// The term immediately after this step.
+    pub after: Term,
+}
+
+/// Perform one step of reduction, reporting which rule fired and where, if
+/// a reduction was possible.
+///
+/// This mirrors [`step`]'s traversal exactly (same priority for which redex
+/// is contracted), except that it performs no delta reduction: a saturated
+/// builtin application is left unreduced, as there is no [`RuleKind`] for
+/// it. Otherwise the only difference is that it also reports the
+/// [`RuleKind`] and [`PathStep`] trail locating the redex, for callers that
+/// want to render or assert on *which* rule fired rather than only the
+/// resulting term.
+fn step_located(term: &Term) -> Option<(RuleKind, Vec<PathStep>, Term)> {
+    match term {
+        Term::App(f, arg) => {
+            if let Term::Abs(x, body) = f.as_ref() {
+                Some((RuleKind::Beta, Vec::new(), substitute1(body, x, arg)))
+            } else if let Some((rule, mut path, f_reduced)) = step_located(f) {
+                path.insert(0, PathStep::Func);
+                Some((rule, path, Term::App(Rc::new(f_reduced), arg.clone())))
+            } else {
+                step_located(arg).map(|(rule, mut path, arg_reduced)| {
+                    path.insert(0, PathStep::Arg);
+                    (rule, path, Term::App(f.clone(), Rc::new(arg_reduced)))
+                })
+            }
+        }
+
+        Term::LetPair(x, y, pair, body) => {
+            if let Term::Pair(a, b) = pair.as_ref() {
+                Some((RuleKind::LetPair, Vec::new(), substitute2(body, x, a, y, b)))
+            } else if let Some((rule, mut path, pair_reduced)) = step_located(pair) {
+                path.insert(0, PathStep::Scrutinee);
+                Some((
+                    rule,
+                    path,
+                    Term::LetPair(x.clone(), y.clone(), Rc::new(pair_reduced), body.clone()),
+                ))
+            } else {
+                step_located(body).map(|(rule, mut path, body_reduced)| {
+                    path.insert(0, PathStep::Body);
+                    (
+                        rule,
+                        path,
+                        Term::LetPair(x.clone(), y.clone(), pair.clone(), Rc::new(body_reduced)),
+                    )
+                })
+            }
+        }
+
+        Term::Case(scrut, x, left, y, right) => match scrut.as_ref() {
+            Term::Inl(v) => Some((RuleKind::CaseInl, Vec::new(), substitute1(left, x, v))),
+            Term::Inr(v) => Some((RuleKind::CaseInr, Vec::new(), substitute1(right, y, v))),
+            _ => {
+                if let Some((rule, mut path, scrut_reduced)) = step_located(scrut) {
+                    path.insert(0, PathStep::Scrutinee);
+                    Some((
+                        rule,
+                        path,
+                        Term::Case(Rc::new(scrut_reduced), x.clone(), left.clone(), y.clone(), right.clone()),
+                    ))
+                } else if let Some((rule, mut path, left_reduced)) = step_located(left) {
+                    path.insert(0, PathStep::Left);
+                    Some((
+                        rule,
+                        path,
+                        Term::Case(scrut.clone(), x.clone(), Rc::new(left_reduced), y.clone(), right.clone()),
+                    ))
+                } else {
+                    step_located(right).map(|(rule, mut path, right_reduced)| {
+                        path.insert(0, PathStep::Right);
+                        (
+                            rule,
+                            path,
+                            Term::Case(scrut.clone(), x.clone(), left.clone(), y.clone(), Rc::new(right_reduced)),
+                        )
+                    })
+                }
+            }
+        },
+
+        Term::Fst(pair) => {
+            if let Term::Pair(a, _) = pair.as_ref() {
+                Some((RuleKind::Fst, Vec::new(), a.as_ref().clone()))
+            } else {
+                step_located(pair).map(|(rule, mut path, pair_reduced)| {
+                    path.insert(0, PathStep::Inner);
+                    (rule, path, Term::Fst(Rc::new(pair_reduced)))
+                })
+            }
+        }
+
+        Term::Snd(pair) => {
+            if let Term::Pair(_, b) = pair.as_ref() {
+                Some((RuleKind::Snd, Vec::new(), b.as_ref().clone()))
+            } else {
+                step_located(pair).map(|(rule, mut path, pair_reduced)| {
+                    path.insert(0, PathStep::Inner);
+                    (rule, path, Term::Snd(Rc::new(pair_reduced)))
+                })
+            }
+        }
+
+        Term::Derelict(e) => {
+            if let Term::Promote(v) = e.as_ref() {
+                Some((RuleKind::Dereliction, Vec::new(), v.as_ref().clone()))
+            } else {
+                step_located(e).map(|(rule, mut path, e_reduced)| {
+                    path.insert(0, PathStep::Inner);
+                    (rule, path, Term::Derelict(Rc::new(e_reduced)))
+                })
+            }
+        }
+
+        Term::Copy(src, x, y, body) => {
+            if let Term::Promote(v) = src.as_ref() {
+                let promoted = Term::Promote(v.clone());
+                Some((
+                    RuleKind::Copy,
+                    Vec::new(),
+                    substitute2(body, x, &promoted, y, &promoted),
+                ))
+            } else if let Some((rule, mut path, src_reduced)) = step_located(src) {
+                path.insert(0, PathStep::Scrutinee);
+                Some((
+                    rule,
+                    path,
+                    Term::Copy(Rc::new(src_reduced), x.clone(), y.clone(), body.clone()),
+                ))
+            } else {
+                step_located(body).map(|(rule, mut path, body_reduced)| {
+                    path.insert(0, PathStep::Body);
+                    (
+                        rule,
+                        path,
+                        Term::Copy(src.clone(), x.clone(), y.clone(), Rc::new(body_reduced)),
+                    )
+                })
+            }
+        }
+
+        Term::Discard(discarded, body) => {
+            if matches!(discarded.as_ref(), Term::Promote(_)) {
+                Some((RuleKind::Discard, Vec::new(), body.as_ref().clone()))
+            } else if let Some((rule, mut path, discarded_reduced)) = step_located(discarded) {
+                path.insert(0, PathStep::Scrutinee);
+                Some((rule, path, Term::Discard(Rc::new(discarded_reduced), body.clone())))
+            } else {
+                step_located(body).map(|(rule, mut path, body_reduced)| {
+                    path.insert(0, PathStep::Body);
+                    (rule, path, Term::Discard(discarded.clone(), Rc::new(body_reduced)))
+                })
+            }
+        }
+
+        Term::Abs(x, body) => step_located(body).map(|(rule, mut path, reduced)| {
+            path.insert(0, PathStep::Body);
+            (rule, path, Term::Abs(x.clone(), Rc::new(reduced)))
+        }),
+
+        Term::Pair(a, b) => {
+            if let Some((rule, mut path, a_reduced)) = step_located(a) {
+                path.insert(0, PathStep::Left);
+                Some((rule, path, Term::Pair(Rc::new(a_reduced), b.clone())))
+            } else {
+                step_located(b).map(|(rule, mut path, b_reduced)| {
+                    path.insert(0, PathStep::Right);
+                    (rule, path, Term::Pair(a.clone(), Rc::new(b_reduced)))
+                })
+            }
+        }
+
+        Term::Inl(e) => step_located(e).map(|(rule, mut path, reduced)| {
+            path.insert(0, PathStep::Inner);
+            (rule, path, Term::Inl(Rc::new(reduced)))
+        }),
+        Term::Inr(e) => step_located(e).map(|(rule, mut path, reduced)| {
+            path.insert(0, PathStep::Inner);
+            (rule, path, Term::Inr(Rc::new(reduced)))
+        }),
+        Term::Promote(e) => step_located(e).map(|(rule, mut path, reduced)| {
+            path.insert(0, PathStep::Inner);
+            (rule, path, Term::Promote(Rc::new(reduced)))
+        }),
+
+        Term::Var(_) | Term::Unit | Term::Trivial | Term::Abort(_) | Term::Builtin(_) | Term::Lit(_) => None,
+    }
+}
+
+/// Fully normalize a term, recording each [`ReductionStep`] along the way.
+///
+/// This is a thin instrumentation layer over [`step`]: it follows the same
+/// leftmost-outermost traversal, but additionally reports, for every step,
+/// which rule fired and where the contracted redex was found. Unlike `step`,
+/// it performs no delta reduction, so on a term with a saturated builtin
+/// application it stops short of what [`normalize`] returns.
+///
+/// # Example
+///
+/// ```
+/// use lolli_extract::{normalize_trace, RuleKind};
+/// use lolli_core::Term;
+/// use std::rc::Rc;
+///
+/// let t = Term::App(
+///     Rc::new(Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())))),
+///     Rc::new(Term::Unit),
+/// );
+///
+/// let trace = normalize_trace(&t);
+/// assert_eq!(trace.len(), 1);
+/// assert_eq!(trace[0].rule, RuleKind::Beta);
+/// assert_eq!(trace[0].after, Term::Unit);
+/// ```
+pub fn normalize_trace(term: &Term) -> Vec<ReductionStep> {
+    let mut steps = Vec::new();
+    let mut current = term.clone();
+    while let Some((rule, path, after)) = step_located(&current) {
+        steps.push(ReductionStep {
+            before: current,
+            rule,
+            path,
+            after: after.clone(),
+        });
+        current = after;
+    }
+    steps
+}
+
+/// A reduction strategy: which redex [`step_with`] contracts when more than
+/// one is available.
+///
+/// Since linear terms are strongly normalizing, every strategy that reduces
+/// under binders ([`NormalOrder`](Strategy::NormalOrder) and
+/// [`ApplicativeOrder`](Strategy::ApplicativeOrder)) reaches the same full
+/// normal form as [`normalize`] on a term without builtin applications —
+/// only the reduction sequence and step count differ. Only `NormalOrder`
+/// (which is [`step`]) performs delta reduction; the other strategies leave
+/// a saturated builtin application unreduced. [`CallByName`](Strategy::CallByName) and
+/// [`CallByValue`](Strategy::CallByValue) are weak strategies: like
+/// [`whnf`], they never reduce inside a value former, so [`normalize_with`]
+/// only takes them to weak (head) normal form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Contract the leftmost-outermost redex first. This is what [`step`]
+    /// has always done; kept here for explicit selection and back-compat.
+    NormalOrder,
+    /// Contract the leftmost-innermost redex: a node's subterms are reduced
+    /// before its own redex is fired.
+    ApplicativeOrder,
+    /// Weak, leftmost-outermost reduction: never descends into a value
+    /// former (`Abs`, `Pair`, `Inl`, `Inr`, `Promote`) until it becomes the
+    /// head of a redex, and never reduces an application's argument.
+    CallByName,
+    /// Like `CallByName`, but reduces an application's argument to a value
+    /// before contracting the redex.
+    CallByValue,
+}
+
+/// Perform one step of reduction under a particular [`Strategy`], if
+/// possible.
+///
+/// # Example
+///
+/// ```
+/// use lolli_extract::{step_with, Strategy};
+/// use lolli_core::Term;
+/// use std::rc::Rc;
+///
+/// let t = Term::App(
+///     Rc::new(Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())))),
+///     Rc::new(Term::Unit),
+/// );
+///
+/// assert_eq!(step_with(&t, Strategy::CallByValue), Some(Term::Unit));
+/// ```
+pub fn step_with(term: &Term, strategy: Strategy) -> Option<Term> {
+    match strategy {
+        Strategy::NormalOrder => step(term),
+        Strategy::ApplicativeOrder => step_applicative(term),
+        Strategy::CallByName => step_call_by_name(term),
+        Strategy::CallByValue => step_call_by_value(term),
+    }
+}
+
+/// Fully reduce a term under a particular [`Strategy`] — see [`Strategy`]'s
+/// doc comment for which strategies reach a full normal form versus a weak
+/// one.
+pub fn normalize_with(term: &Term, strategy: Strategy) -> Term {
+    let mut current = term.clone();
+    while let Some(reduced) = step_with(&current, strategy) {
+        current = reduced;
+    }
+    current
+}
+
+/// A term that cannot itself be the target of further reduction until an
+/// enclosing elimination forces it open — the same shallow notion of
+/// "value former" that [`whnf`] stops at.
+fn is_value(term: &Term) -> bool {
+    matches!(
+        term,
+        Term::Abs(_, _)
+            | Term::Pair(_, _)
+            | Term::Inl(_)
+            | Term::Inr(_)
+            | Term::Promote(_)
+            | Term::Var(_)
+            | Term::Unit
+            | Term::Trivial
+            | Term::Abort(_)
+            | Term::Builtin(_)
+            | Term::Lit(_)
+    )
+}
+
+/// Applicative-order (leftmost-innermost) reduction: reduce a node's
+/// subterms before firing its own redex.
+fn step_applicative(term: &Term) -> Option<Term> {
+    match term {
+        Term::App(f, arg) => {
+            if let Some(f_reduced) = step_applicative(f) {
+                Some(Term::App(Rc::new(f_reduced), arg.clone()))
+            } else if let Some(arg_reduced) = step_applicative(arg) {
+                Some(Term::App(f.clone(), Rc::new(arg_reduced)))
+            } else if let Term::Abs(x, body) = f.as_ref() {
+                Some(substitute1(body, x, arg))
+            } else {
+                None
+            }
+        }
+
+        Term::LetPair(x, y, pair, body) => {
+            if let Some(pair_reduced) = step_applicative(pair) {
+                Some(Term::LetPair(x.clone(), y.clone(), Rc::new(pair_reduced), body.clone()))
+            } else if let Some(body_reduced) = step_applicative(body) {
+                Some(Term::LetPair(x.clone(), y.clone(), pair.clone(), Rc::new(body_reduced)))
+            } else if let Term::Pair(a, b) = pair.as_ref() {
+                Some(substitute2(body, x, a, y, b))
+            } else {
+                None
+            }
+        }
+
+        Term::Case(scrut, x, left, y, right) => {
+            if let Some(scrut_reduced) = step_applicative(scrut) {
+                Some(Term::Case(Rc::new(scrut_reduced), x.clone(), left.clone(), y.clone(), right.clone()))
+            } else if let Some(left_reduced) = step_applicative(left) {
+                Some(Term::Case(scrut.clone(), x.clone(), Rc::new(left_reduced), y.clone(), right.clone()))
+            } else if let Some(right_reduced) = step_applicative(right) {
+                Some(Term::Case(scrut.clone(), x.clone(), left.clone(), y.clone(), Rc::new(right_reduced)))
+            } else {
+                match scrut.as_ref() {
+                    Term::Inl(v) => Some(substitute1(left, x, v)),
+                    Term::Inr(v) => Some(substitute1(right, y, v)),
+                    _ => None,
+                }
+            }
+        }
+
+        Term::Fst(pair) => {
+            if let Some(pair_reduced) = step_applicative(pair) {
+                Some(Term::Fst(Rc::new(pair_reduced)))
+            } else if let Term::Pair(a, _) = pair.as_ref() {
+                Some(a.as_ref().clone())
+            } else {
+                None
+            }
+        }
+
+        Term::Snd(pair) => {
+            if let Some(pair_reduced) = step_applicative(pair) {
+                Some(Term::Snd(Rc::new(pair_reduced)))
+            } else if let Term::Pair(_, b) = pair.as_ref() {
+                Some(b.as_ref().clone())
+            } else {
+                None
+            }
+        }
+
+        Term::Derelict(e) => {
+            if let Some(e_reduced) = step_applicative(e) {
+                Some(Term::Derelict(Rc::new(e_reduced)))
+            } else if let Term::Promote(v) = e.as_ref() {
+                Some(v.as_ref().clone())
+            } else {
+                None
+            }
+        }
+
+        Term::Copy(src, x, y, body) => {
+            if let Some(src_reduced) = step_applicative(src) {
+                Some(Term::Copy(Rc::new(src_reduced), x.clone(), y.clone(), body.clone()))
+            } else if let Some(body_reduced) = step_applicative(body) {
+                Some(Term::Copy(src.clone(), x.clone(), y.clone(), Rc::new(body_reduced)))
+            } else if let Term::Promote(v) = src.as_ref() {
+                let promoted = Term::Promote(v.clone());
+                Some(substitute2(body, x, &promoted, y, &promoted))
+            } else {
+                None
+            }
+        }
+
+        Term::Discard(discarded, body) => {
+            if let Some(discarded_reduced) = step_applicative(discarded) {
+                Some(Term::Discard(Rc::new(discarded_reduced), body.clone()))
+            } else if let Some(body_reduced) = step_applicative(body) {
+                Some(Term::Discard(discarded.clone(), Rc::new(body_reduced)))
+            } else if matches!(discarded.as_ref(), Term::Promote(_)) {
+                Some(body.as_ref().clone())
+            } else {
+                None
+            }
+        }
+
+        Term::Abs(x, body) => step_applicative(body).map(|reduced| Term::Abs(x.clone(), Rc::new(reduced))),
+
+        Term::Pair(a, b) => {
+            if let Some(a_reduced) = step_applicative(a) {
+                Some(Term::Pair(Rc::new(a_reduced), b.clone()))
+            } else {
+                step_applicative(b).map(|b_reduced| Term::Pair(a.clone(), Rc::new(b_reduced)))
+            }
+        }
+
+        Term::Inl(e) => step_applicative(e).map(|reduced| Term::Inl(Rc::new(reduced))),
+        Term::Inr(e) => step_applicative(e).map(|reduced| Term::Inr(Rc::new(reduced))),
+        Term::Promote(e) => step_applicative(e).map(|reduced| Term::Promote(Rc::new(reduced))),
+
+        Term::Var(_) | Term::Unit | Term::Trivial | Term::Abort(_) | Term::Builtin(_) | Term::Lit(_) => None,
+    }
+}
+
+/// Weak, leftmost-outermost reduction that never descends into a value
+/// former and never reduces an application's argument.
+fn step_call_by_name(term: &Term) -> Option<Term> {
+    match term {
+        Term::App(f, arg) => {
+            if let Term::Abs(x, body) = f.as_ref() {
+                Some(substitute1(body, x, arg))
+            } else {
+                step_call_by_name(f).map(|f_reduced| Term::App(Rc::new(f_reduced), arg.clone()))
+            }
+        }
+
+        Term::LetPair(x, y, pair, body) => {
+            if let Term::Pair(a, b) = pair.as_ref() {
+                Some(substitute2(body, x, a, y, b))
+            } else {
+                step_call_by_name(pair)
+                    .map(|pair_reduced| Term::LetPair(x.clone(), y.clone(), Rc::new(pair_reduced), body.clone()))
+            }
+        }
+
+        Term::Case(scrut, x, left, y, right) => match scrut.as_ref() {
+            Term::Inl(v) => Some(substitute1(left, x, v)),
+            Term::Inr(v) => Some(substitute1(right, y, v)),
+            _ => step_call_by_name(scrut)
+                .map(|scrut_reduced| Term::Case(Rc::new(scrut_reduced), x.clone(), left.clone(), y.clone(), right.clone())),
+        },
+
+        Term::Fst(pair) => {
+            if let Term::Pair(a, _) = pair.as_ref() {
+                Some(a.as_ref().clone())
+            } else {
+                step_call_by_name(pair).map(|reduced| Term::Fst(Rc::new(reduced)))
+            }
+        }
+
+        Term::Snd(pair) => {
+            if let Term::Pair(_, b) = pair.as_ref() {
+                Some(b.as_ref().clone())
+            } else {
+                step_call_by_name(pair).map(|reduced| Term::Snd(Rc::new(reduced)))
+            }
+        }
+
+        Term::Derelict(e) => {
+            if let Term::Promote(v) = e.as_ref() {
+                Some(v.as_ref().clone())
+            } else {
+                step_call_by_name(e).map(|reduced| Term::Derelict(Rc::new(reduced)))
+            }
+        }
+
+        Term::Copy(src, x, y, body) => {
+            if let Term::Promote(v) = src.as_ref() {
+                let promoted = Term::Promote(v.clone());
+                Some(substitute2(body, x, &promoted, y, &promoted))
+            } else {
+                step_call_by_name(src).map(|reduced| Term::Copy(Rc::new(reduced), x.clone(), y.clone(), body.clone()))
+            }
+        }
+
+        Term::Discard(discarded, body) => {
+            if matches!(discarded.as_ref(), Term::Promote(_)) {
+                Some(body.as_ref().clone())
+            } else {
+                step_call_by_name(discarded).map(|reduced| Term::Discard(Rc::new(reduced), body.clone()))
+            }
+        }
+
+        Term::Abs(_, _)
+        | Term::Pair(_, _)
+        | Term::Inl(_)
+        | Term::Inr(_)
+        | Term::Promote(_)
+        | Term::Var(_)
+        | Term::Unit
+        | Term::Trivial
+        | Term::Abort(_)
+        | Term::Builtin(_)
+        | Term::Lit(_) => None,
+    }
+}
+
+/// Like [`step_call_by_name`], but an application's argument must be
+/// reduced to a value before the redex fires.
+fn step_call_by_value(term: &Term) -> Option<Term> {
+    match term {
+        Term::App(f, arg) => {
+            if let Term::Abs(x, body) = f.as_ref() {
+                if is_value(arg) {
+                    Some(substitute1(body, x, arg))
+                } else {
+                    step_call_by_value(arg).map(|arg_reduced| Term::App(f.clone(), Rc::new(arg_reduced)))
+                }
+            } else {
+                step_call_by_value(f).map(|f_reduced| Term::App(Rc::new(f_reduced), arg.clone()))
+            }
+        }
+
+        Term::LetPair(x, y, pair, body) => {
+            if let Term::Pair(a, b) = pair.as_ref() {
+                Some(substitute2(body, x, a, y, b))
+            } else {
+                step_call_by_value(pair)
+                    .map(|pair_reduced| Term::LetPair(x.clone(), y.clone(), Rc::new(pair_reduced), body.clone()))
+            }
+        }
+
+        Term::Case(scrut, x, left, y, right) => match scrut.as_ref() {
+            Term::Inl(v) => Some(substitute1(left, x, v)),
+            Term::Inr(v) => Some(substitute1(right, y, v)),
+            _ => step_call_by_value(scrut)
+                .map(|scrut_reduced| Term::Case(Rc::new(scrut_reduced), x.clone(), left.clone(), y.clone(), right.clone())),
+        },
+
+        Term::Fst(pair) => {
+            if let Term::Pair(a, _) = pair.as_ref() {
+                Some(a.as_ref().clone())
+            } else {
+                step_call_by_value(pair).map(|reduced| Term::Fst(Rc::new(reduced)))
+            }
+        }
+
+        Term::Snd(pair) => {
+            if let Term::Pair(_, b) = pair.as_ref() {
+                Some(b.as_ref().clone())
+            } else {
+                step_call_by_value(pair).map(|reduced| Term::Snd(Rc::new(reduced)))
+            }
+        }
+
+        Term::Derelict(e) => {
+            if let Term::Promote(v) = e.as_ref() {
+                Some(v.as_ref().clone())
+            } else {
+                step_call_by_value(e).map(|reduced| Term::Derelict(Rc::new(reduced)))
+            }
+        }
+
+        Term::Copy(src, x, y, body) => {
+            if let Term::Promote(v) = src.as_ref() {
+                let promoted = Term::Promote(v.clone());
+                Some(substitute2(body, x, &promoted, y, &promoted))
+            } else {
+                step_call_by_value(src).map(|reduced| Term::Copy(Rc::new(reduced), x.clone(), y.clone(), body.clone()))
+            }
+        }
+
+        Term::Discard(discarded, body) => {
+            if matches!(discarded.as_ref(), Term::Promote(_)) {
+                Some(body.as_ref().clone())
+            } else {
+                step_call_by_value(discarded).map(|reduced| Term::Discard(Rc::new(reduced), body.clone()))
+            }
+        }
+
+        Term::Abs(_, _)
+        | Term::Pair(_, _)
+        | Term::Inl(_)
+        | Term::Inr(_)
+        | Term::Promote(_)
+        | Term::Var(_)
+        | Term::Unit
+        | Term::Trivial
+        | Term::Abort(_)
+        | Term::Builtin(_)
+        | Term::Lit(_) => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -230,11 +1057,11 @@ mod tests {
     fn test_beta_reduction() {
         // (λx. x) () → ()
         let t = Term::App(
-            Box::new(Term::Abs(
+            Rc::new(Term::Abs(
                 "x".to_string(),
-                Box::new(Term::Var("x".to_string())),
+                Rc::new(Term::Var("x".to_string())),
             )),
-            Box::new(Term::Unit),
+            Rc::new(Term::Unit),
         );
 
         let result = normalize(&t);
@@ -247,8 +1074,8 @@ mod tests {
         let t = Term::LetPair(
             "x".to_string(),
             "y".to_string(),
-            Box::new(Term::Pair(Box::new(Term::Unit), Box::new(Term::Trivial))),
-            Box::new(Term::Var("x".to_string())),
+            Rc::new(Term::Pair(Rc::new(Term::Unit), Rc::new(Term::Trivial))),
+            Rc::new(Term::Var("x".to_string())),
         );
 
         let result = normalize(&t);
@@ -259,11 +1086,11 @@ mod tests {
     fn test_case_inl_reduction() {
         // case inl () of { inl x => x | inr y => y } → ()
         let t = Term::Case(
-            Box::new(Term::Inl(Box::new(Term::Unit))),
+            Rc::new(Term::Inl(Rc::new(Term::Unit))),
             "x".to_string(),
-            Box::new(Term::Var("x".to_string())),
+            Rc::new(Term::Var("x".to_string())),
             "y".to_string(),
-            Box::new(Term::Var("y".to_string())),
+            Rc::new(Term::Var("y".to_string())),
         );
 
         let result = normalize(&t);
@@ -274,11 +1101,11 @@ mod tests {
     fn test_case_inr_reduction() {
         // case inr ⟨⟩ of { inl x => x | inr y => y } → ⟨⟩
         let t = Term::Case(
-            Box::new(Term::Inr(Box::new(Term::Trivial))),
+            Rc::new(Term::Inr(Rc::new(Term::Trivial))),
             "x".to_string(),
-            Box::new(Term::Var("x".to_string())),
+            Rc::new(Term::Var("x".to_string())),
             "y".to_string(),
-            Box::new(Term::Var("y".to_string())),
+            Rc::new(Term::Var("y".to_string())),
         );
 
         let result = normalize(&t);
@@ -288,9 +1115,9 @@ mod tests {
     #[test]
     fn test_fst_reduction() {
         // fst ((), ⟨⟩) → ()
-        let t = Term::Fst(Box::new(Term::Pair(
-            Box::new(Term::Unit),
-            Box::new(Term::Trivial),
+        let t = Term::Fst(Rc::new(Term::Pair(
+            Rc::new(Term::Unit),
+            Rc::new(Term::Trivial),
         )));
 
         let result = normalize(&t);
@@ -300,9 +1127,9 @@ mod tests {
     #[test]
     fn test_snd_reduction() {
         // snd ((), ⟨⟩) → ⟨⟩
-        let t = Term::Snd(Box::new(Term::Pair(
-            Box::new(Term::Unit),
-            Box::new(Term::Trivial),
+        let t = Term::Snd(Rc::new(Term::Pair(
+            Rc::new(Term::Unit),
+            Rc::new(Term::Trivial),
         )));
 
         let result = normalize(&t);
@@ -312,7 +1139,7 @@ mod tests {
     #[test]
     fn test_dereliction_reduction() {
         // derelict (!()) → ()
-        let t = Term::Derelict(Box::new(Term::Promote(Box::new(Term::Unit))));
+        let t = Term::Derelict(Rc::new(Term::Promote(Rc::new(Term::Unit))));
 
         let result = normalize(&t);
         assert_eq!(result, Term::Unit);
@@ -322,12 +1149,12 @@ mod tests {
     fn test_copy_reduction() {
         // copy !() as (x, y) in (x, y) → (!(), !())
         let t = Term::Copy(
-            Box::new(Term::Promote(Box::new(Term::Unit))),
+            Rc::new(Term::Promote(Rc::new(Term::Unit))),
             "x".to_string(),
             "y".to_string(),
-            Box::new(Term::Pair(
-                Box::new(Term::Var("x".to_string())),
-                Box::new(Term::Var("y".to_string())),
+            Rc::new(Term::Pair(
+                Rc::new(Term::Var("x".to_string())),
+                Rc::new(Term::Var("y".to_string())),
             )),
         );
 
@@ -335,8 +1162,8 @@ mod tests {
         assert_eq!(
             result,
             Term::Pair(
-                Box::new(Term::Promote(Box::new(Term::Unit))),
-                Box::new(Term::Promote(Box::new(Term::Unit))),
+                Rc::new(Term::Promote(Rc::new(Term::Unit))),
+                Rc::new(Term::Promote(Rc::new(Term::Unit))),
             )
         );
     }
@@ -345,8 +1172,8 @@ mod tests {
     fn test_discard_reduction() {
         // discard !() in ⟨⟩ → ⟨⟩
         let t = Term::Discard(
-            Box::new(Term::Promote(Box::new(Term::Unit))),
-            Box::new(Term::Trivial),
+            Rc::new(Term::Promote(Rc::new(Term::Unit))),
+            Rc::new(Term::Trivial),
         );
 
         let result = normalize(&t);
@@ -359,16 +1186,16 @@ mod tests {
         assert!(is_normal(&Term::Var("x".to_string())));
         assert!(is_normal(&Term::Abs(
             "x".to_string(),
-            Box::new(Term::Var("x".to_string()))
+            Rc::new(Term::Var("x".to_string()))
         )));
 
         // Redex is not normal
         let redex = Term::App(
-            Box::new(Term::Abs(
+            Rc::new(Term::Abs(
                 "x".to_string(),
-                Box::new(Term::Var("x".to_string())),
+                Rc::new(Term::Var("x".to_string())),
             )),
-            Box::new(Term::Unit),
+            Rc::new(Term::Unit),
         );
         assert!(!is_normal(&redex));
     }
@@ -377,16 +1204,16 @@ mod tests {
     fn test_nested_reduction() {
         // (λf. f ()) (λx. x) → ()
         let t = Term::App(
-            Box::new(Term::Abs(
+            Rc::new(Term::Abs(
                 "f".to_string(),
-                Box::new(Term::App(
-                    Box::new(Term::Var("f".to_string())),
-                    Box::new(Term::Unit),
+                Rc::new(Term::App(
+                    Rc::new(Term::Var("f".to_string())),
+                    Rc::new(Term::Unit),
                 )),
             )),
-            Box::new(Term::Abs(
+            Rc::new(Term::Abs(
                 "x".to_string(),
-                Box::new(Term::Var("x".to_string())),
+                Rc::new(Term::Var("x".to_string())),
             )),
         );
 
@@ -394,15 +1221,52 @@ mod tests {
         assert_eq!(result, Term::Unit);
     }
 
+    #[test]
+    fn test_case_reduces_under_stuck_branches() {
+        // case x of { inl l => (λy. y) l | inr r => r } — the scrutinee `x`
+        // is stuck, but the `inl` branch still has a redex to reduce.
+        let t = Term::Case(
+            Rc::new(Term::Var("x".to_string())),
+            "l".to_string(),
+            Rc::new(Term::App(
+                Rc::new(Term::Abs("y".to_string(), Rc::new(Term::Var("y".to_string())))),
+                Rc::new(Term::Var("l".to_string())),
+            )),
+            "r".to_string(),
+            Rc::new(Term::Var("r".to_string())),
+        );
+
+        let reduced = step(&t).expect("the inl branch's redex should fire");
+        assert!(matches!(reduced, Term::Case(_, _, left, _, _) if *left == Term::Var("l".to_string())));
+    }
+
+    #[test]
+    fn test_copy_reduces_body_when_source_is_stuck() {
+        // copy x as (a, b) in (λz. z) a — `x` is a free variable, so the
+        // copy itself is stuck, but its body still has a redex.
+        let t = Term::Copy(
+            Rc::new(Term::Var("x".to_string())),
+            "a".to_string(),
+            "b".to_string(),
+            Rc::new(Term::App(
+                Rc::new(Term::Abs("z".to_string(), Rc::new(Term::Var("z".to_string())))),
+                Rc::new(Term::Var("a".to_string())),
+            )),
+        );
+
+        let reduced = step(&t).expect("the body's redex should fire");
+        assert!(matches!(reduced, Term::Copy(_, _, _, body) if *body == Term::Var("a".to_string())));
+    }
+
     #[test]
     fn test_normalize_bounded() {
         // Test that bounded normalization respects the limit
         let t = Term::App(
-            Box::new(Term::Abs(
+            Rc::new(Term::Abs(
                 "x".to_string(),
-                Box::new(Term::Var("x".to_string())),
+                Rc::new(Term::Var("x".to_string())),
             )),
-            Box::new(Term::Unit),
+            Rc::new(Term::Unit),
         );
 
         // With 0 steps, should return the original term
@@ -413,4 +1277,344 @@ mod tests {
         let result_full = normalize_bounded(&t, 10);
         assert_eq!(result_full, Term::Unit);
     }
+
+    #[test]
+    fn test_whnf_exposes_head_without_reducing_payload() {
+        // (λx. inl x) ((λy. y) ()) → inl ((λy. y) ()) — whnf must not
+        // reduce the still-redex payload under `inl`.
+        let unreduced_payload = Term::App(
+            Rc::new(Term::Abs("y".to_string(), Rc::new(Term::Var("y".to_string())))),
+            Rc::new(Term::Unit),
+        );
+        let t = Term::App(
+            Rc::new(Term::Abs(
+                "x".to_string(),
+                Rc::new(Term::Inl(Rc::new(Term::Var("x".to_string())))),
+            )),
+            Rc::new(unreduced_payload.clone()),
+        );
+
+        assert_eq!(whnf(&t), Term::Inl(Rc::new(unreduced_payload)));
+    }
+
+    #[test]
+    fn test_whnf_stops_at_value_formers() {
+        assert_eq!(whnf(&Term::Unit), Term::Unit);
+        assert_eq!(whnf(&Term::Var("x".to_string())), Term::Var("x".to_string()));
+
+        let abs = Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())));
+        assert_eq!(whnf(&abs), abs);
+    }
+
+    #[test]
+    fn test_whnf_fires_case_head_redex() {
+        // case inl () of { inl x => (λz. z) x | inr y => y } → () — the
+        // chosen branch is itself a redex, and whnf should still reduce it
+        // since it's now in head position.
+        let t = Term::Case(
+            Rc::new(Term::Inl(Rc::new(Term::Unit))),
+            "x".to_string(),
+            Rc::new(Term::App(
+                Rc::new(Term::Abs("z".to_string(), Rc::new(Term::Var("z".to_string())))),
+                Rc::new(Term::Var("x".to_string())),
+            )),
+            "y".to_string(),
+            Rc::new(Term::Var("y".to_string())),
+        );
+
+        assert_eq!(whnf(&t), Term::Unit);
+    }
+
+    #[test]
+    fn test_whnf_leaves_stuck_term_untouched() {
+        let stuck = Term::Fst(Rc::new(Term::Var("x".to_string())));
+        assert_eq!(whnf(&stuck), stuck);
+    }
+
+    #[test]
+    fn test_beta_reduction_does_not_capture_free_variable() {
+        // (λf. λy. f) x → λy. x — substituting the free variable `x` for
+        // `f` under the `λy` binder must not be confused with `y`, and in
+        // particular must not capture a hypothetical free `y` carried by
+        // the argument (here the argument is just `x`, but the body's own
+        // `y` binder must survive untouched in the result).
+        let t = Term::App(
+            Rc::new(Term::Abs(
+                "f".to_string(),
+                Rc::new(Term::Abs("y".to_string(), Rc::new(Term::Var("f".to_string())))),
+            )),
+            Rc::new(Term::Var("x".to_string())),
+        );
+
+        let result = normalize(&t);
+        assert_eq!(
+            result,
+            Term::Abs("y".to_string(), Rc::new(Term::Var("x".to_string())))
+        );
+    }
+
+    #[test]
+    fn test_beta_reduction_avoids_capturing_argument_with_colliding_name() {
+        // (λf. λy. f y) (λ_. y) — the argument `λ_. y` carries a free `y`.
+        // Substituting it for `f` under the inner `λy` binder must rename
+        // that binder so the argument's free `y` is not captured.
+        let free_y_arg = Term::Abs("_".to_string(), Rc::new(Term::Var("y".to_string())));
+        let t = Term::App(
+            Rc::new(Term::Abs(
+                "f".to_string(),
+                Rc::new(Term::Abs(
+                    "y".to_string(),
+                    Rc::new(Term::App(
+                        Rc::new(Term::Var("f".to_string())),
+                        Rc::new(Term::Var("y".to_string())),
+                    )),
+                )),
+            )),
+            Rc::new(free_y_arg.clone()),
+        );
+
+        let after_beta = step(&t).expect("the outer application should be a redex");
+        match after_beta {
+            Term::Abs(bound, body) => {
+                assert_ne!(bound, "y", "the binder must be renamed away from the captured name");
+                assert_eq!(
+                    *body,
+                    Term::App(Rc::new(free_y_arg), Rc::new(Term::Var(bound)))
+                );
+            }
+            other => panic!("expected an Abs, got {other:?}"),
+        }
+
+        // Fully normalizing goes on to fire the now-exposed inner redex too,
+        // and the argument's free `y` still must not end up bound.
+        assert_eq!(
+            normalize(&t),
+            Term::Abs("y'".to_string(), Rc::new(Term::Var("y".to_string())))
+        );
+    }
+
+    #[test]
+    fn test_step_with_normal_order_matches_step() {
+        let t = Term::App(
+            Rc::new(Term::Abs(
+                "x".to_string(),
+                Rc::new(Term::Var("x".to_string())),
+            )),
+            Rc::new(Term::Unit),
+        );
+        assert_eq!(step_with(&t, Strategy::NormalOrder), step(&t));
+    }
+
+    #[test]
+    fn test_normal_order_contracts_outer_redex_before_argument() {
+        // (λx. x) ((λy. y) ()) — the outer redex is contracted immediately,
+        // leaving the argument's own redex exposed but unreduced.
+        let t = Term::App(
+            Rc::new(Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())))),
+            Rc::new(Term::App(
+                Rc::new(Term::Abs("y".to_string(), Rc::new(Term::Var("y".to_string())))),
+                Rc::new(Term::Unit),
+            )),
+        );
+
+        let expected_arg = Term::App(
+            Rc::new(Term::Abs("y".to_string(), Rc::new(Term::Var("y".to_string())))),
+            Rc::new(Term::Unit),
+        );
+        assert_eq!(step_with(&t, Strategy::NormalOrder), Some(expected_arg));
+    }
+
+    #[test]
+    fn test_applicative_and_call_by_value_reduce_argument_before_outer_redex() {
+        // Same term as above: both strategies that evaluate arguments first
+        // fire the argument's redex before touching the outer one.
+        let t = Term::App(
+            Rc::new(Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())))),
+            Rc::new(Term::App(
+                Rc::new(Term::Abs("y".to_string(), Rc::new(Term::Var("y".to_string())))),
+                Rc::new(Term::Unit),
+            )),
+        );
+
+        let expected = Term::App(
+            Rc::new(Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())))),
+            Rc::new(Term::Unit),
+        );
+        assert_eq!(step_with(&t, Strategy::ApplicativeOrder), Some(expected.clone()));
+        assert_eq!(step_with(&t, Strategy::CallByValue), Some(expected));
+    }
+
+    #[test]
+    fn test_call_by_name_never_reduces_under_a_binder() {
+        // λz. (λw. w) () — the redex lives inside an unapplied lambda.
+        // Call-by-name is weak: it must not reduce there.
+        let t = Term::Abs(
+            "z".to_string(),
+            Rc::new(Term::App(
+                Rc::new(Term::Abs("w".to_string(), Rc::new(Term::Var("w".to_string())))),
+                Rc::new(Term::Unit),
+            )),
+        );
+
+        assert_eq!(step_with(&t, Strategy::CallByName), None);
+        assert_eq!(step_with(&t, Strategy::CallByValue), None);
+
+        // Normal order and applicative order both still reduce under
+        // binders, so they do make progress here.
+        assert!(step_with(&t, Strategy::NormalOrder).is_some());
+        assert!(step_with(&t, Strategy::ApplicativeOrder).is_some());
+    }
+
+    #[test]
+    fn test_normalize_with_weak_strategies_stop_at_weak_normal_form() {
+        // λz. (λw. w) () normalizes fully to λz. () under the strong
+        // strategies, but call-by-name/call-by-value settle for the
+        // already-a-value outer `Abs` and never touch its body.
+        let t = Term::Abs(
+            "z".to_string(),
+            Rc::new(Term::App(
+                Rc::new(Term::Abs("w".to_string(), Rc::new(Term::Var("w".to_string())))),
+                Rc::new(Term::Unit),
+            )),
+        );
+
+        assert_eq!(
+            normalize_with(&t, Strategy::NormalOrder),
+            Term::Abs("z".to_string(), Rc::new(Term::Unit))
+        );
+        assert_eq!(
+            normalize_with(&t, Strategy::ApplicativeOrder),
+            Term::Abs("z".to_string(), Rc::new(Term::Unit))
+        );
+        assert_eq!(normalize_with(&t, Strategy::CallByName), t);
+        assert_eq!(normalize_with(&t, Strategy::CallByValue), t);
+    }
+
+    #[test]
+    fn test_normalize_trace_records_a_single_beta_step() {
+        let t = Term::App(
+            Rc::new(Term::Abs(
+                "x".to_string(),
+                Rc::new(Term::Var("x".to_string())),
+            )),
+            Rc::new(Term::Unit),
+        );
+
+        let trace = normalize_trace(&t);
+        assert_eq!(trace.len(), 1);
+        assert_eq!(trace[0].before, t);
+        assert_eq!(trace[0].rule, RuleKind::Beta);
+        assert_eq!(trace[0].path, Vec::new());
+        assert_eq!(trace[0].after, Term::Unit);
+    }
+
+    #[test]
+    fn test_normalize_trace_locates_a_redex_under_an_abstraction() {
+        // λz. (λw. w) () — the redex is one `Body` step below the root.
+        let t = Term::Abs(
+            "z".to_string(),
+            Rc::new(Term::App(
+                Rc::new(Term::Abs("w".to_string(), Rc::new(Term::Var("w".to_string())))),
+                Rc::new(Term::Unit),
+            )),
+        );
+
+        let trace = normalize_trace(&t);
+        assert_eq!(trace.len(), 1);
+        assert_eq!(trace[0].rule, RuleKind::Beta);
+        assert_eq!(trace[0].path, vec![PathStep::Body]);
+        assert_eq!(trace[0].after, Term::Abs("z".to_string(), Rc::new(Term::Unit)));
+    }
+
+    #[test]
+    fn test_normalize_trace_records_multiple_steps_in_order() {
+        // (λf. f ()) (λx. x) — a `Func`-side `Var` resolves to the identity
+        // first via Beta, then the resulting application reduces via a
+        // second Beta at the root.
+        let t = Term::App(
+            Rc::new(Term::Abs(
+                "f".to_string(),
+                Rc::new(Term::App(
+                    Rc::new(Term::Var("f".to_string())),
+                    Rc::new(Term::Unit),
+                )),
+            )),
+            Rc::new(Term::Abs(
+                "x".to_string(),
+                Rc::new(Term::Var("x".to_string())),
+            )),
+        );
+
+        let trace = normalize_trace(&t);
+        assert_eq!(trace.len(), 2);
+        assert!(trace.iter().all(|s| s.rule == RuleKind::Beta));
+        assert_eq!(trace.last().unwrap().after, Term::Unit);
+    }
+
+    #[test]
+    fn test_normalize_trace_records_non_beta_rules() {
+        // let (x, y) = ((), ⟨⟩) in x → () via the LetPair rule.
+        let t = Term::LetPair(
+            "x".to_string(),
+            "y".to_string(),
+            Rc::new(Term::Pair(Rc::new(Term::Unit), Rc::new(Term::Trivial))),
+            Rc::new(Term::Var("x".to_string())),
+        );
+
+        let trace = normalize_trace(&t);
+        assert_eq!(trace.len(), 1);
+        assert_eq!(trace[0].rule, RuleKind::LetPair);
+        assert_eq!(trace[0].after, Term::Unit);
+    }
+
+    #[test]
+    fn test_normalize_trace_empty_for_already_normal_term() {
+        assert_eq!(normalize_trace(&Term::Unit), Vec::new());
+    }
+
+    #[test]
+    fn test_step_fires_delta_reduction_on_saturated_builtin() {
+        use crate::builtin::{Builtin, Literal};
+
+        // is_zero 0 → inl ⟨⟩
+        let t = Term::App(Rc::new(Term::Builtin(Builtin::IsZero)), Rc::new(Term::Lit(Literal::Nat(0))));
+        assert_eq!(step(&t), Some(Term::Inl(Rc::new(Term::Trivial))));
+    }
+
+    #[test]
+    fn test_step_normalizes_the_forced_argument_first() {
+        use crate::builtin::{Builtin, Literal};
+
+        // is_zero ((λx. x) 0) → inl ⟨⟩ — the argument is itself a redex,
+        // which `step` must normalize before pattern-matching on it.
+        let arg = Term::App(
+            Rc::new(Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())))),
+            Rc::new(Term::Lit(Literal::Nat(0))),
+        );
+        let t = Term::App(Rc::new(Term::Builtin(Builtin::IsZero)), Rc::new(arg));
+        assert_eq!(normalize(&t), Term::Inl(Rc::new(Term::Trivial)));
+    }
+
+    #[test]
+    fn test_step_leaves_under_applied_builtin_unreduced() {
+        use crate::builtin::Builtin;
+
+        let t = Term::Builtin(Builtin::Fold);
+        assert_eq!(step(&t), None);
+    }
+
+    #[test]
+    fn test_fold_delta_reduces_then_continues_reducing() {
+        use crate::builtin::{Builtin, Literal};
+
+        // fold 2 succ 0 → succ (succ 0), which keeps reducing to Lit(2).
+        let t = Term::App(
+            Rc::new(Term::App(
+                Rc::new(Term::App(Rc::new(Term::Builtin(Builtin::Fold)), Rc::new(Term::Lit(Literal::Nat(2))))),
+                Rc::new(Term::Builtin(Builtin::Succ)),
+            )),
+            Rc::new(Term::Lit(Literal::Nat(0))),
+        );
+        assert_eq!(normalize(&t), Term::Lit(Literal::Nat(2)));
+    }
 }
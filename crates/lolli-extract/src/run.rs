@@ -0,0 +1,195 @@
+//! Running an extracted term against concrete input values.
+//!
+//! `Extract` and `Codegen` turn a proof into a [`Term`] but stop short of
+//! actually executing it. This module supplies the missing piece: parse a
+//! handful of value literals (one per antecedent) into `Term`s, apply them
+//! to the extracted term as an ordinary application spine, and drive the
+//! result to a value.
+//!
+//! Since every linear term is strongly normalizing, [`normalize`] is
+//! already exactly the "small evaluator" this needs: closures beta-reduce
+//! via `App`, tensor pairs destructure via `LetPair`, with-pairs project
+//! via `Fst`/`Snd`, sum injections eliminate via `Case`, and `!`-boxes via
+//! `Derelict`/`Copy`/`Discard`. So running a term is just: apply, then
+//! normalize.
+
+use std::rc::Rc;
+
+use lolli_core::Term;
+
+use crate::builtin::Literal;
+use crate::normalize::normalize;
+
+/// A value literal failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ValueError {
+    /// The input didn't match any recognized value syntax.
+    #[error("not a value: {0:?}")]
+    NotAValue(String),
+    /// A `(`, `!`, `inl`, or `inr` was never given its argument, or a pair
+    /// was never closed with `)`.
+    #[error("unexpected end of input in {0:?}")]
+    Incomplete(String),
+}
+
+/// Parse a single value literal: a natural number, `()` for unit, `<>` for
+/// the trivial value, `!v` for a promoted `!`-box, `inl v` / `inr v` for a
+/// sum injection, or `(v, w)` for a tensor or with pair (extraction
+/// represents both connectives the same way, as [`Term::Pair`]).
+///
+/// # Examples
+///
+/// ```
+/// use lolli_extract::parse_value;
+/// use lolli_core::Term;
+/// use std::rc::Rc;
+///
+/// assert_eq!(parse_value("()").unwrap(), Term::Unit);
+/// assert!(matches!(parse_value("(1, !2)").unwrap(), Term::Pair(_, _)));
+/// ```
+pub fn parse_value(input: &str) -> Result<Term, ValueError> {
+    let (term, rest) = parse_value_prefix(input)?;
+    let rest = rest.trim();
+    if !rest.is_empty() {
+        return Err(ValueError::NotAValue(input.to_string()));
+    }
+    Ok(term)
+}
+
+fn parse_value_prefix(input: &str) -> Result<(Term, &str), ValueError> {
+    let input = input.trim_start();
+
+    if input.is_empty() {
+        return Err(ValueError::Incomplete(input.to_string()));
+    }
+    if let Some(rest) = input.strip_prefix("()") {
+        return Ok((Term::Unit, rest));
+    }
+    if let Some(rest) = input.strip_prefix("<>") {
+        return Ok((Term::Trivial, rest));
+    }
+    if let Some(rest) = input.strip_prefix('!') {
+        let (inner, rest) = parse_value_prefix(rest)?;
+        return Ok((Term::Promote(Rc::new(inner)), rest));
+    }
+    if let Some(rest) = input.strip_prefix("inl") {
+        let (inner, rest) = parse_value_prefix(rest)?;
+        return Ok((Term::Inl(Rc::new(inner)), rest));
+    }
+    if let Some(rest) = input.strip_prefix("inr") {
+        let (inner, rest) = parse_value_prefix(rest)?;
+        return Ok((Term::Inr(Rc::new(inner)), rest));
+    }
+    if let Some(rest) = input.strip_prefix('(') {
+        let (first, rest) = parse_value_prefix(rest)?;
+        let rest = rest
+            .trim_start()
+            .strip_prefix(',')
+            .ok_or_else(|| ValueError::Incomplete(input.to_string()))?;
+        let (second, rest) = parse_value_prefix(rest)?;
+        let rest = rest
+            .trim_start()
+            .strip_prefix(')')
+            .ok_or_else(|| ValueError::Incomplete(input.to_string()))?;
+        return Ok((Term::Pair(Rc::new(first), Rc::new(second)), rest));
+    }
+
+    let digits_end = input.find(|c: char| !c.is_ascii_digit()).unwrap_or(input.len());
+    if digits_end > 0 {
+        let n: u64 = input[..digits_end]
+            .parse()
+            .map_err(|_| ValueError::NotAValue(input.to_string()))?;
+        return Ok((Term::Lit(Literal::Nat(n)), &input[digits_end..]));
+    }
+
+    Err(ValueError::NotAValue(input.to_string()))
+}
+
+/// Apply `term` to `args` in turn, as an ordinary application spine, and
+/// normalize the result to a value.
+///
+/// # Examples
+///
+/// ```
+/// use lolli_extract::{run_term, parse_value, Literal};
+/// use lolli_core::Term;
+/// use std::rc::Rc;
+///
+/// let id = Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())));
+/// let result = run_term(&id, vec![parse_value("5").unwrap()]);
+/// assert_eq!(result, Term::Lit(Literal::Nat(5)));
+/// ```
+pub fn run_term(term: &Term, args: Vec<Term>) -> Term {
+    let applied = args
+        .into_iter()
+        .fold(term.clone(), |acc, arg| Term::App(Rc::new(acc), Rc::new(arg)));
+    normalize(&applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_unit_and_trivial() {
+        assert_eq!(parse_value("()").unwrap(), Term::Unit);
+        assert_eq!(parse_value("<>").unwrap(), Term::Trivial);
+    }
+
+    #[test]
+    fn test_parse_nat_literal() {
+        assert_eq!(parse_value("42").unwrap(), Term::Lit(Literal::Nat(42)));
+    }
+
+    #[test]
+    fn test_parse_promoted_value() {
+        let v = parse_value("!7").unwrap();
+        assert_eq!(v, Term::Promote(Rc::new(Term::Lit(Literal::Nat(7)))));
+    }
+
+    #[test]
+    fn test_parse_pair() {
+        let v = parse_value("(1, 2)").unwrap();
+        assert_eq!(
+            v,
+            Term::Pair(
+                Rc::new(Term::Lit(Literal::Nat(1))),
+                Rc::new(Term::Lit(Literal::Nat(2))),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_sum_injection() {
+        assert_eq!(parse_value("inl ()").unwrap(), Term::Inl(Rc::new(Term::Unit)));
+        assert_eq!(parse_value("inr 3").unwrap(), Term::Inr(Rc::new(Term::Lit(Literal::Nat(3)))));
+    }
+
+    #[test]
+    fn test_trailing_garbage_is_an_error() {
+        assert!(matches!(parse_value("1 2"), Err(ValueError::NotAValue(_))));
+    }
+
+    #[test]
+    fn test_unclosed_pair_is_an_error() {
+        assert!(matches!(parse_value("(1, 2"), Err(ValueError::Incomplete(_))));
+    }
+
+    #[test]
+    fn test_run_identity_on_a_literal() {
+        let id = Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())));
+        let result = run_term(&id, vec![parse_value("9").unwrap()]);
+        assert_eq!(result, Term::Lit(Literal::Nat(9)));
+    }
+
+    #[test]
+    fn test_run_destructures_a_pair_argument() {
+        // λp. fst p, applied to (1, 2), should yield 1.
+        let fst_fn = Term::Abs(
+            "p".to_string(),
+            Rc::new(Term::Fst(Rc::new(Term::Var("p".to_string())))),
+        );
+        let result = run_term(&fst_fn, vec![parse_value("(1, 2)").unwrap()]);
+        assert_eq!(result, Term::Lit(Literal::Nat(1)));
+    }
+}
@@ -0,0 +1,496 @@
+//! A bidirectional linear type checker for extracted terms.
+//!
+//! Extraction trusts that the `Term` it builds matches the proof it came
+//! from, and nothing enforces the linearity discipline (each ordinary
+//! variable used exactly once; only `!`-marked values may be duplicated or
+//! dropped). This module checks that discipline directly against a
+//! [`Formula`], independently of any proof, so it can serve as an optional
+//! post-condition on extraction.
+//!
+//! The context is split multiplicatively for `Tensor`/`App`/`Pair` (each
+//! sub-term consumes its own share of the available variables) and shared
+//! additively for `With`/`Case` (both branches type-check from the *same*
+//! starting context and must consume exactly the same variables, since only
+//! one branch ever runs).
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use lolli_core::{Formula, Sequent, Term};
+
+/// Error from type-checking a [`Term`] against a [`Formula`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TypeError {
+    /// A variable was referenced that isn't bound, or was already consumed.
+    #[error("variable {0:?} is unbound, or was already used")]
+    UnboundVariable(String),
+
+    /// A linear variable bound in this scope was never used.
+    #[error("variable {0:?} is never used")]
+    UnusedVariable(String),
+
+    /// The term's constructor doesn't match the expected formula's shape.
+    #[error("expected a formula of the form {expected}, found {found}")]
+    ExpectedConnective {
+        /// A short description of the connective shape that was expected.
+        expected: String,
+        /// The formula actually found.
+        found: String,
+    },
+
+    /// A term was checked in inferred mode and the inferred type didn't
+    /// match what was expected.
+    #[error("expected type {expected}, found {found}")]
+    TypeMismatch {
+        /// The formula the term was checked against.
+        expected: String,
+        /// The formula the term actually inferred to.
+        found: String,
+    },
+
+    /// `Copy` or `Discard` was applied to a value whose type isn't
+    /// `!`-marked, so it cannot be duplicated or dropped.
+    #[error("value of type {0} is not `!`-marked, so it cannot be copied or discarded")]
+    NotReplicable(String),
+
+    /// The two branches of an additive rule (`With`-typed `Pair`, or
+    /// `Case`) didn't consume the same set of linear variables.
+    #[error("the two branches of an additive rule consume different variables")]
+    AdditiveContextMismatch,
+
+    /// This term isn't in a position where its type can be inferred (it
+    /// needs a surrounding formula to check against).
+    #[error("cannot infer a type for this term here; it needs an expected formula to check against")]
+    CannotInfer,
+
+    /// A proof's conclusion doesn't have the hypotheses-plus-one-goal shape
+    /// that [`check_proof`] expects.
+    #[error("expected exactly one non-hypothesis formula in the conclusion, found {0}")]
+    AmbiguousConclusion(usize),
+}
+
+#[derive(Debug, Clone, Default)]
+struct Context {
+    // Each binding tracks its formula and whether it's `!`-marked (and so
+    // may be used any number of times without being removed from scope).
+    vars: HashMap<String, (Formula, bool)>,
+}
+
+impl Context {
+    fn bind(&mut self, name: &str, formula: Formula, replicable: bool) {
+        self.vars.insert(name.to_string(), (formula, replicable));
+    }
+
+    fn use_var(&mut self, name: &str) -> Result<Formula, TypeError> {
+        match self.vars.get(name) {
+            Some((formula, true)) => Ok(formula.clone()),
+            Some((formula, false)) => {
+                let formula = formula.clone();
+                self.vars.remove(name);
+                Ok(formula)
+            }
+            None => Err(TypeError::UnboundVariable(name.to_string())),
+        }
+    }
+
+    fn assert_consumed(&self, name: &str) -> Result<(), TypeError> {
+        if self.vars.contains_key(name) {
+            Err(TypeError::UnusedVariable(name.to_string()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Check that `term` has type `formula`, starting from an empty context —
+/// i.e. `term` must be closed, with no free variables.
+pub fn check(term: &Term, formula: &Formula) -> Result<(), TypeError> {
+    check_in_context(term, formula, &mut Context::default())
+}
+
+fn check_in_context(term: &Term, formula: &Formula, ctx: &mut Context) -> Result<(), TypeError> {
+    match (term, formula) {
+        (Term::Unit, Formula::One) => Ok(()),
+        (Term::Trivial, Formula::Top) => Ok(()),
+
+        (Term::Abs(var, body), Formula::Lolli(dom, cod)) => {
+            ctx.bind(var, (**dom).clone(), false);
+            check_in_context(body, cod, ctx)?;
+            ctx.assert_consumed(var)
+        }
+
+        (Term::Pair(a, b), Formula::Tensor(ta, tb)) => {
+            check_in_context(a, ta, ctx)?;
+            check_in_context(b, tb, ctx)
+        }
+
+        (Term::Pair(a, b), Formula::With(ta, tb)) => {
+            let mut left_ctx = ctx.clone();
+            check_in_context(a, ta, &mut left_ctx)?;
+            let mut right_ctx = ctx.clone();
+            check_in_context(b, tb, &mut right_ctx)?;
+            if left_ctx.vars != right_ctx.vars {
+                return Err(TypeError::AdditiveContextMismatch);
+            }
+            *ctx = left_ctx;
+            Ok(())
+        }
+
+        (Term::Inl(inner), Formula::Plus(ta, _)) => check_in_context(inner, ta, ctx),
+        (Term::Inr(inner), Formula::Plus(_, tb)) => check_in_context(inner, tb, ctx),
+
+        (Term::Abort(inner), _) => {
+            // Ex falso: a term of type `0` (an impossible hypothesis)
+            // realizes any formula at all.
+            let inner_ty = infer(inner, ctx)?;
+            if inner_ty == Formula::Zero {
+                Ok(())
+            } else {
+                Err(TypeError::ExpectedConnective { expected: "0".to_string(), found: inner_ty.pretty() })
+            }
+        }
+
+        (Term::Promote(inner), Formula::OfCourse(a)) => {
+            // `!`-introduction may only depend on already-replicable
+            // hypotheses, so check the body in a context restricted to them.
+            let mut restricted = Context {
+                vars: ctx.vars.iter().filter(|(_, (_, rep))| *rep).map(|(k, v)| (k.clone(), v.clone())).collect(),
+            };
+            check_in_context(inner, a, &mut restricted)
+        }
+
+        (Term::LetPair(x, y, producer, consumer), _) => {
+            let producer_ty = infer(producer, ctx)?;
+            let Formula::Tensor(ta, tb) = producer_ty else {
+                return Err(TypeError::ExpectedConnective {
+                    expected: "A ⊗ B".to_string(),
+                    found: producer_ty.pretty(),
+                });
+            };
+            ctx.bind(x, *ta, false);
+            ctx.bind(y, *tb, false);
+            check_in_context(consumer, formula, ctx)?;
+            ctx.assert_consumed(x)?;
+            ctx.assert_consumed(y)
+        }
+
+        (Term::Case(subject, x, left, y, right), _) => {
+            let subject_ty = infer(subject, ctx)?;
+            let Formula::Plus(ta, tb) = subject_ty else {
+                return Err(TypeError::ExpectedConnective {
+                    expected: "A ⊕ B".to_string(),
+                    found: subject_ty.pretty(),
+                });
+            };
+
+            let mut left_ctx = ctx.clone();
+            left_ctx.bind(x, *ta, false);
+            check_in_context(left, formula, &mut left_ctx)?;
+            left_ctx.assert_consumed(x)?;
+
+            let mut right_ctx = ctx.clone();
+            right_ctx.bind(y, *tb, false);
+            check_in_context(right, formula, &mut right_ctx)?;
+            right_ctx.assert_consumed(y)?;
+
+            if left_ctx.vars != right_ctx.vars {
+                return Err(TypeError::AdditiveContextMismatch);
+            }
+            *ctx = left_ctx;
+            Ok(())
+        }
+
+        (Term::Copy(src, x, y, body), _) => {
+            let src_ty = infer(src, ctx)?;
+            if !matches!(src_ty, Formula::OfCourse(_)) {
+                return Err(TypeError::NotReplicable(src_ty.pretty()));
+            }
+            ctx.bind(x, src_ty.clone(), true);
+            ctx.bind(y, src_ty, true);
+            check_in_context(body, formula, ctx)
+        }
+
+        (Term::Discard(value, body), _) => {
+            let value_ty = infer(value, ctx)?;
+            if !matches!(value_ty, Formula::OfCourse(_)) {
+                return Err(TypeError::NotReplicable(value_ty.pretty()));
+            }
+            check_in_context(body, formula, ctx)
+        }
+
+        // Everything else falls back to inference and a type comparison.
+        _ => {
+            let inferred = infer(term, ctx)?;
+            if &inferred == formula {
+                Ok(())
+            } else {
+                Err(TypeError::TypeMismatch {
+                    expected: formula.pretty(),
+                    found: inferred.pretty(),
+                })
+            }
+        }
+    }
+}
+
+fn infer(term: &Term, ctx: &mut Context) -> Result<Formula, TypeError> {
+    match term {
+        Term::Var(name) => ctx.use_var(name),
+        Term::Unit => Ok(Formula::One),
+        Term::Trivial => Ok(Formula::Top),
+
+        Term::App(f, arg) => {
+            let f_ty = infer(f, ctx)?;
+            let Formula::Lolli(dom, cod) = f_ty else {
+                return Err(TypeError::ExpectedConnective {
+                    expected: "A ⊸ B".to_string(),
+                    found: f_ty.pretty(),
+                });
+            };
+            check_in_context(arg, &dom, ctx)?;
+            Ok(*cod)
+        }
+
+        Term::Fst(pair) => {
+            let pair_ty = infer(pair, ctx)?;
+            match pair_ty {
+                Formula::With(a, _) => Ok(*a),
+                other => Err(TypeError::ExpectedConnective {
+                    expected: "A & B".to_string(),
+                    found: other.pretty(),
+                }),
+            }
+        }
+
+        Term::Snd(pair) => {
+            let pair_ty = infer(pair, ctx)?;
+            match pair_ty {
+                Formula::With(_, b) => Ok(*b),
+                other => Err(TypeError::ExpectedConnective {
+                    expected: "A & B".to_string(),
+                    found: other.pretty(),
+                }),
+            }
+        }
+
+        Term::Derelict(inner) => {
+            let inner_ty = infer(inner, ctx)?;
+            match inner_ty {
+                Formula::OfCourse(a) => Ok(*a),
+                other => Err(TypeError::ExpectedConnective {
+                    expected: "!A".to_string(),
+                    found: other.pretty(),
+                }),
+            }
+        }
+
+        Term::LetPair(x, y, producer, consumer) => {
+            let producer_ty = infer(producer, ctx)?;
+            let Formula::Tensor(ta, tb) = producer_ty else {
+                return Err(TypeError::ExpectedConnective {
+                    expected: "A ⊗ B".to_string(),
+                    found: producer_ty.pretty(),
+                });
+            };
+            ctx.bind(x, *ta, false);
+            ctx.bind(y, *tb, false);
+            let result = infer(consumer, ctx)?;
+            ctx.assert_consumed(x)?;
+            ctx.assert_consumed(y)?;
+            Ok(result)
+        }
+
+        // `Abs`, `Pair`, `Inl`/`Inr`, `Promote`, `Copy`, `Discard`, `Case`,
+        // and `Abort` are ambiguous or under-determined without a checking
+        // position (e.g. a `Pair` could be a `Tensor` or a `With`) — they
+        // need an expected formula from [`check_in_context`], not inference.
+        _ => Err(TypeError::CannotInfer),
+    }
+}
+
+/// Check that `term` realizes `conclusion`'s computational content: every
+/// `Formula::NegAtom` in `conclusion` is treated as a hypothesis bound to a
+/// free variable named after the atom, lower-cased (the name the extractor's
+/// axiom case gives an unbound hypothesis), and the single remaining formula is the goal `term` must
+/// produce, with every hypothesis consumed exactly once.
+///
+/// This is the post-condition [`crate::Extractor`] wires up: run it after
+/// `extract` to assert that the extraction was sound, rather than trusting
+/// it blindly.
+pub fn check_proof(term: &Term, conclusion: &Sequent) -> Result<(), TypeError> {
+    let mut ctx = Context::default();
+    let mut goal = None;
+
+    for formula in &conclusion.linear {
+        if let Formula::NegAtom(name) = formula {
+            ctx.bind(&name.to_lowercase(), Formula::Atom(name.clone()), false);
+        } else if goal.replace(formula.clone()).is_some() {
+            return Err(TypeError::AmbiguousConclusion(
+                conclusion.linear.iter().filter(|f| !matches!(f, Formula::NegAtom(_))).count(),
+            ));
+        }
+    }
+
+    let goal = goal.ok_or(TypeError::AmbiguousConclusion(0))?;
+    check_in_context(term, &goal, &mut ctx)?;
+
+    for name in ctx.vars.keys() {
+        // Only non-replicable hypotheses must be consumed; `!`-marked ones
+        // may be weakened.
+        if !ctx.vars[name].1 {
+            return Err(TypeError::UnusedVariable(name.clone()));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity_checks() {
+        let term = Term::Abs("x".to_string(), Rc::new(Term::Var("x".to_string())));
+        let formula = Formula::lolli(Formula::atom("A"), Formula::atom("A"));
+        assert_eq!(check(&term, &formula), Ok(()));
+    }
+
+    #[test]
+    fn test_unused_variable_is_rejected() {
+        let term = Term::Abs("x".to_string(), Rc::new(Term::Unit));
+        let formula = Formula::lolli(Formula::One, Formula::One);
+        assert_eq!(check(&term, &formula), Err(TypeError::UnusedVariable("x".to_string())));
+    }
+
+    #[test]
+    fn test_double_use_is_rejected() {
+        // λx. (x, x) : A ⊗ A — `x` used twice, but Tensor splits the
+        // context multiplicatively, so the second use finds it gone.
+        let term = Term::Abs(
+            "x".to_string(),
+            Rc::new(Term::Pair(Rc::new(Term::Var("x".to_string())), Rc::new(Term::Var("x".to_string())))),
+        );
+        let formula = Formula::lolli(Formula::atom("A"), Formula::tensor(Formula::atom("A"), Formula::atom("A")));
+        assert_eq!(check(&term, &formula), Err(TypeError::UnboundVariable("x".to_string())));
+    }
+
+    #[test]
+    fn test_copy_and_discard_require_of_course() {
+        let copy_of_linear = Term::Copy(
+            Rc::new(Term::Var("x".to_string())),
+            "a".to_string(),
+            "b".to_string(),
+            Rc::new(Term::Unit),
+        );
+        let err = check_in_context(
+            &copy_of_linear,
+            &Formula::One,
+            &mut {
+                let mut ctx = Context::default();
+                ctx.bind("x", Formula::atom("A"), false);
+                ctx
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, TypeError::NotReplicable("A".to_string()));
+
+        let discard_of_linear = Term::Discard(Rc::new(Term::Var("x".to_string())), Rc::new(Term::Unit));
+        let err = check_in_context(
+            &discard_of_linear,
+            &Formula::One,
+            &mut {
+                let mut ctx = Context::default();
+                ctx.bind("x", Formula::atom("A"), false);
+                ctx
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, TypeError::NotReplicable("A".to_string()));
+    }
+
+    #[test]
+    fn test_copy_duplicates_a_replicable_value() {
+        // copy x as (a, b) in (a, b) : !A ⊗ !A, given x : !A
+        let term = Term::Copy(
+            Rc::new(Term::Var("x".to_string())),
+            "a".to_string(),
+            "b".to_string(),
+            Rc::new(Term::Pair(Rc::new(Term::Var("a".to_string())), Rc::new(Term::Var("b".to_string())))),
+        );
+        let formula =
+            Formula::tensor(Formula::of_course(Formula::atom("A")), Formula::of_course(Formula::atom("A")));
+
+        let mut ctx = Context::default();
+        ctx.bind("x", Formula::of_course(Formula::atom("A")), false);
+        assert_eq!(check_in_context(&term, &formula, &mut ctx), Ok(()));
+    }
+
+    #[test]
+    fn test_additive_with_pair_must_agree_on_leftover_context() {
+        // (x, z) & (x, ()) : (A ⊗ 1) & (A ⊗ 1) — both branches produce the
+        // same type, but the left one also consumes the outer hypothesis
+        // `z`, while the right one doesn't: unsound, since only one side
+        // of a `With` pair is ever actually evaluated.
+        let term = Term::Pair(
+            Rc::new(Term::Pair(Rc::new(Term::Var("x".to_string())), Rc::new(Term::Var("z".to_string())))),
+            Rc::new(Term::Pair(Rc::new(Term::Var("x".to_string())), Rc::new(Term::Unit))),
+        );
+        let branch = Formula::tensor(Formula::atom("A"), Formula::One);
+        let formula = Formula::with(branch.clone(), branch);
+
+        let mut ctx = Context::default();
+        ctx.bind("x", Formula::atom("A"), false);
+        ctx.bind("z", Formula::One, false);
+
+        assert_eq!(check_in_context(&term, &formula, &mut ctx), Err(TypeError::AdditiveContextMismatch));
+    }
+
+    #[test]
+    fn test_case_requires_matching_leftover_variables() {
+        // case s of { inl x => (x, z) | inr y => (y, ()) } — both branches
+        // agree on the result type `A ⊗ 1`, but the left branch also
+        // consumes the outer hypothesis `z` while the right one doesn't,
+        // which is unsound: only one branch actually runs.
+        let term = Term::Case(
+            Rc::new(Term::Var("s".to_string())),
+            "x".to_string(),
+            Rc::new(Term::Pair(Rc::new(Term::Var("x".to_string())), Rc::new(Term::Var("z".to_string())))),
+            "y".to_string(),
+            Rc::new(Term::Pair(Rc::new(Term::Var("y".to_string())), Rc::new(Term::Unit))),
+        );
+        let formula = Formula::tensor(Formula::atom("A"), Formula::One);
+
+        let mut ctx = Context::default();
+        ctx.bind("s", Formula::plus(Formula::atom("A"), Formula::atom("A")), false);
+        ctx.bind("z", Formula::One, false);
+
+        assert_eq!(check_in_context(&term, &formula, &mut ctx), Err(TypeError::AdditiveContextMismatch));
+    }
+
+    #[test]
+    fn test_check_proof_for_tensor_extraction() {
+        // The term an honest Tensor-introduction extracts: Pair(Var(a), Var(b))
+        // realizing `A ⊗ B` from hypotheses `a : A` and `b : B`.
+        let term = Term::Pair(Rc::new(Term::Var("a".to_string())), Rc::new(Term::Var("b".to_string())));
+        let conclusion = Sequent::new(vec![
+            Formula::neg_atom("A"),
+            Formula::neg_atom("B"),
+            Formula::tensor(Formula::atom("A"), Formula::atom("B")),
+        ]);
+
+        assert_eq!(check_proof(&term, &conclusion), Ok(()));
+    }
+
+    #[test]
+    fn test_check_proof_catches_an_unconsumed_hypothesis() {
+        let term = Term::Var("a".to_string());
+        let conclusion = Sequent::new(vec![
+            Formula::neg_atom("A"),
+            Formula::neg_atom("B"),
+            Formula::atom("A"),
+        ]);
+
+        assert_eq!(check_proof(&term, &conclusion), Err(TypeError::UnusedVariable("b".to_string())));
+    }
+}
@@ -16,15 +16,47 @@
 //! | Bang | ! | ! |
 //! | Why not | ? | ? |
 //! | Negation | A⊥ | A^ |
+//! | Iff | ⊸⊸ / ⧟ | o-o |
 //! | Turnstile | ⊢ | \|- |
 //!
+//! `A o-o B` is non-associative sugar for `(A ⊸ B) & (B ⊸ A)`: it sits at
+//! the loosest precedence, below `-o`, and `A o-o B o-o C` is a parse
+//! error rather than an implicit grouping.
+//!
+//! ## Comments
+//!
+//! Line comments start with `%` or `//` and run to end of line; block comments
+//! are `(* ... *)` and may nest. Comments are treated as whitespace and can
+//! appear anywhere a space is allowed.
+//!
+//! Predicates apply a name to zero or more first-order terms, e.g. `P(x, f(y))`.
+//!
+//! ## Tokens
+//!
+//! [`tokenize`] (and its iterator form [`tokenize_iter`]) exposes the lexical
+//! token stream behind a formula or sequent — each [`Token`] carries its
+//! [`TokenKind`], byte span, and line/column — for tools that want syntax
+//! highlighting or bracket matching without re-deriving the grammar.
+//!
+//! ## Programs
+//!
+//! [`parse_program`] parses a whole theory document: a sequence of
+//! declarations, named definitions, and goal sequents. See [`Program`] for
+//! the item syntax.
+//!
+//! ## Problem files
+//!
+//! [`parse_problem`] parses a batch problem file: an ordered sequence of
+//! named `axiom`/`lemma`/`assertion`/`definition` statements. See
+//! [`Problem`] for the statement syntax.
+//!
 //! ## Example
 //!
 //! ```
 //! use lolli_parse::{parse_formula, parse_sequent};
 //!
 //! let formula = parse_formula("A -o B").unwrap();
-//! assert_eq!(formula.pretty(), "(A ⊸ B)");
+//! assert_eq!(formula.pretty(), "A ⊸ B");
 //!
 //! let sequent = parse_sequent("A, B |- A * B").unwrap();
 //! assert_eq!(sequent.antecedent.len(), 2);
@@ -36,34 +68,231 @@
 use pest::Parser;
 use pest_derive::Parser;
 
-pub use lolli_core::{Formula, Sequent, TwoSidedSequent};
+pub use lolli_core::{FolTerm, Formula, Sequent, TwoSidedSequent};
+
+mod program;
+pub use program::{parse_program, Program};
+
+mod problem;
+pub use problem::{parse_problem, Problem, Statement, StatementBody, StatementKind};
+
+mod tokenize;
+pub use tokenize::{tokenize, tokenize_iter, Token, TokenKind};
 
 #[derive(Parser)]
 #[grammar = "grammar.pest"]
 struct LolliParser;
 
+/// A byte-offset span into the original input.
+///
+/// `end` is `None` when the error only pins down a single position
+/// (e.g. "input ended here") rather than a range of offending text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    /// Byte offset of the first character covered by the span.
+    pub start: usize,
+    /// Byte offset one past the last character covered by the span, if known.
+    pub end: Option<usize>,
+}
+
+impl Span {
+    /// Create a span covering a single point with no extent.
+    pub fn point(offset: usize) -> Self {
+        Span {
+            start: offset,
+            end: None,
+        }
+    }
+
+    fn from_pest(span: pest::Span<'_>) -> Self {
+        Span {
+            start: span.start(),
+            end: Some(span.end()),
+        }
+    }
+}
+
+/// A 1-based line/column position, computed from a byte offset into the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column number.
+    pub column: usize,
+}
+
+impl Position {
+    /// Compute the line/column position of a byte offset in `input`, advancing
+    /// one column per character and resetting the column on each newline.
+    pub fn from_offset(input: &str, offset: usize) -> Self {
+        let mut line = 1;
+        let mut column = 1;
+        for ch in input[..offset.min(input.len())].chars() {
+            if ch == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+        Position { line, column }
+    }
+}
+
 /// Parse error type.
 #[derive(Debug, thiserror::Error)]
 pub enum ParseError {
     /// Unexpected token in input
-    #[error("Unexpected token: {0}")]
-    UnexpectedToken(String),
+    #[error("Unexpected token: {token}")]
+    UnexpectedToken {
+        /// The offending token text
+        token: String,
+        /// Where the token occurred
+        span: Span,
+    },
 
     /// Unknown operator
-    #[error("Unknown operator: {0}")]
-    UnknownOperator(String),
+    #[error("Unknown operator: {operator}")]
+    UnknownOperator {
+        /// The unrecognized operator text
+        operator: String,
+        /// Where the operator occurred
+        span: Span,
+    },
 
     /// Unexpected rule during parsing
-    #[error("Unexpected rule: {0}")]
-    UnexpectedRule(String),
+    #[error("Unexpected rule: {rule}")]
+    UnexpectedRule {
+        /// The pest rule that was not expected here
+        rule: String,
+        /// Where the rule matched
+        span: Span,
+    },
 
     /// Pest parsing error
-    #[error("Parse error: {0}")]
-    PestError(#[from] pest::error::Error<Rule>),
+    #[error("Parse error: {message}")]
+    PestError {
+        /// The message from pest's error variant
+        message: String,
+        /// Where pest reported the failure
+        span: Span,
+    },
 
     /// Empty input
     #[error("Empty input")]
-    EmptyInput,
+    EmptyInput {
+        /// The start of the input, or the span of the rule that came up empty
+        span: Span,
+    },
+
+    /// A declaration name was declared more than once.
+    #[error("Duplicate declaration: {name}")]
+    DuplicateDeclaration {
+        /// The name that was declared twice
+        name: String,
+        /// Where the duplicate declaration occurred
+        span: Span,
+    },
+
+    /// A predicate was used with a different arity than it was declared with.
+    #[error("Arity mismatch for {name}: expected {expected}, got {got}")]
+    ArityMismatch {
+        /// The predicate name
+        name: String,
+        /// The declared arity
+        expected: usize,
+        /// The arity actually used
+        got: usize,
+        /// Where the mismatched use occurred
+        span: Span,
+    },
+
+    /// A name was referenced that has no declaration or definition.
+    #[error("Undefined name: {name}")]
+    UndefinedName {
+        /// The undefined name
+        name: String,
+        /// Where the reference occurred
+        span: Span,
+    },
+
+    /// A binary operator was the last thing in the input, with no right
+    /// operand following it.
+    #[error("Expected formula after operator: {operator}")]
+    IncompleteExpression {
+        /// The dangling operator text
+        operator: String,
+        /// Always points at the end of input, not the operator itself
+        span: Span,
+    },
+}
+
+impl From<pest::error::Error<Rule>> for ParseError {
+    fn from(err: pest::error::Error<Rule>) -> Self {
+        let span = match err.location {
+            pest::error::InputLocation::Pos(p) => Span::point(p),
+            pest::error::InputLocation::Span((start, end)) => Span {
+                start,
+                end: Some(end),
+            },
+        };
+        ParseError::PestError {
+            message: err.variant.message().to_string(),
+            span,
+        }
+    }
+}
+
+impl ParseError {
+    /// The span in the original input this error is anchored to.
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::UnexpectedToken { span, .. }
+            | ParseError::UnknownOperator { span, .. }
+            | ParseError::UnexpectedRule { span, .. }
+            | ParseError::PestError { span, .. }
+            | ParseError::EmptyInput { span }
+            | ParseError::DuplicateDeclaration { span, .. }
+            | ParseError::ArityMismatch { span, .. }
+            | ParseError::UndefinedName { span, .. }
+            | ParseError::IncompleteExpression { span, .. } => *span,
+        }
+    }
+
+    /// Render this error as a multi-line report: the message, the offending
+    /// line of `input`, and a `^~~~` caret underline beneath the span.
+    pub fn render(&self, input: &str) -> String {
+        let span = self.span();
+        let pos = Position::from_offset(input, span.start);
+
+        let line_start = input[..span.start.min(input.len())]
+            .rfind('\n')
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        let line_end = input[span.start.min(input.len())..]
+            .find('\n')
+            .map(|i| span.start + i)
+            .unwrap_or(input.len());
+        let line = &input[line_start..line_end];
+
+        let caret_start = span.start.saturating_sub(line_start);
+        let end = span.end.unwrap_or(span.start + 1).max(span.start + 1);
+        let caret_len = end.min(line_end).saturating_sub(span.start).max(1);
+
+        let mut underline = String::new();
+        underline.push('^');
+        underline.push_str(&"~".repeat(caret_len - 1));
+
+        format!(
+            "{}\n  --> line {}, column {}\n  | {}\n  | {}{}",
+            self,
+            pos.line,
+            pos.column,
+            line,
+            " ".repeat(caret_start),
+            underline
+        )
+    }
 }
 
 /// Parse a formula from a string.
@@ -87,11 +316,72 @@ pub enum ParseError {
 ///
 /// Returns a `ParseError` if the input is not a valid formula.
 pub fn parse_formula(input: &str) -> Result<Formula, ParseError> {
-    let pairs = LolliParser::parse(Rule::formula, input)?;
-    let pair = pairs.into_iter().next().ok_or(ParseError::EmptyInput)?;
+    let pairs = LolliParser::parse(Rule::formula_entry, input)?;
+    let entry = pairs
+        .into_iter()
+        .next()
+        .ok_or(ParseError::EmptyInput { span: Span::point(0) })?;
+    let pair = entry
+        .into_inner()
+        .next()
+        .ok_or(ParseError::EmptyInput { span: Span::point(0) })?;
+    check_fully_consumed(&pair, input)?;
     build_formula(pair)
 }
 
+/// Parse a formula written in `pretty_ascii`'s surface syntax.
+///
+/// This is the same grammar [`parse_formula`] uses — it already accepts
+/// the ASCII spellings (`*`, `|`, `-o`, `&`, `+`, `!`, `?`, `^`, `1`, `bot`,
+/// `top`, `0`) alongside the Unicode symbols — so this is an alias kept
+/// for callers who only ever feed it ASCII and want that documented at the
+/// call site. `parse_ascii(f.pretty_ascii())` round-trips to `f`.
+///
+/// # Errors
+///
+/// Returns a `ParseError` if the input is not a valid formula.
+pub fn parse_ascii(input: &str) -> Result<Formula, ParseError> {
+    parse_formula(input)
+}
+
+/// The binary operators that appear in the grammar as an optional or
+/// repeated `(op ~ rhs)` suffix. When their right-hand side is missing,
+/// pest backtracks the whole suffix and leaves the bare operator text as
+/// the unconsumed remainder — see `check_fully_consumed`.
+const DANGLING_BINARY_OPERATORS: &[&str] =
+    &["o-o", "⊸⊸", "⧟", "-o", "⊸", "⅋", "|", "⊗", "*", "⊕", "+", "&"];
+
+/// Reject a pair that didn't consume the whole input, reporting the
+/// leftover text as an unexpected token rather than silently ignoring it.
+///
+/// One leftover shape gets a dedicated diagnosis: a binary operator with no
+/// right operand (e.g. `"A -o"`). Because that operand is parsed via an
+/// optional/repeated `(op ~ rhs)` suffix, pest backtracks the *entire*
+/// suffix when `rhs` fails to match, so the only trace of the failure is
+/// the bare operator sitting unconsumed at the end of input. Reporting that
+/// as "unexpected token: -o" would point at the operator as if it were the
+/// problem; the actual gap is the missing formula after it, so the span
+/// points at the true point of incompleteness — the end of input.
+fn check_fully_consumed(pair: &Pair<Rule>, input: &str) -> Result<(), ParseError> {
+    let end = pair.as_span().end();
+    let rest = &input[end..];
+    let trimmed = rest.trim();
+    if trimmed.is_empty() {
+        return Ok(());
+    }
+    if DANGLING_BINARY_OPERATORS.contains(&trimmed) {
+        return Err(ParseError::IncompleteExpression {
+            operator: trimmed.to_string(),
+            span: Span::point(input.len()),
+        });
+    }
+    let trailing_ws = rest.len() - rest.trim_start().len();
+    Err(ParseError::UnexpectedToken {
+        token: trimmed.to_string(),
+        span: Span::point(end + trailing_ws),
+    })
+}
+
 /// Parse a sequent from a string.
 ///
 /// # Examples
@@ -110,8 +400,16 @@ pub fn parse_formula(input: &str) -> Result<Formula, ParseError> {
 ///
 /// Returns a `ParseError` if the input is not a valid sequent.
 pub fn parse_sequent(input: &str) -> Result<TwoSidedSequent, ParseError> {
-    let pairs = LolliParser::parse(Rule::sequent, input)?;
-    let pair = pairs.into_iter().next().ok_or(ParseError::EmptyInput)?;
+    let pairs = LolliParser::parse(Rule::sequent_entry, input)?;
+    let entry = pairs
+        .into_iter()
+        .next()
+        .ok_or(ParseError::EmptyInput { span: Span::point(0) })?;
+    let pair = entry
+        .into_inner()
+        .next()
+        .ok_or(ParseError::EmptyInput { span: Span::point(0) })?;
+    check_fully_consumed(&pair, input)?;
     build_sequent(pair)
 }
 
@@ -120,9 +418,15 @@ use pest::iterators::Pair;
 fn build_formula(pair: Pair<Rule>) -> Result<Formula, ParseError> {
     match pair.as_rule() {
         Rule::formula => {
-            let inner = pair.into_inner().next().ok_or(ParseError::EmptyInput)?;
+            let span = pair.as_span();
+            let inner = pair
+                .into_inner()
+                .next()
+                .ok_or(ParseError::EmptyInput { span: Span::from_pest(span) })?;
             build_formula(inner)
         }
+        Rule::quant_expr => build_quant_expr(pair),
+        Rule::iff_expr => build_iff_expr(pair),
         Rule::lolli_expr => build_lolli_expr(pair),
         Rule::par_expr => build_left_assoc_binary(pair, Rule::par_op, Formula::par),
         Rule::tensor_expr => build_left_assoc_binary(pair, Rule::tensor_op, Formula::tensor),
@@ -130,25 +434,106 @@ fn build_formula(pair: Pair<Rule>) -> Result<Formula, ParseError> {
         Rule::with_expr => build_left_assoc_binary(pair, Rule::with_op, Formula::with),
         Rule::unary_expr => build_unary_expr(pair),
         Rule::primary_expr => build_primary_expr(pair),
+        Rule::predicate => build_predicate(pair),
         Rule::ident => Ok(Formula::Atom(pair.as_str().to_string())),
         Rule::one => Ok(Formula::One),
         Rule::bottom => Ok(Formula::Bottom),
         Rule::top => Ok(Formula::Top),
         Rule::zero => Ok(Formula::Zero),
-        _ => Err(ParseError::UnexpectedRule(format!("{:?}", pair.as_rule()))),
+        _ => Err(ParseError::UnexpectedRule {
+            rule: format!("{:?}", pair.as_rule()),
+            span: Span::from_pest(pair.as_span()),
+        }),
+    }
+}
+
+/// Build a formula from a `quant_expr` pair: a `forall`/`exists` binder
+/// consumes the rest of the formula as its body, so `forall x. A -o B`
+/// parses as `forall x. (A -o B)`, not `(forall x. A) -o B`.
+fn build_quant_expr(pair: Pair<Rule>) -> Result<Formula, ParseError> {
+    let span = pair.as_span();
+    let mut inner = pair.into_inner().peekable();
+    let first = inner
+        .peek()
+        .ok_or(ParseError::EmptyInput { span: Span::from_pest(span) })?;
+
+    match first.as_rule() {
+        Rule::forall_op | Rule::exists_op => {
+            let is_forall = first.as_rule() == Rule::forall_op;
+            inner.next(); // consume the operator
+
+            let var = inner
+                .next()
+                .ok_or(ParseError::EmptyInput { span: Span::from_pest(span) })?
+                .as_str()
+                .to_string();
+
+            let body = inner
+                .next()
+                .ok_or(ParseError::EmptyInput { span: Span::from_pest(span) })?;
+            let body = build_formula(body)?;
+
+            Ok(if is_forall {
+                Formula::forall(var, body)
+            } else {
+                Formula::exists(var, body)
+            })
+        }
+        _ => {
+            let iff = inner
+                .next()
+                .ok_or(ParseError::EmptyInput { span: Span::from_pest(span) })?;
+            build_formula(iff)
+        }
+    }
+}
+
+/// Build a formula from an `iff_expr` pair: `A o-o B` desugars to
+/// `(A ⊸ B) & (B ⊸ A)`. Non-associative by construction — the grammar
+/// admits at most one `iff_op`, so `A o-o B o-o C` never reaches this
+/// function with more than two operands.
+fn build_iff_expr(pair: Pair<Rule>) -> Result<Formula, ParseError> {
+    let span = pair.as_span();
+    let mut inner = pair.into_inner();
+    let first = inner
+        .next()
+        .ok_or(ParseError::EmptyInput { span: Span::from_pest(span) })?;
+    let left = build_formula(first)?;
+
+    match inner.next() {
+        None => Ok(left),
+        Some(op) if op.as_rule() == Rule::iff_op => {
+            let right = inner
+                .next()
+                .ok_or(ParseError::EmptyInput { span: Span::from_pest(span) })?;
+            let right = build_formula(right)?;
+            Ok(Formula::with(
+                Formula::lolli(left.clone(), right.clone()),
+                Formula::lolli(right, left),
+            ))
+        }
+        Some(unexpected) => Err(ParseError::UnexpectedRule {
+            rule: format!("{:?}", unexpected.as_rule()),
+            span: Span::from_pest(unexpected.as_span()),
+        }),
     }
 }
 
 fn build_lolli_expr(pair: Pair<Rule>) -> Result<Formula, ParseError> {
+    let span = pair.as_span();
     let mut inner = pair.into_inner();
-    let first = inner.next().ok_or(ParseError::EmptyInput)?;
+    let first = inner
+        .next()
+        .ok_or(ParseError::EmptyInput { span: Span::from_pest(span) })?;
     let mut result = build_formula(first)?;
 
     // Check for lolli operator and right side
     while let Some(op_or_expr) = inner.next() {
         if op_or_expr.as_rule() == Rule::lolli_op {
             // Get the right side (which is itself a lolli_expr for right associativity)
-            let right = inner.next().ok_or(ParseError::EmptyInput)?;
+            let right = inner
+                .next()
+                .ok_or(ParseError::EmptyInput { span: Span::from_pest(span) })?;
             let right_formula = build_formula(right)?;
             result = Formula::lolli(result, right_formula);
         } else {
@@ -169,8 +554,11 @@ fn build_left_assoc_binary<F>(
 where
     F: Fn(Formula, Formula) -> Formula,
 {
+    let span = pair.as_span();
     let mut inner = pair.into_inner();
-    let first = inner.next().ok_or(ParseError::EmptyInput)?;
+    let first = inner
+        .next()
+        .ok_or(ParseError::EmptyInput { span: Span::from_pest(span) })?;
     let mut result = build_formula(first)?;
 
     while let Some(next) = inner.next() {
@@ -186,27 +574,36 @@ where
 }
 
 fn build_unary_expr(pair: Pair<Rule>) -> Result<Formula, ParseError> {
+    let span = pair.as_span();
     let mut inner = pair.into_inner().peekable();
 
     // Check for prefix operators
-    let first = inner.peek().ok_or(ParseError::EmptyInput)?;
+    let first = inner
+        .peek()
+        .ok_or(ParseError::EmptyInput { span: Span::from_pest(span) })?;
 
     match first.as_rule() {
         Rule::bang_op => {
             inner.next(); // consume the operator
-            let operand = inner.next().ok_or(ParseError::EmptyInput)?;
+            let operand = inner
+                .next()
+                .ok_or(ParseError::EmptyInput { span: Span::from_pest(span) })?;
             let formula = build_formula(operand)?;
             Ok(Formula::of_course(formula))
         }
         Rule::whynot_op => {
             inner.next(); // consume the operator
-            let operand = inner.next().ok_or(ParseError::EmptyInput)?;
+            let operand = inner
+                .next()
+                .ok_or(ParseError::EmptyInput { span: Span::from_pest(span) })?;
             let formula = build_formula(operand)?;
             Ok(Formula::why_not(formula))
         }
         _ => {
             // Primary expression with optional negation suffix
-            let primary = inner.next().ok_or(ParseError::EmptyInput)?;
+            let primary = inner
+                .next()
+                .ok_or(ParseError::EmptyInput { span: Span::from_pest(span) })?;
             let mut formula = build_formula(primary)?;
 
             // Check for negation suffix
@@ -221,8 +618,43 @@ fn build_unary_expr(pair: Pair<Rule>) -> Result<Formula, ParseError> {
     }
 }
 
+fn build_predicate(pair: Pair<Rule>) -> Result<Formula, ParseError> {
+    let mut inner = pair.into_inner();
+    let name = inner
+        .next()
+        .expect("predicate rule always has a leading ident")
+        .as_str()
+        .to_string();
+    let args = match inner.next() {
+        Some(list) => build_fol_term_list(list)?,
+        None => Vec::new(),
+    };
+    Ok(Formula::predicate(name, args))
+}
+
+fn build_fol_term_list(pair: Pair<Rule>) -> Result<Vec<FolTerm>, ParseError> {
+    pair.into_inner().map(build_fol_term).collect()
+}
+
+fn build_fol_term(pair: Pair<Rule>) -> Result<FolTerm, ParseError> {
+    let mut inner = pair.into_inner();
+    let name = inner
+        .next()
+        .expect("fol_term rule always has a leading ident")
+        .as_str()
+        .to_string();
+    match inner.next() {
+        Some(list) => Ok(FolTerm::app(name, build_fol_term_list(list)?)),
+        None => Ok(FolTerm::var(name)),
+    }
+}
+
 fn build_primary_expr(pair: Pair<Rule>) -> Result<Formula, ParseError> {
-    let inner = pair.into_inner().next().ok_or(ParseError::EmptyInput)?;
+    let span = pair.as_span();
+    let inner = pair
+        .into_inner()
+        .next()
+        .ok_or(ParseError::EmptyInput { span: Span::from_pest(span) })?;
     build_formula(inner)
 }
 
@@ -265,6 +697,28 @@ fn build_formula_list(pair: Pair<Rule>) -> Result<Vec<Formula>, ParseError> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_position_from_offset() {
+        let input = "A -o\nB";
+        // 'B' is at offset 5, on line 2, column 1
+        assert_eq!(Position::from_offset(input, 5), Position { line: 2, column: 1 });
+        assert_eq!(Position::from_offset(input, 0), Position { line: 1, column: 1 });
+    }
+
+    #[test]
+    fn test_parse_error_span() {
+        let err = parse_formula("A -o").unwrap_err();
+        assert_eq!(err.span().start, 4);
+    }
+
+    #[test]
+    fn test_render_has_caret() {
+        let err = parse_formula("A -o").unwrap_err();
+        let rendered = err.render("A -o");
+        assert!(rendered.contains('^'));
+        assert!(rendered.contains("line 1, column"));
+    }
+
     #[test]
     fn test_parse_atoms() {
         let f = parse_formula("A").unwrap();
@@ -452,6 +906,60 @@ mod tests {
         assert_eq!(s.succedent.len(), 1);
     }
 
+    #[test]
+    fn test_line_comments() {
+        let f = parse_formula("A % a resource\n-o B").unwrap();
+        assert_eq!(f, Formula::lolli(Formula::atom("A"), Formula::atom("B")));
+
+        let f = parse_formula("A // a resource\n-o B").unwrap();
+        assert_eq!(f, Formula::lolli(Formula::atom("A"), Formula::atom("B")));
+    }
+
+    #[test]
+    fn test_block_comments_between_every_token() {
+        let f = parse_formula("(* lhs *) A (* the resource *) -o (* rhs *) B (* end *)").unwrap();
+        assert_eq!(f, Formula::lolli(Formula::atom("A"), Formula::atom("B")));
+    }
+
+    #[test]
+    fn test_nested_block_comments() {
+        let f = parse_formula("A (* outer (* inner *) still outer *) -o B").unwrap();
+        assert_eq!(f, Formula::lolli(Formula::atom("A"), Formula::atom("B")));
+    }
+
+    #[test]
+    fn test_unterminated_block_comment_is_an_error() {
+        let err = parse_formula("A (* never closed -o B").unwrap_err();
+        // Should be reported with a span, not silently swallowed.
+        assert!(err.span().start > 0);
+    }
+
+    #[test]
+    fn test_iff_desugars_to_with_of_two_lollis() {
+        let f = parse_formula("A o-o B").unwrap();
+        assert_eq!(
+            f,
+            Formula::with(
+                Formula::lolli(Formula::atom("A"), Formula::atom("B")),
+                Formula::lolli(Formula::atom("B"), Formula::atom("A"))
+            )
+        );
+        assert_eq!(f.pretty(), "(A ⊸ B) & (B ⊸ A)");
+
+        let f = parse_formula("A ⊸⊸ B").unwrap();
+        assert_eq!(f.pretty(), "(A ⊸ B) & (B ⊸ A)");
+
+        let f = parse_formula("A ⧟ B").unwrap();
+        assert_eq!(f.pretty(), "(A ⊸ B) & (B ⊸ A)");
+    }
+
+    #[test]
+    fn test_iff_is_non_associative() {
+        let err = parse_formula("A o-o B o-o C").unwrap_err();
+        assert!(matches!(err, ParseError::UnexpectedToken { .. }));
+        assert!(err.span().start > 0);
+    }
+
     #[test]
     fn test_roundtrip() {
         let formulas = vec![
@@ -468,6 +976,10 @@ mod tests {
             "0",
             "A * B -o C",
             "!A * ?B -o C + D",
+            "P(x)",
+            "P(x, f(y))",
+            "forall x. P(x)",
+            "exists x. P(x) -o Q(x)",
         ];
 
         for input in formulas {
@@ -476,4 +988,38 @@ mod tests {
             let _ = f.pretty();
         }
     }
+
+    #[test]
+    fn test_parse_ascii_round_trips_through_pretty_ascii() {
+        let formulas = vec![
+            Formula::atom("A"),
+            Formula::lolli(Formula::atom("A"), Formula::atom("B")),
+            Formula::tensor(Formula::atom("A"), Formula::atom("B")),
+            Formula::par(Formula::atom("A"), Formula::atom("B")),
+            Formula::with(Formula::atom("A"), Formula::atom("B")),
+            Formula::plus(Formula::atom("A"), Formula::atom("B")),
+            Formula::of_course(Formula::atom("A")),
+            Formula::why_not(Formula::atom("A")),
+            Formula::neg_atom("A"),
+            Formula::One,
+            Formula::Bottom,
+            Formula::Top,
+            Formula::Zero,
+            Formula::predicate("P", vec![FolTerm::var("x")]),
+            Formula::predicate("P", vec![FolTerm::app("f", vec![FolTerm::var("x"), FolTerm::var("y")])]),
+            Formula::forall("x", Formula::predicate("P", vec![FolTerm::var("x")])),
+            Formula::exists(
+                "x",
+                Formula::lolli(
+                    Formula::predicate("P", vec![FolTerm::var("x")]),
+                    Formula::predicate("Q", vec![FolTerm::var("x")]),
+                ),
+            ),
+        ];
+
+        for f in formulas {
+            let ascii = f.pretty_ascii();
+            assert_eq!(parse_ascii(&ascii).unwrap(), f, "round-trip failed for {ascii}");
+        }
+    }
 }
@@ -0,0 +1,257 @@
+//! Parsing of batch "problem" files: named axioms, lemmas, assertions, and
+//! definitions, modeled on anthem-rs's statement-oriented problem format.
+//!
+//! A problem file is a sequence of period-terminated statements:
+//!
+//! ```text
+//! axiom double_neg: A |- (A^)^.
+//! lemma dup "duplicate a replicable resource": !A |- A * A.
+//! assertion: !A, !A -o B |- B.
+//! definition comm: A * B -o B * A.
+//! ```
+//!
+//! Each statement opens with its kind (`axiom`, `lemma`, `assertion`, or
+//! `definition`), followed by an optional name, an optional quoted
+//! description, a `:`, and a body — a sequent for the first three kinds, or
+//! a bare formula for a `definition`.
+
+use crate::{parse_formula, parse_sequent, Formula, ParseError, Span, TwoSidedSequent};
+
+/// What role a [`Statement`] plays in a [`Problem`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatementKind {
+    /// A sequent assumed to hold, taken on faith without proof.
+    Axiom,
+    /// A sequent whose proof other statements in the problem may build on.
+    Lemma,
+    /// A sequent whose proof is an end goal of the problem.
+    Assertion,
+    /// A named formula, available for later statements to refer to.
+    Definition,
+}
+
+/// The body of a [`Statement`]: a goal sequent for `axiom`/`lemma`/
+/// `assertion`, or a bare formula for a `definition`.
+#[derive(Debug, Clone)]
+pub enum StatementBody {
+    /// The sequent to prove (or, for an axiom, to assume).
+    Sequent(TwoSidedSequent),
+    /// The formula a `definition` statement names.
+    Formula(Formula),
+}
+
+/// A single named statement parsed from a problem file.
+#[derive(Debug, Clone)]
+pub struct Statement {
+    /// Whether this is an axiom, lemma, assertion, or definition.
+    pub kind: StatementKind,
+    /// The statement's name, if it was given one.
+    pub name: Option<String>,
+    /// A human-readable description, if it was given one.
+    pub description: Option<String>,
+    /// The statement's sequent or formula.
+    pub body: StatementBody,
+}
+
+/// A whole parsed problem file: an ordered sequence of [`Statement`]s.
+#[derive(Debug, Clone, Default)]
+pub struct Problem {
+    /// The statements making up this problem, in declaration order.
+    pub statements: Vec<Statement>,
+}
+
+/// Parse a whole problem file of axioms, lemmas, assertions, and
+/// definitions.
+///
+/// # Errors
+///
+/// Returns a `ParseError` if a statement has an unrecognized kind, is
+/// missing its `:` separator, or has a malformed sequent or formula body.
+///
+/// # Examples
+///
+/// ```
+/// use lolli_parse::{parse_problem, StatementKind};
+///
+/// let problem = parse_problem("lemma dup: !A |- A * A.").unwrap();
+/// assert_eq!(problem.statements.len(), 1);
+/// assert_eq!(problem.statements[0].kind, StatementKind::Lemma);
+/// assert_eq!(problem.statements[0].name.as_deref(), Some("dup"));
+/// ```
+pub fn parse_problem(input: &str) -> Result<Problem, ParseError> {
+    let mut problem = Problem::default();
+
+    for (offset, item) in split_statements(input) {
+        let trimmed = item.trim_start();
+        let leading_ws = item.len() - trimmed.len();
+        let item_start = offset + leading_ws;
+        let trimmed = trimmed.trim_end();
+
+        problem.statements.push(parse_statement(trimmed, item_start)?);
+    }
+
+    Ok(problem)
+}
+
+/// Split a document into (byte_offset, text) statements on `.`, ignoring a
+/// `.` inside a quoted description so a sentence-ending period in a
+/// statement's description text doesn't split it early.
+fn split_statements(input: &str) -> Vec<(usize, &str)> {
+    let mut items = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+
+    for (i, c) in input.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '.' if !in_quotes => {
+                let piece = &input[start..i];
+                if !piece.trim().is_empty() {
+                    items.push((start, piece));
+                }
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    let piece = &input[start..];
+    if !piece.trim().is_empty() {
+        items.push((start, piece));
+    }
+
+    items
+}
+
+fn parse_statement(text: &str, item_start: usize) -> Result<Statement, ParseError> {
+    let (keyword, rest) = split_head_word(text);
+    let kind = match keyword {
+        "axiom" => StatementKind::Axiom,
+        "lemma" => StatementKind::Lemma,
+        "assertion" => StatementKind::Assertion,
+        "definition" => StatementKind::Definition,
+        other => {
+            return Err(ParseError::UnexpectedToken {
+                token: other.to_string(),
+                span: Span::point(item_start),
+            })
+        }
+    };
+
+    let rest = rest.trim_start();
+    let (name, rest) = if rest.starts_with('"') || rest.starts_with(':') {
+        (None, rest)
+    } else {
+        let (word, after) = split_head_word(rest);
+        (Some(word.to_string()), after.trim_start())
+    };
+
+    if kind == StatementKind::Definition && name.is_none() {
+        return Err(ParseError::UnexpectedToken {
+            token: text.to_string(),
+            span: Span::point(item_start),
+        });
+    }
+
+    let (description, rest) = if let Some(quoted) = rest.strip_prefix('"') {
+        let end = quoted.find('"').ok_or_else(|| ParseError::UnexpectedToken {
+            token: rest.to_string(),
+            span: Span::point(item_start),
+        })?;
+        (Some(quoted[..end].to_string()), quoted[end + 1..].trim_start())
+    } else {
+        (None, rest)
+    };
+
+    let body_text = rest.strip_prefix(':').ok_or_else(|| ParseError::UnexpectedToken {
+        token: rest.to_string(),
+        span: Span::point(item_start),
+    })?;
+    let body_text = body_text.trim();
+
+    let body = if kind == StatementKind::Definition {
+        StatementBody::Formula(parse_formula(body_text)?)
+    } else {
+        StatementBody::Sequent(parse_sequent(body_text)?)
+    };
+
+    Ok(Statement {
+        kind,
+        name,
+        description,
+        body,
+    })
+}
+
+/// Split `s` into its leading word (up to the first whitespace, `:`, or
+/// `"`) and the remainder starting at that delimiter.
+fn split_head_word(s: &str) -> (&str, &str) {
+    let s = s.trim_start();
+    match s.find(|c: char| c.is_whitespace() || c == ':' || c == '"') {
+        Some(idx) => (&s[..idx], &s[idx..]),
+        None => (s, ""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_named_lemma_with_description() {
+        let problem = parse_problem(r#"lemma dup "duplicate a resource": !A |- A * A."#).unwrap();
+        assert_eq!(problem.statements.len(), 1);
+        let stmt = &problem.statements[0];
+        assert_eq!(stmt.kind, StatementKind::Lemma);
+        assert_eq!(stmt.name.as_deref(), Some("dup"));
+        assert_eq!(stmt.description.as_deref(), Some("duplicate a resource"));
+        assert!(matches!(stmt.body, StatementBody::Sequent(_)));
+    }
+
+    #[test]
+    fn test_parse_unnamed_assertion() {
+        let problem = parse_problem("assertion: A |- A.").unwrap();
+        assert_eq!(problem.statements[0].kind, StatementKind::Assertion);
+        assert_eq!(problem.statements[0].name, None);
+    }
+
+    #[test]
+    fn test_parse_definition_requires_a_name() {
+        let err = parse_problem("definition: A -o A.").unwrap_err();
+        assert!(matches!(err, ParseError::UnexpectedToken { .. }));
+    }
+
+    #[test]
+    fn test_parse_definition_body_is_a_bare_formula() {
+        let problem = parse_problem("definition comm: A * B -o B * A.").unwrap();
+        assert_eq!(problem.statements[0].kind, StatementKind::Definition);
+        assert!(matches!(problem.statements[0].body, StatementBody::Formula(_)));
+    }
+
+    #[test]
+    fn test_multiple_statements_split_on_periods() {
+        let problem = parse_problem("axiom a: A |- A.\nlemma b: B |- B.").unwrap();
+        assert_eq!(problem.statements.len(), 2);
+        assert_eq!(problem.statements[0].kind, StatementKind::Axiom);
+        assert_eq!(problem.statements[1].kind, StatementKind::Lemma);
+    }
+
+    #[test]
+    fn test_period_inside_description_does_not_split_the_statement() {
+        let problem = parse_problem(r#"lemma dup "a. b.": A |- A."#).unwrap();
+        assert_eq!(problem.statements.len(), 1);
+        assert_eq!(problem.statements[0].description.as_deref(), Some("a. b."));
+    }
+
+    #[test]
+    fn test_unknown_statement_kind_is_an_error() {
+        let err = parse_problem("theorem foo: A |- A.").unwrap_err();
+        assert!(matches!(err, ParseError::UnexpectedToken { .. }));
+    }
+
+    #[test]
+    fn test_missing_colon_is_an_error() {
+        let err = parse_problem("axiom foo A |- A.").unwrap_err();
+        assert!(matches!(err, ParseError::UnexpectedToken { .. }));
+    }
+}
@@ -0,0 +1,272 @@
+//! Parsing of whole "theory" documents: declarations, named definitions, and goal sequents.
+//!
+//! A program is a sequence of semicolon- or newline-separated items:
+//!
+//! ```text
+//! decl P/2;
+//! def Comm := A * B -o B * A;
+//! goal: A, B |- Comm;
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::{parse_formula, parse_sequent, Formula, ParseError, Span, TwoSidedSequent};
+
+/// A parsed theory document.
+#[derive(Debug, Clone, Default)]
+pub struct Program {
+    /// Predicate/atom arity declarations, keyed by name.
+    pub declarations: BTreeMap<String, usize>,
+    /// Named formula definitions, keyed by name, with references already resolved.
+    pub definitions: BTreeMap<String, Formula>,
+    /// The ordered list of goal sequents.
+    pub goals: Vec<TwoSidedSequent>,
+}
+
+/// Parse a whole theory document of declarations, definitions, and goals.
+///
+/// # Errors
+///
+/// Returns a `ParseError` on a malformed item, a duplicate declaration or
+/// definition name, a predicate arity mismatch, or a reference to an
+/// undefined name.
+pub fn parse_program(input: &str) -> Result<Program, ParseError> {
+    let mut program = Program::default();
+
+    for (offset, item) in split_items(input) {
+        let trimmed = item.trim_start();
+        let leading_ws = item.len() - trimmed.len();
+        let item_start = offset + leading_ws;
+        let trimmed = trimmed.trim_end();
+
+        if let Some(rest) = trimmed.strip_prefix("decl") {
+            parse_decl(&mut program, rest, item_start)?;
+        } else if let Some(rest) = trimmed.strip_prefix("def") {
+            parse_def(&mut program, rest, item_start)?;
+        } else if let Some(rest) = trimmed.strip_prefix("goal:") {
+            parse_goal(&mut program, rest, item_start)?;
+        } else {
+            return Err(ParseError::UnexpectedToken {
+                token: trimmed.to_string(),
+                span: Span::point(item_start),
+            });
+        }
+    }
+
+    Ok(program)
+}
+
+/// Split a document into (byte_offset, text) items on `;` and newlines,
+/// dropping items that are blank once trimmed.
+fn split_items(input: &str) -> Vec<(usize, &str)> {
+    let mut items = Vec::new();
+    let mut start = 0;
+
+    for (i, c) in input.char_indices() {
+        if c == ';' || c == '\n' {
+            let piece = &input[start..i];
+            if !piece.trim().is_empty() {
+                items.push((start, piece));
+            }
+            start = i + c.len_utf8();
+        }
+    }
+
+    let piece = &input[start..];
+    if !piece.trim().is_empty() {
+        items.push((start, piece));
+    }
+
+    items
+}
+
+fn parse_decl(program: &mut Program, rest: &str, item_start: usize) -> Result<(), ParseError> {
+    let rest = rest.trim();
+    let (name, arity_str) = rest.split_once('/').ok_or(ParseError::UnexpectedToken {
+        token: rest.to_string(),
+        span: Span::point(item_start),
+    })?;
+    let name = name.trim().to_string();
+    let arity: usize = arity_str.trim().parse().map_err(|_| ParseError::UnexpectedToken {
+        token: arity_str.trim().to_string(),
+        span: Span::point(item_start),
+    })?;
+
+    if program.declarations.contains_key(&name) {
+        return Err(ParseError::DuplicateDeclaration {
+            name,
+            span: Span::point(item_start),
+        });
+    }
+
+    program.declarations.insert(name, arity);
+    Ok(())
+}
+
+fn parse_def(program: &mut Program, rest: &str, item_start: usize) -> Result<(), ParseError> {
+    let (name, formula_text) = rest.split_once(":=").ok_or(ParseError::UnexpectedToken {
+        token: rest.trim().to_string(),
+        span: Span::point(item_start),
+    })?;
+    let name = name.trim().to_string();
+
+    if program.definitions.contains_key(&name) {
+        return Err(ParseError::DuplicateDeclaration {
+            name,
+            span: Span::point(item_start),
+        });
+    }
+
+    let formula = parse_formula(formula_text.trim())?;
+    let resolved = resolve_refs(&formula, program, item_start)?;
+    check_arities(&resolved, program, item_start)?;
+
+    program.definitions.insert(name, resolved);
+    Ok(())
+}
+
+fn parse_goal(program: &mut Program, rest: &str, item_start: usize) -> Result<(), ParseError> {
+    let sequent = parse_sequent(rest.trim())?;
+    let antecedent = sequent
+        .antecedent
+        .iter()
+        .map(|f| resolve_refs(f, program, item_start))
+        .collect::<Result<Vec<_>, _>>()?;
+    let succedent = sequent
+        .succedent
+        .iter()
+        .map(|f| resolve_refs(f, program, item_start))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for f in antecedent.iter().chain(succedent.iter()) {
+        check_arities(f, program, item_start)?;
+    }
+
+    program.goals.push(TwoSidedSequent::new(antecedent, succedent));
+    Ok(())
+}
+
+/// Replace any atom whose name matches a known `def` with its definition
+/// (a negated occurrence with the definition's negation).
+fn resolve_refs(formula: &Formula, program: &Program, span_hint: usize) -> Result<Formula, ParseError> {
+    Ok(match formula {
+        Formula::Atom(name) => match program.definitions.get(name) {
+            Some(def) => def.clone(),
+            None => formula.clone(),
+        },
+        Formula::NegAtom(name) => match program.definitions.get(name) {
+            Some(def) => def.negate(),
+            None => formula.clone(),
+        },
+        // `Formula::substitute` renames a bound variable that a definition's
+        // free variables would otherwise capture.
+        Formula::Forall(..) | Formula::Exists(..) => {
+            let map: std::collections::HashMap<String, Formula> =
+                program.definitions.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+            formula.substitute(&map)
+        }
+        Formula::Tensor(a, b) => Formula::tensor(
+            resolve_refs(a, program, span_hint)?,
+            resolve_refs(b, program, span_hint)?,
+        ),
+        Formula::Par(a, b) => Formula::par(
+            resolve_refs(a, program, span_hint)?,
+            resolve_refs(b, program, span_hint)?,
+        ),
+        Formula::With(a, b) => Formula::with(
+            resolve_refs(a, program, span_hint)?,
+            resolve_refs(b, program, span_hint)?,
+        ),
+        Formula::Plus(a, b) => Formula::plus(
+            resolve_refs(a, program, span_hint)?,
+            resolve_refs(b, program, span_hint)?,
+        ),
+        Formula::Lolli(a, b) => Formula::lolli(
+            resolve_refs(a, program, span_hint)?,
+            resolve_refs(b, program, span_hint)?,
+        ),
+        Formula::OfCourse(a) => Formula::of_course(resolve_refs(a, program, span_hint)?),
+        Formula::WhyNot(a) => Formula::why_not(resolve_refs(a, program, span_hint)?),
+        _ => formula.clone(),
+    })
+}
+
+fn check_arities(formula: &Formula, program: &Program, span_hint: usize) -> Result<(), ParseError> {
+    match formula {
+        Formula::Predicate(name, args) | Formula::NegPredicate(name, args) => {
+            match program.declarations.get(name) {
+                Some(&arity) if arity == args.len() => Ok(()),
+                Some(&arity) => Err(ParseError::ArityMismatch {
+                    name: name.clone(),
+                    expected: arity,
+                    got: args.len(),
+                    span: Span::point(span_hint),
+                }),
+                None => Err(ParseError::UndefinedName {
+                    name: name.clone(),
+                    span: Span::point(span_hint),
+                }),
+            }
+        }
+        Formula::Tensor(a, b)
+        | Formula::Par(a, b)
+        | Formula::With(a, b)
+        | Formula::Plus(a, b)
+        | Formula::Lolli(a, b) => {
+            check_arities(a, program, span_hint)?;
+            check_arities(b, program, span_hint)
+        }
+        Formula::OfCourse(a) | Formula::WhyNot(a) | Formula::Forall(_, a) | Formula::Exists(_, a) => {
+            check_arities(a, program, span_hint)
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_decl_and_goal() {
+        let program = parse_program("decl P/1;\ngoal: |- A").unwrap();
+        assert_eq!(program.declarations.get("P"), Some(&1));
+        assert_eq!(program.goals.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_def_and_reference() {
+        let program = parse_program(
+            "def Comm := A * B -o B * A;\ngoal: |- Comm",
+        )
+        .unwrap();
+
+        assert_eq!(program.definitions.len(), 1);
+        assert_eq!(program.goals[0].succedent[0], program.definitions["Comm"]);
+    }
+
+    #[test]
+    fn test_duplicate_declaration_is_an_error() {
+        let err = parse_program("decl P/1;\ndecl P/2;").unwrap_err();
+        assert!(matches!(err, ParseError::DuplicateDeclaration { .. }));
+    }
+
+    #[test]
+    fn test_undefined_name_is_an_error() {
+        let err = parse_program("goal: |- Q(A)").unwrap_err();
+        assert!(matches!(err, ParseError::UndefinedName { .. }));
+    }
+
+    #[test]
+    fn test_arity_mismatch_is_an_error() {
+        let err = parse_program("decl P/1;\ngoal: |- P(A, B)").unwrap_err();
+        assert!(matches!(err, ParseError::ArityMismatch { .. }));
+    }
+
+    #[test]
+    fn test_items_separated_by_semicolons_on_one_line() {
+        let program = parse_program("decl P/0; goal: |- A").unwrap();
+        assert_eq!(program.declarations.get("P"), Some(&0));
+        assert_eq!(program.goals.len(), 1);
+    }
+}
@@ -0,0 +1,166 @@
+//! A flat, positioned token stream, independent of the tree-shaped formula/sequent grammar.
+//!
+//! This gives external tools (syntax highlighting, bracket matching, completion) a stable
+//! surface for "what operators/atoms appear here" without re-deriving the pest grammar.
+
+use pest::Parser;
+
+use crate::{LolliParser, ParseError, Position, Rule, Span};
+
+/// The lexical category of a [`Token`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// A connective: `⊗`, `*`, `⅋`, `|`, `-o`, `⊸`, `o-o`, `⊸⊸`, `⧟`, `&`, `+`, `⊕`, `!`, `?`,
+    /// or a postfix negation suffix (`^`, `⊥`).
+    Connective,
+    /// A multiplicative or additive unit: `1`, `one`, `bot`, `bottom`, `top`, `0`, `zero`.
+    Unit,
+    /// An identifier (atom or predicate name).
+    Identifier,
+    /// A parenthesis, `(` or `)`.
+    Paren,
+    /// The turnstile, `|-` or `⊢`.
+    Turnstile,
+    /// A comma separating formulas in a sequent.
+    Comma,
+}
+
+/// A single lexical token: its kind, the exact text it covers, and its position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// The token's lexical category.
+    pub kind: TokenKind,
+    /// The exact source text of the token.
+    pub text: String,
+    /// The byte span of the token in the original input.
+    pub span: Span,
+    /// The line/column of the start of the token.
+    pub position: Position,
+}
+
+/// Tokenize a formula or sequent into a flat, positioned stream of tokens.
+///
+/// # Errors
+///
+/// Returns a `ParseError` if the input contains text that isn't any known token
+/// (e.g. an unsupported symbol).
+pub fn tokenize(input: &str) -> Result<Vec<Token>, ParseError> {
+    let pairs = LolliParser::parse(Rule::tokens, input)?;
+    let tokens_pair = pairs
+        .into_iter()
+        .next()
+        .ok_or(ParseError::EmptyInput { span: Span::point(0) })?;
+
+    let mut tokens = Vec::new();
+    for token_pair in tokens_pair.into_inner() {
+        if token_pair.as_rule() != Rule::token {
+            continue;
+        }
+        let inner = token_pair
+            .into_inner()
+            .next()
+            .expect("token rule always wraps exactly one concrete terminal");
+        let kind = match inner.as_rule() {
+            Rule::iff_op
+            | Rule::lolli_op
+            | Rule::par_op
+            | Rule::tensor_op
+            | Rule::plus_op
+            | Rule::with_op
+            | Rule::bang_op
+            | Rule::whynot_op
+            | Rule::negation_suffix => TokenKind::Connective,
+            Rule::one | Rule::bottom | Rule::top | Rule::zero => TokenKind::Unit,
+            Rule::ident => TokenKind::Identifier,
+            Rule::lparen | Rule::rparen => TokenKind::Paren,
+            Rule::turnstile => TokenKind::Turnstile,
+            Rule::comma => TokenKind::Comma,
+            other => {
+                return Err(ParseError::UnexpectedRule {
+                    rule: format!("{:?}", other),
+                    span: Span::from_pest(inner.as_span()),
+                })
+            }
+        };
+        let span = Span::from_pest(inner.as_span());
+        tokens.push(Token {
+            kind,
+            text: inner.as_str().to_string(),
+            position: Position::from_offset(input, span.start),
+            span,
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Tokenize a formula or sequent, returning an iterator over the tokens.
+///
+/// # Errors
+///
+/// Returns a `ParseError` under the same conditions as [`tokenize`].
+pub fn tokenize_iter(input: &str) -> Result<std::vec::IntoIter<Token>, ParseError> {
+    Ok(tokenize(input)?.into_iter())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_kinds() {
+        let tokens = tokenize("P(x) * !A -o B").unwrap();
+        let kinds: Vec<TokenKind> = tokens.iter().map(|t| t.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::Identifier,
+                TokenKind::Paren,
+                TokenKind::Identifier,
+                TokenKind::Paren,
+                TokenKind::Connective,
+                TokenKind::Connective,
+                TokenKind::Identifier,
+                TokenKind::Connective,
+                TokenKind::Identifier,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_sequent_has_comma_and_turnstile() {
+        let tokens = tokenize("A, B |- C").unwrap();
+        assert_eq!(tokens[1].kind, TokenKind::Comma);
+        assert_eq!(tokens[3].kind, TokenKind::Turnstile);
+    }
+
+    #[test]
+    fn test_tokenize_iter_matches_tokenize() {
+        let expected = tokenize("A -o B").unwrap();
+        let actual: Vec<Token> = tokenize_iter("A -o B").unwrap().collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_token_spans_reconstruct_input_with_whitespace_gaps() {
+        let input = "  A  *  B -o C  ";
+        let tokens = tokenize(input).unwrap();
+
+        let mut rebuilt = String::new();
+        let mut cursor = 0;
+        for token in &tokens {
+            rebuilt.push_str(&input[cursor..token.span.start]);
+            rebuilt.push_str(&input[token.span.start..token.span.end.unwrap()]);
+            cursor = token.span.end.unwrap();
+        }
+        rebuilt.push_str(&input[cursor..]);
+
+        assert_eq!(rebuilt, input);
+    }
+
+    #[test]
+    fn test_tokenize_rejects_unknown_symbol() {
+        let err = tokenize("A $ B").unwrap_err();
+        assert!(err.span().start > 0);
+    }
+}
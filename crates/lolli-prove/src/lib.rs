@@ -16,7 +16,7 @@
 //! ## Example
 //!
 //! ```
-//! use lolli_prove::Prover;
+//! use lolli_prove::{Prover, ProofResult};
 //! use lolli_core::{Formula, TwoSidedSequent};
 //!
 //! let mut prover = Prover::new(100);
@@ -27,15 +27,59 @@
 //!     vec![Formula::atom("A")],
 //! );
 //! let result = prover.prove_two_sided(&seq);
-//! assert!(result.is_some());
+//! assert!(matches!(result, ProofResult::Proven(_)));
 //! ```
+//!
+//! ## Provable, refuted, or unknown
+//!
+//! [`Prover::prove`] and [`Prover::prove_two_sided`] return a [`ProofResult`]
+//! rather than a plain `Option<Proof>`, so a negative answer can say *why*:
+//! a sequent that genuinely has no proof (`Disproven`) is reported
+//! differently from one where the search simply ran out of depth budget
+//! before it could rule out every branch (`Unknown`).
 
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 
+mod problem;
 mod search;
 mod verify;
 
 pub use lolli_core::{Formula, Proof, Rule, Sequent, TwoSidedSequent};
+pub use problem::{Problem, ProofStatus, Statement, StatementBody, StatementKind, StatementReport};
 pub use search::Prover;
 pub use verify::{verify_proof, ProofError};
+
+/// The outcome of a bounded proof search.
+///
+/// Unlike a plain `Option<Proof>`, this distinguishes a sequent that is
+/// genuinely underivable — every applicable rule was tried on every branch
+/// and none led to a proof — from one where the search simply exhausted its
+/// depth budget before it could explore every branch.
+#[derive(Debug, Clone)]
+pub enum ProofResult {
+    /// A proof was found.
+    Proven(Proof),
+    /// Every applicable rule was tried on every branch without ever
+    /// hitting the depth limit, and none led to a proof: the sequent is
+    /// genuinely underivable.
+    Disproven,
+    /// Search hit the depth limit on at least one branch before it could
+    /// be ruled out; raise `depth` for a definitive answer.
+    Unknown,
+}
+
+impl ProofResult {
+    /// The proof, if the search found one.
+    pub fn proof(self) -> Option<Proof> {
+        match self {
+            ProofResult::Proven(proof) => Some(proof),
+            ProofResult::Disproven | ProofResult::Unknown => None,
+        }
+    }
+
+    /// True if a proof was found.
+    pub fn is_proven(&self) -> bool {
+        matches!(self, ProofResult::Proven(_))
+    }
+}
@@ -0,0 +1,241 @@
+//! Batch problem files.
+//!
+//! This module adds a driver layer over [`verify_proof`](crate::verify_proof)
+//! for checking whole collections of proofs at once, modeled on anthem-rs's
+//! statement/problem structure. A [`Problem`] holds a list of named
+//! [`Statement`]s — axioms, lemmas, and assertions — and [`Problem::check`]
+//! verifies each one and reports a [`ProofStatus`] per statement. This gives
+//! users a way to maintain a library of named linear-logic proofs and
+//! re-verify all of them in one pass.
+
+use lolli_core::{Proof, Sequent};
+
+use crate::verify::{verify_proof, ProofError};
+
+/// What role a named [`Statement`] plays in a [`Problem`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatementKind {
+    /// An assumed sequent, accepted without being verified, even if it
+    /// carries a [`Proof`].
+    Axiom,
+    /// A proof, verified on its own like an assertion; other statements'
+    /// proofs cannot cite it.
+    Lemma,
+    /// A proof whose conclusion is an end goal of the problem.
+    Assertion,
+}
+
+/// The content of a [`Statement`]: either a full proof to verify, or a bare
+/// sequent asserted without proof (an assumption).
+#[derive(Debug, Clone)]
+pub enum StatementBody {
+    /// A sequent assumed to hold, with no proof to check.
+    Assumed(Sequent),
+    /// A proof to verify.
+    Proof(Proof),
+}
+
+/// A single named statement in a [`Problem`].
+#[derive(Debug, Clone)]
+pub struct Statement {
+    /// The statement's name, used to refer to it in reports.
+    pub name: String,
+    /// Whether this is an axiom, lemma, or assertion.
+    pub kind: StatementKind,
+    /// The statement's sequent or proof.
+    pub body: StatementBody,
+}
+
+impl Statement {
+    /// The sequent this statement concludes, regardless of whether it
+    /// carries a full proof or is merely assumed.
+    pub fn conclusion(&self) -> &Sequent {
+        match &self.body {
+            StatementBody::Assumed(sequent) => sequent,
+            StatementBody::Proof(proof) => &proof.conclusion,
+        }
+    }
+}
+
+/// The outcome of checking a single [`Statement`].
+#[derive(Debug, Clone)]
+pub enum ProofStatus {
+    /// The statement's proof was checked and found valid, or it is an
+    /// axiom and so was accepted without checking.
+    Proven,
+    /// No proof was supplied to check this statement against.
+    NotProven,
+    /// The statement's proof was checked and found invalid.
+    Disproven(ProofError),
+}
+
+/// The result of checking one [`Statement`] within a [`Problem`].
+#[derive(Debug, Clone)]
+pub struct StatementReport {
+    /// The statement's name.
+    pub name: String,
+    /// The statement's kind.
+    pub kind: StatementKind,
+    /// Whether the statement's proof held up.
+    pub status: ProofStatus,
+}
+
+/// A named collection of axioms, lemmas, and assertions.
+///
+/// # Example
+///
+/// ```
+/// use lolli_prove::{Problem, ProofStatus, Statement, StatementBody, StatementKind};
+/// use lolli_core::{Formula, Proof, Rule, Sequent};
+///
+/// let mut problem = Problem::new();
+/// problem.add(Statement {
+///     name: "identity".to_string(),
+///     kind: StatementKind::Lemma,
+///     body: StatementBody::Proof(Proof {
+///         conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+///         rule: Rule::Axiom,
+///         premises: vec![],
+///     }),
+/// });
+///
+/// let reports = problem.check();
+/// assert!(matches!(reports[0].status, ProofStatus::Proven));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Problem {
+    /// The statements making up this problem, in declaration order.
+    pub statements: Vec<Statement>,
+}
+
+impl Problem {
+    /// Create an empty problem.
+    pub fn new() -> Self {
+        Problem {
+            statements: Vec::new(),
+        }
+    }
+
+    /// Append a statement to this problem.
+    pub fn add(&mut self, statement: Statement) {
+        self.statements.push(statement);
+    }
+
+    /// Verify every lemma and assertion with `verify_proof`, report every
+    /// axiom as proven without verifying it, and return a report for each
+    /// statement in declaration order. Each proof is verified on its own: it
+    /// cannot use an axiom or lemma of the problem as a leaf.
+    pub fn check(&self) -> Vec<StatementReport> {
+        self.statements
+            .iter()
+            .map(|statement| {
+                let status = match (&statement.kind, &statement.body) {
+                    (StatementKind::Axiom, _) => ProofStatus::Proven,
+                    (_, StatementBody::Proof(proof)) => match verify_proof(proof) {
+                        Ok(()) => ProofStatus::Proven,
+                        Err(e) => ProofStatus::Disproven(e),
+                    },
+                    (_, StatementBody::Assumed(_)) => ProofStatus::NotProven,
+                };
+                StatementReport {
+                    name: statement.name.clone(),
+                    kind: statement.kind,
+                    status,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lolli_core::{Formula, Rule};
+
+    fn identity_proof() -> Proof {
+        Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        }
+    }
+
+    #[test]
+    fn test_axiom_is_consumed_without_reverification() {
+        let mut problem = Problem::new();
+        problem.add(Statement {
+            name: "bad-axiom".to_string(),
+            kind: StatementKind::Axiom,
+            body: StatementBody::Proof(Proof {
+                conclusion: Sequent::new(vec![Formula::atom("A"), Formula::neg_atom("B")]),
+                rule: Rule::Axiom,
+                premises: vec![],
+            }),
+        });
+
+        let reports = problem.check();
+        assert!(matches!(reports[0].status, ProofStatus::Proven));
+    }
+
+    #[test]
+    fn test_lemma_with_valid_proof_is_proven() {
+        let mut problem = Problem::new();
+        problem.add(Statement {
+            name: "identity".to_string(),
+            kind: StatementKind::Lemma,
+            body: StatementBody::Proof(identity_proof()),
+        });
+
+        let reports = problem.check();
+        assert!(matches!(reports[0].status, ProofStatus::Proven));
+    }
+
+    #[test]
+    fn test_assertion_with_invalid_proof_is_disproven() {
+        let mut problem = Problem::new();
+        problem.add(Statement {
+            name: "nonsense".to_string(),
+            kind: StatementKind::Assertion,
+            body: StatementBody::Proof(Proof {
+                conclusion: Sequent::new(vec![Formula::atom("A"), Formula::neg_atom("B")]),
+                rule: Rule::Axiom,
+                premises: vec![],
+            }),
+        });
+
+        let reports = problem.check();
+        assert!(matches!(reports[0].status, ProofStatus::Disproven(_)));
+    }
+
+    #[test]
+    fn test_assumed_lemma_is_not_proven() {
+        let mut problem = Problem::new();
+        problem.add(Statement {
+            name: "assumed".to_string(),
+            kind: StatementKind::Lemma,
+            body: StatementBody::Assumed(Sequent::new(vec![Formula::atom("A")])),
+        });
+
+        let reports = problem.check();
+        assert!(matches!(reports[0].status, ProofStatus::NotProven));
+    }
+
+    #[test]
+    fn test_check_reports_in_declaration_order() {
+        let mut problem = Problem::new();
+        problem.add(Statement {
+            name: "first".to_string(),
+            kind: StatementKind::Lemma,
+            body: StatementBody::Proof(identity_proof()),
+        });
+        problem.add(Statement {
+            name: "second".to_string(),
+            kind: StatementKind::Assertion,
+            body: StatementBody::Proof(identity_proof()),
+        });
+
+        let reports = problem.check();
+        assert_eq!(reports[0].name, "first");
+        assert_eq!(reports[1].name, "second");
+    }
+}
@@ -0,0 +1,460 @@
+//! Depth-bounded backward proof search for one-sided linear logic sequents.
+//!
+//! [`Prover`] decides `⊢ Γ` by recursively picking a formula in `Γ` to
+//! decompose and applying the matching introduction rule, following the
+//! positive/negative classification from [`Formula::is_positive`]:
+//!
+//! - **Negative** connectives (⅋, ⊥, &, ⊤, ?) have exactly one applicable
+//!   rule, so they're decomposed eagerly with no backtracking.
+//! - **Positive** connectives (⊗, 1, ⊕, 0, !, atoms) require *choosing* a
+//!   split or a branch, so the search tries every choice and keeps
+//!   whichever succeeds.
+//!
+//! This decides the decidable MALL fragment (⊗, ⅋, 1, ⊥, &, ⊕, ⊤, 0,
+//! axiom) completely: within the depth bound, a `Disproven` answer means
+//! every applicable rule was tried on every branch and none led to a
+//! proof. `!`/`?` are handled by their single canonical rule (promotion
+//! and dereliction respectively) without attempting weakening or
+//! contraction, and first-order predicates/quantifiers aren't attempted at
+//! all — so a failure to close a branch carrying a `?` or a first-order
+//! formula is reported as `Unknown` rather than `Disproven`, the same as
+//! running out of depth. A `!` whose promotion side condition fails, with
+//! no `?` around, is `Disproven`.
+
+use lolli_core::{Formula, Proof, Rule, Sequent, TwoSidedSequent};
+
+use crate::ProofResult;
+
+/// A depth-bounded prover for one-sided linear logic sequents.
+pub struct Prover {
+    depth: usize,
+}
+
+impl Prover {
+    /// Create a prover that gives up and reports [`ProofResult::Unknown`]
+    /// after `depth` nested rule applications on some branch.
+    pub fn new(depth: usize) -> Self {
+        Prover { depth }
+    }
+
+    /// Attempt to prove a one-sided sequent `⊢ Γ`.
+    pub fn prove(&mut self, sequent: &Sequent) -> ProofResult {
+        let ctx: Vec<Formula> = sequent.linear.iter().map(Formula::desugar).collect();
+        match search(ctx, self.depth) {
+            Step::Proven(proof) => ProofResult::Proven(proof),
+            Step::Disproven => ProofResult::Disproven,
+            Step::Unknown => ProofResult::Unknown,
+        }
+    }
+
+    /// Attempt to prove a two-sided sequent by converting it to its
+    /// one-sided form (negating the antecedent into the succedent) first.
+    pub fn prove_two_sided(&mut self, sequent: &TwoSidedSequent) -> ProofResult {
+        self.prove(&sequent.to_one_sided())
+    }
+}
+
+/// The outcome of searching a single sequent, before it's folded into the
+/// crate-level [`ProofResult`] (which doesn't carry a sequent of its own).
+enum Step {
+    Proven(Proof),
+    Disproven,
+    Unknown,
+}
+
+fn remove_at(ctx: &[Formula], pos: usize) -> Vec<Formula> {
+    let mut rest = ctx.to_vec();
+    rest.remove(pos);
+    rest
+}
+
+/// `Proven` if either side is; `Unknown` if neither is but one might still
+/// be reachable with more depth or a different (unattempted) exponential
+/// strategy; `Disproven` only when both sides are definitively hopeless.
+/// Used when a positive connective offers a *choice* (⊕'s two disjuncts,
+/// ⊗'s many context splits).
+fn either(a: Step, b: Step) -> Step {
+    match (a, b) {
+        (Step::Proven(p), _) | (_, Step::Proven(p)) => Step::Proven(p),
+        (Step::Disproven, Step::Disproven) => Step::Disproven,
+        _ => Step::Unknown,
+    }
+}
+
+fn search(ctx: Vec<Formula>, depth: usize) -> Step {
+    // ⊤ discharges the whole sequent unconditionally, regardless of
+    // anything else present (even an unresolvable `0`).
+    if ctx.iter().any(|f| matches!(f, Formula::Top)) {
+        return Step::Proven(Proof {
+            conclusion: Sequent::new(ctx),
+            rule: Rule::TopIntro,
+            premises: Vec::new(),
+        });
+    }
+
+    if ctx.len() == 1 && ctx[0] == Formula::One {
+        return Step::Proven(Proof {
+            conclusion: Sequent::new(ctx),
+            rule: Rule::OneIntro,
+            premises: Vec::new(),
+        });
+    }
+
+    if ctx.len() == 2 {
+        let dual = matches!(
+            (&ctx[0], &ctx[1]),
+            (Formula::Atom(a), Formula::NegAtom(b)) | (Formula::NegAtom(b), Formula::Atom(a))
+                if a == b
+        );
+        if dual {
+            return Step::Proven(Proof {
+                conclusion: Sequent::new(ctx),
+                rule: Rule::Axiom,
+                premises: Vec::new(),
+            });
+        }
+    }
+
+    if depth == 0 {
+        return Step::Unknown;
+    }
+
+    if let Some(pos) = ctx.iter().position(|f| matches!(f, Formula::Bottom)) {
+        let conclusion = Sequent::new(ctx.clone());
+        let rest = remove_at(&ctx, pos);
+        return match search(rest, depth - 1) {
+            Step::Proven(premise) => Step::Proven(Proof {
+                conclusion,
+                rule: Rule::BottomIntro,
+                premises: vec![premise],
+            }),
+            other => other,
+        };
+    }
+
+    if let Some(pos) = ctx.iter().position(|f| matches!(f, Formula::Par(_, _))) {
+        let conclusion = Sequent::new(ctx.clone());
+        let (a, b) = match &ctx[pos] {
+            Formula::Par(a, b) => ((**a).clone(), (**b).clone()),
+            _ => unreachable!(),
+        };
+        let mut rest = remove_at(&ctx, pos);
+        rest.push(a);
+        rest.push(b);
+        return match search(rest, depth - 1) {
+            Step::Proven(premise) => Step::Proven(Proof {
+                conclusion,
+                rule: Rule::ParIntro,
+                premises: vec![premise],
+            }),
+            other => other,
+        };
+    }
+
+    if let Some(pos) = ctx.iter().position(|f| matches!(f, Formula::With(_, _))) {
+        return decompose_with(&ctx, pos, depth);
+    }
+
+    // Promotion needs the `?`-formulas of its context intact, so it is tried
+    // before dereliction takes them apart.
+    if let Some(pos) = ctx.iter().position(|f| matches!(f, Formula::OfCourse(_))) {
+        if let Step::Proven(proof) = decompose_ofcourse(&ctx, pos, depth) {
+            return Step::Proven(proof);
+        }
+    }
+
+    // `?`'s only rule (dereliction) is deterministic, so it's decomposed
+    // eagerly alongside the other negatives above.
+    if let Some(pos) = ctx.iter().position(|f| matches!(f, Formula::WhyNot(_))) {
+        let conclusion = Sequent::new(ctx.clone());
+        let a = match &ctx[pos] {
+            Formula::WhyNot(a) => (**a).clone(),
+            _ => unreachable!(),
+        };
+        let mut rest = remove_at(&ctx, pos);
+        rest.push(a);
+        return match search(rest, depth - 1) {
+            Step::Proven(premise) => Step::Proven(Proof {
+                conclusion,
+                rule: Rule::WhyNotIntro,
+                premises: vec![premise],
+            }),
+            // Weakening and contraction of the `?` are never tried, so a
+            // failure here does not rule the sequent out.
+            _ => Step::Unknown,
+        };
+    }
+
+    // No negative connective is left to decompose for free: focus on a
+    // positive one instead, trying every choice present and keeping
+    // whichever succeeds.
+    let mut best = Step::Disproven;
+    for pos in 0..ctx.len() {
+        let outcome = match &ctx[pos] {
+            Formula::Tensor(_, _) => Some(decompose_tensor(&ctx, pos, depth)),
+            Formula::Plus(_, _) => Some(decompose_plus(&ctx, pos, depth)),
+            Formula::OfCourse(_) => Some(decompose_ofcourse(&ctx, pos, depth)),
+            _ => None,
+        };
+        if let Some(outcome) = outcome {
+            best = either(best, outcome);
+            if matches!(best, Step::Proven(_)) {
+                return best;
+            }
+        }
+    }
+
+    // Nothing left to try. Bare, unpaired atoms are a genuine dead end;
+    // first-order predicates/quantifiers are out of scope for this search
+    // and left as `Unknown` rather than a false `Disproven`.
+    let out_of_scope = ctx.iter().any(|f| {
+        matches!(
+            f,
+            Formula::Predicate(_, _) | Formula::NegPredicate(_, _) | Formula::Forall(_, _) | Formula::Exists(_, _)
+        )
+    });
+    if out_of_scope {
+        return Step::Unknown;
+    }
+    best
+}
+
+fn decompose_with(ctx: &[Formula], pos: usize, depth: usize) -> Step {
+    let conclusion = Sequent::new(ctx.to_vec());
+    let (a, b) = match &ctx[pos] {
+        Formula::With(a, b) => ((**a).clone(), (**b).clone()),
+        _ => unreachable!(),
+    };
+    let rest = remove_at(ctx, pos);
+
+    let mut left_ctx = rest.clone();
+    left_ctx.push(a);
+    let mut right_ctx = rest;
+    right_ctx.push(b);
+
+    match (search(left_ctx, depth - 1), search(right_ctx, depth - 1)) {
+        (Step::Proven(l), Step::Proven(r)) => Step::Proven(Proof {
+            conclusion,
+            rule: Rule::WithIntro,
+            premises: vec![l, r],
+        }),
+        (Step::Disproven, _) | (_, Step::Disproven) => Step::Disproven,
+        _ => Step::Unknown,
+    }
+}
+
+/// Try every way of splitting the rest of the context between ⊗'s two
+/// premises. `2^n` splits for `n` remaining formulas — the same
+/// exponential enumeration the sibling crate `lolli-viz`
+/// accepts for switching-based net correctness, and for the same reason:
+/// it's the direct reading of the rule, not an optimization target here.
+fn decompose_tensor(ctx: &[Formula], pos: usize, depth: usize) -> Step {
+    let conclusion = Sequent::new(ctx.to_vec());
+    let (a, b) = match &ctx[pos] {
+        Formula::Tensor(a, b) => ((**a).clone(), (**b).clone()),
+        _ => unreachable!(),
+    };
+    let rest = remove_at(ctx, pos);
+
+    let mut any_unknown = false;
+    for mask in 0..(1usize << rest.len()) {
+        let mut left_ctx = Vec::new();
+        let mut right_ctx = Vec::new();
+        for (i, f) in rest.iter().enumerate() {
+            if mask & (1 << i) == 0 {
+                left_ctx.push(f.clone());
+            } else {
+                right_ctx.push(f.clone());
+            }
+        }
+        left_ctx.push(a.clone());
+        right_ctx.push(b.clone());
+
+        match (search(left_ctx, depth - 1), search(right_ctx, depth - 1)) {
+            (Step::Proven(l), Step::Proven(r)) => {
+                return Step::Proven(Proof {
+                    conclusion,
+                    rule: Rule::TensorIntro,
+                    premises: vec![l, r],
+                });
+            }
+            (Step::Disproven, Step::Disproven) => {}
+            _ => any_unknown = true,
+        }
+    }
+
+    if any_unknown {
+        Step::Unknown
+    } else {
+        Step::Disproven
+    }
+}
+
+fn decompose_plus(ctx: &[Formula], pos: usize, depth: usize) -> Step {
+    let conclusion = Sequent::new(ctx.to_vec());
+    let (a, b) = match &ctx[pos] {
+        Formula::Plus(a, b) => ((**a).clone(), (**b).clone()),
+        _ => unreachable!(),
+    };
+    let rest = remove_at(ctx, pos);
+
+    let mut left_ctx = rest.clone();
+    left_ctx.push(a);
+    let left = match search(left_ctx, depth - 1) {
+        Step::Proven(p) => {
+            return Step::Proven(Proof {
+                conclusion,
+                rule: Rule::PlusIntroLeft,
+                premises: vec![p],
+            });
+        }
+        other => other,
+    };
+
+    let mut right_ctx = rest;
+    right_ctx.push(b);
+    match search(right_ctx, depth - 1) {
+        Step::Proven(p) => Step::Proven(Proof {
+            conclusion,
+            rule: Rule::PlusIntroRight,
+            premises: vec![p],
+        }),
+        right => either(left, right),
+    }
+}
+
+/// `!A`'s only rule is promotion, and its side condition (every other
+/// context formula is `?`-prefixed) is a deterministic structural check —
+/// no backtracking needed, just whether the one available rule applies.
+fn decompose_ofcourse(ctx: &[Formula], pos: usize, depth: usize) -> Step {
+    let conclusion = Sequent::new(ctx.to_vec());
+    let a = match &ctx[pos] {
+        Formula::OfCourse(a) => (**a).clone(),
+        _ => unreachable!(),
+    };
+    let rest = remove_at(ctx, pos);
+    if !rest.iter().all(|f| matches!(f, Formula::WhyNot(_))) {
+        return Step::Disproven;
+    }
+
+    let mut premise_ctx = rest;
+    premise_ctx.push(a);
+    match search(premise_ctx, depth - 1) {
+        Step::Proven(premise) => Step::Proven(Proof {
+            conclusion,
+            rule: Rule::OfCourseIntro,
+            premises: vec![premise],
+        }),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity_is_proven() {
+        let mut prover = Prover::new(10);
+        let seq = Sequent::new(vec![Formula::atom("A"), Formula::neg_atom("A")]);
+        assert!(prover.prove(&seq).is_proven());
+    }
+
+    #[test]
+    fn test_mismatched_atoms_are_disproven() {
+        let mut prover = Prover::new(10);
+        let seq = Sequent::new(vec![Formula::atom("A"), Formula::neg_atom("B")]);
+        assert!(matches!(prover.prove(&seq), ProofResult::Disproven));
+    }
+
+    #[test]
+    fn test_zero_depth_is_unknown_rather_than_disproven() {
+        let mut prover = Prover::new(0);
+        let seq = Sequent::new(vec![
+            Formula::neg_atom("A"),
+            Formula::neg_atom("B"),
+            Formula::tensor(Formula::atom("A"), Formula::atom("B")),
+        ]);
+        assert!(matches!(prover.prove(&seq), ProofResult::Unknown));
+    }
+
+    #[test]
+    fn test_tensor_of_two_axioms_is_proven() {
+        let mut prover = Prover::new(10);
+        let seq = Sequent::new(vec![
+            Formula::neg_atom("A"),
+            Formula::neg_atom("B"),
+            Formula::tensor(Formula::atom("A"), Formula::atom("B")),
+        ]);
+        let result = prover.prove(&seq);
+        assert!(result.is_proven());
+        assert!(matches!(result.proof().unwrap().rule, Rule::TensorIntro));
+    }
+
+    #[test]
+    fn test_with_requires_both_branches() {
+        // ⊢ A⊥,B⊥,(A&C) needs both A⊥,B⊥,A (fine) and A⊥,B⊥,C (no dual
+        // for C) — the second branch fails, so the whole `&` must fail.
+        let mut prover = Prover::new(10);
+        let seq = Sequent::new(vec![
+            Formula::neg_atom("A"),
+            Formula::neg_atom("B"),
+            Formula::with(Formula::atom("A"), Formula::atom("C")),
+        ]);
+        assert!(matches!(prover.prove(&seq), ProofResult::Disproven));
+    }
+
+    #[test]
+    fn test_plus_succeeds_via_either_disjunct() {
+        let mut prover = Prover::new(10);
+        let seq = Sequent::new(vec![
+            Formula::neg_atom("A"),
+            Formula::plus(Formula::atom("B"), Formula::atom("A")),
+        ]);
+        let result = prover.prove(&seq);
+        assert!(result.is_proven());
+        assert!(matches!(result.proof().unwrap().rule, Rule::PlusIntroRight));
+    }
+
+    #[test]
+    fn test_one_and_top_and_bottom() {
+        let mut prover = Prover::new(10);
+        assert!(prover.prove(&Sequent::new(vec![Formula::One])).is_proven());
+        assert!(prover
+            .prove(&Sequent::new(vec![Formula::atom("A"), Formula::Top]))
+            .is_proven());
+        assert!(prover
+            .prove(&Sequent::new(vec![Formula::atom("A"), Formula::neg_atom("A"), Formula::Bottom]))
+            .is_proven());
+    }
+
+    #[test]
+    fn test_zero_in_context_is_disproven() {
+        let mut prover = Prover::new(10);
+        let seq = Sequent::new(vec![Formula::atom("A"), Formula::Zero]);
+        assert!(matches!(prover.prove(&seq), ProofResult::Disproven));
+    }
+
+    #[test]
+    fn test_lolli_identity_via_desugaring() {
+        // A ⊸ A desugars to A⊥ ⅋ A, which is an axiom once unfolded.
+        let mut prover = Prover::new(10);
+        let seq = Sequent::new(vec![Formula::lolli(Formula::atom("A"), Formula::atom("A"))]);
+        assert!(prover.prove(&seq).is_proven());
+    }
+
+    #[test]
+    fn test_promotion_requires_whynot_context() {
+        let mut prover = Prover::new(10);
+        // Side condition fails: "B" isn't `?`-prefixed.
+        let seq = Sequent::new(vec![Formula::atom("B"), Formula::of_course(Formula::atom("A"))]);
+        assert!(matches!(prover.prove(&seq), ProofResult::Disproven));
+
+        // Side condition holds: the only other formula is `?`-prefixed.
+        let seq = Sequent::new(vec![
+            Formula::why_not(Formula::neg_atom("A")),
+            Formula::of_course(Formula::atom("A")),
+        ]);
+        assert!(prover.prove(&seq).is_proven());
+    }
+}
@@ -35,6 +35,44 @@ pub enum ProofError {
     /// Premise verification failed
     #[error("Premise verification failed: {0}")]
     PremiseFailed(Box<ProofError>),
+
+    /// No split of the premises' contexts justifies the principal formula
+    /// this rule claims to introduce.
+    #[error("No premise split justifies rule {rule:?} for conclusion {conclusion}")]
+    NoValidSplit {
+        /// The rule that was applied
+        rule: Rule,
+        /// The conclusion of the proof step
+        conclusion: String,
+    },
+
+    /// The promotion side condition failed: every non-principal formula in
+    /// the conclusion must be `?`-prefixed.
+    #[error("Promotion side condition failed for {conclusion}: every other formula must be `?`-prefixed")]
+    InvalidPromotion {
+        /// The conclusion of the proof step
+        conclusion: String,
+    },
+}
+
+/// Remove the first occurrence of `target` from `items`, returning whether it was found.
+fn remove_one(items: &mut Vec<Formula>, target: &Formula) -> bool {
+    match items.iter().position(|f| f == target) {
+        Some(pos) => {
+            items.remove(pos);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Compare two formula lists as multisets (order-insensitive, duplicates matter).
+fn multiset_eq(a: &[Formula], b: &[Formula]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut remaining = b.to_vec();
+    a.iter().all(|f| remove_one(&mut remaining, f))
 }
 
 /// Verify that a proof is valid.
@@ -130,13 +168,6 @@ fn verify_rule_application(proof: &Proof) -> Result<(), ProofError> {
 
         Rule::BottomIntro => {
             // Bottom: ⊢ Γ, ⊥ from ⊢ Γ
-            if !seq.linear.contains(&Formula::Bottom) {
-                return Err(ProofError::InvalidRule {
-                    rule: proof.rule.clone(),
-                    conclusion: seq.pretty(),
-                });
-            }
-
             if proof.premises.len() != 1 {
                 return Err(ProofError::WrongPremiseCount {
                     expected: 1,
@@ -144,11 +175,29 @@ fn verify_rule_application(proof: &Proof) -> Result<(), ProofError> {
                 });
             }
 
+            let premise = &proof.premises[0].conclusion.linear;
+            let valid = seq.linear.iter().enumerate().any(|(i, f)| {
+                if !matches!(f, Formula::Bottom) {
+                    return false;
+                }
+                let mut rest = seq.linear.clone();
+                rest.remove(i);
+                multiset_eq(premise, &rest)
+            });
+
+            if !valid {
+                return Err(ProofError::NoValidSplit {
+                    rule: proof.rule.clone(),
+                    conclusion: seq.pretty(),
+                });
+            }
+
             Ok(())
         }
 
         Rule::TensorIntro => {
-            // Tensor: needs exactly 2 premises
+            // Tensor: ⊢ Γ,A and ⊢ Δ,B concludes ⊢ Γ,Δ,A⊗B for some split of
+            // the conclusion's context into Γ and Δ.
             if proof.premises.len() != 2 {
                 return Err(ProofError::WrongPremiseCount {
                     expected: 2,
@@ -156,13 +205,24 @@ fn verify_rule_application(proof: &Proof) -> Result<(), ProofError> {
                 });
             }
 
-            // Check there's a tensor in the conclusion
-            let has_tensor = seq
-                .linear
-                .iter()
-                .any(|f| matches!(f, Formula::Tensor(_, _)));
-            if !has_tensor {
-                return Err(ProofError::InvalidRule {
+            let premise_left = &proof.premises[0].conclusion.linear;
+            let premise_right = &proof.premises[1].conclusion.linear;
+            let valid = seq.linear.iter().enumerate().any(|(i, f)| {
+                let Formula::Tensor(a, b) = f else {
+                    return false;
+                };
+                let mut rest = seq.linear.clone();
+                rest.remove(i);
+                let mut left = premise_left.clone();
+                let mut right = premise_right.clone();
+                remove_one(&mut left, a) && remove_one(&mut right, b) && {
+                    left.extend(right);
+                    multiset_eq(&left, &rest)
+                }
+            });
+
+            if !valid {
+                return Err(ProofError::NoValidSplit {
                     rule: proof.rule.clone(),
                     conclusion: seq.pretty(),
                 });
@@ -172,7 +232,7 @@ fn verify_rule_application(proof: &Proof) -> Result<(), ProofError> {
         }
 
         Rule::ParIntro => {
-            // Par: needs exactly 1 premise
+            // Par: ⊢ Γ,A,B concludes ⊢ Γ,A⅋B.
             if proof.premises.len() != 1 {
                 return Err(ProofError::WrongPremiseCount {
                     expected: 1,
@@ -180,10 +240,20 @@ fn verify_rule_application(proof: &Proof) -> Result<(), ProofError> {
                 });
             }
 
-            // Check there's a par in the conclusion
-            let has_par = seq.linear.iter().any(|f| matches!(f, Formula::Par(_, _)));
-            if !has_par {
-                return Err(ProofError::InvalidRule {
+            let premise = &proof.premises[0].conclusion.linear;
+            let valid = seq.linear.iter().enumerate().any(|(i, f)| {
+                let Formula::Par(a, b) = f else {
+                    return false;
+                };
+                let mut expected = seq.linear.clone();
+                expected.remove(i);
+                expected.push((**a).clone());
+                expected.push((**b).clone());
+                multiset_eq(premise, &expected)
+            });
+
+            if !valid {
+                return Err(ProofError::NoValidSplit {
                     rule: proof.rule.clone(),
                     conclusion: seq.pretty(),
                 });
@@ -193,7 +263,8 @@ fn verify_rule_application(proof: &Proof) -> Result<(), ProofError> {
         }
 
         Rule::WithIntro => {
-            // With: needs exactly 2 premises
+            // With: both premises must share the same surrounding context Γ,
+            // one offering A and the other B (additive sharing).
             if proof.premises.len() != 2 {
                 return Err(ProofError::WrongPremiseCount {
                     expected: 2,
@@ -201,13 +272,24 @@ fn verify_rule_application(proof: &Proof) -> Result<(), ProofError> {
                 });
             }
 
-            // Check there's a with in the conclusion
-            let has_with = seq
-                .linear
-                .iter()
-                .any(|f| matches!(f, Formula::With(_, _)));
-            if !has_with {
-                return Err(ProofError::InvalidRule {
+            let premise_left = &proof.premises[0].conclusion.linear;
+            let premise_right = &proof.premises[1].conclusion.linear;
+            let valid = seq.linear.iter().enumerate().any(|(i, f)| {
+                let Formula::With(a, b) = f else {
+                    return false;
+                };
+                let mut rest = seq.linear.clone();
+                rest.remove(i);
+                let mut expected_left = rest.clone();
+                expected_left.push((**a).clone());
+                let mut expected_right = rest;
+                expected_right.push((**b).clone());
+                multiset_eq(&expected_left, premise_left)
+                    && multiset_eq(&expected_right, premise_right)
+            });
+
+            if !valid {
+                return Err(ProofError::NoValidSplit {
                     rule: proof.rule.clone(),
                     conclusion: seq.pretty(),
                 });
@@ -217,7 +299,7 @@ fn verify_rule_application(proof: &Proof) -> Result<(), ProofError> {
         }
 
         Rule::PlusIntroLeft | Rule::PlusIntroRight => {
-            // Plus: needs exactly 1 premise
+            // Plus: the premise must carry the chosen disjunct.
             if proof.premises.len() != 1 {
                 return Err(ProofError::WrongPremiseCount {
                     expected: 1,
@@ -225,13 +307,24 @@ fn verify_rule_application(proof: &Proof) -> Result<(), ProofError> {
                 });
             }
 
-            // Check there's a plus in the conclusion
-            let has_plus = seq
-                .linear
-                .iter()
-                .any(|f| matches!(f, Formula::Plus(_, _)));
-            if !has_plus {
-                return Err(ProofError::InvalidRule {
+            let premise = &proof.premises[0].conclusion.linear;
+            let valid = seq.linear.iter().enumerate().any(|(i, f)| {
+                let Formula::Plus(a, b) = f else {
+                    return false;
+                };
+                let chosen = if matches!(proof.rule, Rule::PlusIntroLeft) {
+                    a
+                } else {
+                    b
+                };
+                let mut expected = seq.linear.clone();
+                expected.remove(i);
+                expected.push((**chosen).clone());
+                multiset_eq(premise, &expected)
+            });
+
+            if !valid {
+                return Err(ProofError::NoValidSplit {
                     rule: proof.rule.clone(),
                     conclusion: seq.pretty(),
                 });
@@ -253,7 +346,8 @@ fn verify_rule_application(proof: &Proof) -> Result<(), ProofError> {
         }
 
         Rule::OfCourseIntro => {
-            // OfCourse: needs exactly 1 premise
+            // OfCourse: promotion. ⊢ ?Γ,A concludes ⊢ ?Γ,!A, provided every
+            // other formula in the conclusion is itself `?`-prefixed.
             if proof.premises.len() != 1 {
                 return Err(ProofError::WrongPremiseCount {
                     expected: 1,
@@ -261,13 +355,30 @@ fn verify_rule_application(proof: &Proof) -> Result<(), ProofError> {
                 });
             }
 
-            // Check there's an ofcourse in the conclusion
-            let has_ofcourse = seq
-                .linear
-                .iter()
-                .any(|f| matches!(f, Formula::OfCourse(_)));
-            if !has_ofcourse {
-                return Err(ProofError::InvalidRule {
+            let premise = &proof.premises[0].conclusion.linear;
+            let mut side_condition_checked = false;
+            let valid = seq.linear.iter().enumerate().any(|(i, f)| {
+                let Formula::OfCourse(a) = f else {
+                    return false;
+                };
+                let mut rest = seq.linear.clone();
+                rest.remove(i);
+                if !rest.iter().all(|g| matches!(g, Formula::WhyNot(_))) {
+                    side_condition_checked = true;
+                    return false;
+                }
+                let mut expected = rest;
+                expected.push((**a).clone());
+                multiset_eq(&expected, premise)
+            });
+
+            if !valid {
+                if side_condition_checked {
+                    return Err(ProofError::InvalidPromotion {
+                        conclusion: seq.pretty(),
+                    });
+                }
+                return Err(ProofError::NoValidSplit {
                     rule: proof.rule.clone(),
                     conclusion: seq.pretty(),
                 });
@@ -276,24 +387,126 @@ fn verify_rule_application(proof: &Proof) -> Result<(), ProofError> {
             Ok(())
         }
 
-        // For other rules, just check premise count for now
-        Rule::Cut(_) => {
+        Rule::Cut(formula) => {
+            // Cut: ⊢ Γ,A and ⊢ Δ,A⊥ concludes ⊢ Γ,Δ, for the cut formula
+            // carried by the rule itself (it doesn't appear in the
+            // conclusion). Either premise may hold the positive side.
             if proof.premises.len() != 2 {
                 return Err(ProofError::WrongPremiseCount {
                     expected: 2,
                     got: proof.premises.len(),
                 });
             }
+
+            let dual = formula.negate();
+            let premise_left = &proof.premises[0].conclusion.linear;
+            let premise_right = &proof.premises[1].conclusion.linear;
+            let try_split = |a: &Formula, b: &Formula| {
+                let mut left = premise_left.clone();
+                let mut right = premise_right.clone();
+                remove_one(&mut left, a) && remove_one(&mut right, b) && {
+                    left.extend(right);
+                    multiset_eq(&left, &seq.linear)
+                }
+            };
+
+            if !try_split(formula, &dual) && !try_split(&dual, formula) {
+                return Err(ProofError::NoValidSplit {
+                    rule: proof.rule.clone(),
+                    conclusion: seq.pretty(),
+                });
+            }
+
+            Ok(())
+        }
+
+        Rule::Weakening => {
+            // Weakening: ⊢ Γ concludes ⊢ Γ,?A - the premise drops one
+            // `?`-prefixed formula from the conclusion.
+            if proof.premises.len() != 1 {
+                return Err(ProofError::WrongPremiseCount {
+                    expected: 1,
+                    got: proof.premises.len(),
+                });
+            }
+
+            let premise = &proof.premises[0].conclusion.linear;
+            let valid = seq.linear.iter().enumerate().any(|(i, f)| {
+                if !matches!(f, Formula::WhyNot(_)) {
+                    return false;
+                }
+                let mut rest = seq.linear.clone();
+                rest.remove(i);
+                multiset_eq(premise, &rest)
+            });
+
+            if !valid {
+                return Err(ProofError::NoValidSplit {
+                    rule: proof.rule.clone(),
+                    conclusion: seq.pretty(),
+                });
+            }
+
+            Ok(())
+        }
+
+        Rule::Contraction => {
+            // Contraction: ⊢ Γ,?A,?A concludes ⊢ Γ,?A - the premise carries
+            // one extra copy of some `?`-prefixed formula in the conclusion.
+            if proof.premises.len() != 1 {
+                return Err(ProofError::WrongPremiseCount {
+                    expected: 1,
+                    got: proof.premises.len(),
+                });
+            }
+
+            let premise = &proof.premises[0].conclusion.linear;
+            let valid = seq.linear.iter().any(|f| {
+                if !matches!(f, Formula::WhyNot(_)) {
+                    return false;
+                }
+                let mut expected = seq.linear.clone();
+                expected.push(f.clone());
+                multiset_eq(premise, &expected)
+            });
+
+            if !valid {
+                return Err(ProofError::NoValidSplit {
+                    rule: proof.rule.clone(),
+                    conclusion: seq.pretty(),
+                });
+            }
+
             Ok(())
         }
 
-        Rule::Weakening | Rule::Contraction | Rule::Dereliction => {
+        Rule::Dereliction => {
+            // Dereliction: ⊢ Γ,A concludes ⊢ Γ,?A.
             if proof.premises.len() != 1 {
                 return Err(ProofError::WrongPremiseCount {
                     expected: 1,
                     got: proof.premises.len(),
                 });
             }
+
+            let premise = &proof.premises[0].conclusion.linear;
+            let valid = seq.linear.iter().enumerate().any(|(i, f)| {
+                let Formula::WhyNot(a) = f else {
+                    return false;
+                };
+                let mut expected = seq.linear.clone();
+                expected.remove(i);
+                expected.push((**a).clone());
+                multiset_eq(premise, &expected)
+            });
+
+            if !valid {
+                return Err(ProofError::NoValidSplit {
+                    rule: proof.rule.clone(),
+                    conclusion: seq.pretty(),
+                });
+            }
+
             Ok(())
         }
 
@@ -358,4 +571,303 @@ mod tests {
 
         assert!(verify_proof(&proof).is_ok());
     }
+
+    #[test]
+    fn test_verify_tensor_with_matching_split() {
+        // ⊢ A,B and ⊢ C,D justify ⊢ B,D,A⊗C
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A"), Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("C"), Formula::atom("D")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::atom("B"),
+                Formula::atom("D"),
+                Formula::tensor(Formula::atom("A"), Formula::atom("C")),
+            ]),
+            rule: Rule::TensorIntro,
+            premises: vec![left, right],
+        };
+
+        assert!(verify_rule_application(&proof).is_ok());
+    }
+
+    #[test]
+    fn test_verify_tensor_rejects_unjustified_split() {
+        // Premises don't actually carry A and C, so no split can work.
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("X")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("Y")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::tensor(Formula::atom("A"), Formula::atom("C"))]),
+            rule: Rule::TensorIntro,
+            premises: vec![left, right],
+        };
+
+        assert!(matches!(
+            verify_rule_application(&proof),
+            Err(ProofError::NoValidSplit { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_with_requires_shared_context() {
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("G"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("G"), Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::atom("G"),
+                Formula::with(Formula::atom("A"), Formula::atom("B")),
+            ]),
+            rule: Rule::WithIntro,
+            premises: vec![left, right],
+        };
+
+        assert!(verify_rule_application(&proof).is_ok());
+    }
+
+    #[test]
+    fn test_verify_with_rejects_mismatched_contexts() {
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("G"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("H"), Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::atom("G"),
+                Formula::with(Formula::atom("A"), Formula::atom("B")),
+            ]),
+            rule: Rule::WithIntro,
+            premises: vec![left, right],
+        };
+
+        assert!(matches!(
+            verify_rule_application(&proof),
+            Err(ProofError::NoValidSplit { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_ofcourse_promotion_side_condition() {
+        // Promoting over a non-?-prefixed neighbor is invalid.
+        let premise = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("G"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::atom("G"),
+                Formula::of_course(Formula::atom("A")),
+            ]),
+            rule: Rule::OfCourseIntro,
+            premises: vec![premise],
+        };
+
+        assert!(matches!(
+            verify_rule_application(&proof),
+            Err(ProofError::InvalidPromotion { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_cut_with_matching_dual() {
+        // ⊢ G,A and ⊢ H,A⊥ justify ⊢ G,H via Cut(A).
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("G"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("H"), Formula::neg_atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("G"), Formula::atom("H")]),
+            rule: Rule::Cut(Formula::atom("A")),
+            premises: vec![left, right],
+        };
+
+        assert!(verify_rule_application(&proof).is_ok());
+    }
+
+    #[test]
+    fn test_verify_cut_rejects_wrong_cut_formula() {
+        // Premises don't actually carry A/A⊥, so no split justifies Cut(A).
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("G"), Formula::atom("X")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("H"), Formula::neg_atom("Y")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("G"), Formula::atom("H")]),
+            rule: Rule::Cut(Formula::atom("A")),
+            premises: vec![left, right],
+        };
+
+        assert!(matches!(
+            verify_rule_application(&proof),
+            Err(ProofError::NoValidSplit { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_weakening_discards_whynot_formula() {
+        let premise = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("G")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::atom("G"),
+                Formula::why_not(Formula::atom("A")),
+            ]),
+            rule: Rule::Weakening,
+            premises: vec![premise],
+        };
+
+        assert!(verify_rule_application(&proof).is_ok());
+    }
+
+    #[test]
+    fn test_verify_weakening_rejects_non_whynot_discard() {
+        let premise = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("G")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("G"), Formula::atom("A")]),
+            rule: Rule::Weakening,
+            premises: vec![premise],
+        };
+
+        assert!(matches!(
+            verify_rule_application(&proof),
+            Err(ProofError::NoValidSplit { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_contraction_merges_duplicate_whynot() {
+        let premise = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::atom("G"),
+                Formula::why_not(Formula::atom("A")),
+                Formula::why_not(Formula::atom("A")),
+            ]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::atom("G"),
+                Formula::why_not(Formula::atom("A")),
+            ]),
+            rule: Rule::Contraction,
+            premises: vec![premise],
+        };
+
+        assert!(verify_rule_application(&proof).is_ok());
+    }
+
+    #[test]
+    fn test_verify_contraction_rejects_single_copy_premise() {
+        let premise = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::atom("G"),
+                Formula::why_not(Formula::atom("A")),
+            ]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::atom("G"),
+                Formula::why_not(Formula::atom("A")),
+            ]),
+            rule: Rule::Contraction,
+            premises: vec![premise],
+        };
+
+        assert!(matches!(
+            verify_rule_application(&proof),
+            Err(ProofError::NoValidSplit { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_dereliction_replaces_whynot_with_bare_formula() {
+        let premise = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("G"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::atom("G"),
+                Formula::why_not(Formula::atom("A")),
+            ]),
+            rule: Rule::Dereliction,
+            premises: vec![premise],
+        };
+
+        assert!(verify_rule_application(&proof).is_ok());
+    }
+
+    #[test]
+    fn test_verify_dereliction_rejects_mismatched_premise() {
+        let premise = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("G"), Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::atom("G"),
+                Formula::why_not(Formula::atom("A")),
+            ]),
+            rule: Rule::Dereliction,
+            premises: vec![premise],
+        };
+
+        assert!(matches!(
+            verify_rule_application(&proof),
+            Err(ProofError::NoValidSplit { .. })
+        ));
+    }
 }
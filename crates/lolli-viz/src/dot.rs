@@ -2,7 +2,10 @@
 //!
 //! Generates DOT format for visualizing proofs as graphs.
 
-use lolli_core::Proof;
+use lolli_core::{Formula, Proof, Rule};
+
+use crate::highlight::{self, HighlightStyle};
+use crate::net::{NetNode, ProofNet};
 
 /// Graphviz DOT renderer for proofs.
 pub struct DotRenderer {
@@ -14,6 +17,9 @@ pub struct DotRenderer {
     pub font: String,
     /// Show rule names in nodes
     pub show_rules: bool,
+    /// Whether and how to highlight each node's principal formula, and the
+    /// cut formula in the premises of a `Cut`.
+    pub highlight: HighlightStyle,
 }
 
 /// Graph direction.
@@ -71,6 +77,7 @@ impl Default for DotRenderer {
             node_shape: NodeShape::Box,
             font: "Helvetica".to_string(),
             show_rules: true,
+            highlight: HighlightStyle::default(),
         }
     }
 }
@@ -96,54 +103,148 @@ impl DotRenderer {
         lines.push("  edge [arrowhead=none];".to_string());
         lines.push(String::new());
 
-        self.render_proof(proof, &mut lines, &mut counter);
+        self.render_proof(proof, &mut lines, &mut counter, None);
 
         lines.push("}".to_string());
         lines.join("\n")
     }
 
-    /// Render a proof recursively, returning the node ID.
-    fn render_proof(&self, proof: &Proof, lines: &mut Vec<String>, counter: &mut usize) -> usize {
+    /// Render a proof recursively, returning the node ID. `cut_highlight` is
+    /// the cut formula of an enclosing `Cut` node, if this node is one of its
+    /// direct premises, so it can be picked out in this node's own sequent.
+    fn render_proof(
+        &self,
+        proof: &Proof,
+        lines: &mut Vec<String>,
+        counter: &mut usize,
+        cut_highlight: Option<&Formula>,
+    ) -> usize {
         let my_id = *counter;
         *counter += 1;
 
         // Format the node label
-        let conclusion = self.format_sequent(proof);
+        let conclusion = self.format_sequent(proof, cut_highlight);
         let rule_name = format!("{:?}", proof.rule);
 
-        let label = if self.show_rules {
+        let body = if self.show_rules {
             format!("⊢ {}\\n({})", conclusion, rule_name)
         } else {
             format!("⊢ {}", conclusion)
         };
 
-        // Escape for DOT
-        let label = label.replace('"', "\\\"");
-
-        lines.push(format!("  n{} [label=\"{}\"];", my_id, label));
+        if self.highlight.enabled {
+            // Highlighted formulas carry `<FONT>` markup, so the label needs
+            // Graphviz's HTML-like label syntax (`<...>`) rather than a
+            // quoted string.
+            lines.push(format!("  n{} [label=<{}>];", my_id, body));
+        } else {
+            let label = body.replace('"', "\\\"");
+            lines.push(format!("  n{} [label=\"{}\"];", my_id, label));
+        }
 
-        // Render premises and add edges
+        // Render premises and add edges, passing down the cut formula if
+        // this node is a `Cut` so each premise can highlight it.
+        let premise_cut_highlight = match &proof.rule {
+            Rule::Cut(formula) => Some(formula),
+            _ => None,
+        };
         for premise in &proof.premises {
-            let child_id = self.render_proof(premise, lines, counter);
+            let child_id = self.render_proof(premise, lines, counter, premise_cut_highlight);
             lines.push(format!("  n{} -> n{};", child_id, my_id));
         }
 
         my_id
     }
 
-    /// Format a sequent for display.
-    fn format_sequent(&self, proof: &Proof) -> String {
-        proof
-            .conclusion
-            .linear
+    /// Format a sequent for display. When `self.highlight` is enabled, the
+    /// rule's principal formula (per [`highlight::principal_formula_index`])
+    /// is colored in `principal_color`, and `cut_highlight` — the cut
+    /// formula of an enclosing `Cut`, if any — is bolded in `cut_color`.
+    fn format_sequent(&self, proof: &Proof, cut_highlight: Option<&Formula>) -> String {
+        let linear = &proof.conclusion.linear;
+        let principal = highlight::principal_formula_index(linear, &proof.rule);
+        let cut_index = cut_highlight.and_then(|cf| highlight::cut_formula_index(linear, &Rule::Cut(cf.clone())));
+
+        linear
             .iter()
-            .map(|f| f.pretty())
+            .enumerate()
+            .map(|(i, f)| {
+                let text = f.pretty();
+                if !self.highlight.enabled {
+                    return text;
+                }
+                if Some(i) == principal {
+                    format!(r#"<FONT COLOR="{}">{}</FONT>"#, self.highlight.principal_color, text)
+                } else if Some(i) == cut_index {
+                    format!(
+                        r#"<FONT COLOR="{}"><B>{}</B></FONT>"#,
+                        self.highlight.cut_color, text
+                    )
+                } else {
+                    text
+                }
+            })
             .collect::<Vec<_>>()
             .join(", ")
     }
 
-    /// Render as a proof net (for multiplicative fragment).
+    /// Render a multiplicative proof net (⊗, ⅋, axiom, cut): axiom and cut
+    /// links are drawn as bidirectional edges, ⊗-links as plain edges, and
+    /// each ⅋-link's two premise edges are dashed to mark them as the
+    /// switchable pair under the Danos–Regnier criterion. Falls back to a
+    /// plain rule-tree sketch for proofs outside that fragment, since those
+    /// have no [`ProofNet`] representation.
     pub fn render_proof_net(&self, proof: &Proof) -> String {
+        match ProofNet::from_proof(proof) {
+            Ok(net) => self.render_net(&net),
+            Err(_) => self.render_net_fallback(proof),
+        }
+    }
+
+    fn render_net(&self, net: &ProofNet) -> String {
+        let mut lines = Vec::new();
+        lines.push("digraph proof_net {".to_string());
+        lines.push("  rankdir=TB;".to_string());
+        lines.push("  node [shape=circle, width=0.3, fontname=\"Helvetica\"];".to_string());
+        lines.push(String::new());
+
+        for (i, node) in net.nodes().iter().enumerate() {
+            let label = match node {
+                NetNode::Atom(f) => f.pretty(),
+                NetNode::Axiom => "ax".to_string(),
+                NetNode::Cut => "cut".to_string(),
+                NetNode::Tensor => "⊗".to_string(),
+                NetNode::Par => "⅋".to_string(),
+            };
+            lines.push(format!("  n{} [label=\"{}\"];", i, label.replace('"', "\\\"")));
+        }
+        lines.push(String::new());
+
+        for edge in net.fixed_edges() {
+            if matches!(net.nodes()[edge.a], NetNode::Tensor) {
+                lines.push(format!("  n{} -> n{};", edge.a, edge.b));
+            } else {
+                lines.push(format!("  n{} -> n{} [dir=both];", edge.a, edge.b));
+            }
+        }
+        for (left, right) in net.par_edges() {
+            lines.push(format!(
+                "  n{} -> n{} [dir=both, style=dashed];",
+                left.a, left.b
+            ));
+            lines.push(format!(
+                "  n{} -> n{} [dir=both, style=dashed];",
+                right.a, right.b
+            ));
+        }
+
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    /// A plain rule-tree sketch, used for proofs outside the multiplicative
+    /// fragment that [`ProofNet`] can't represent.
+    fn render_net_fallback(&self, proof: &Proof) -> String {
         let mut lines = Vec::new();
         let mut counter = 0;
 
@@ -184,7 +285,7 @@ impl DotRenderer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use lolli_core::{Formula, Rule, Sequent};
+    use lolli_core::Sequent;
 
     #[test]
     fn test_render_axiom() {
@@ -271,4 +372,114 @@ mod tests {
         assert!(output.contains("proof_net"));
         assert!(output.contains("circle"));
     }
+
+    #[test]
+    fn test_proof_net_draws_real_axiom_link() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+
+        let renderer = DotRenderer::new();
+        let output = renderer.render_proof_net(&proof);
+
+        assert!(output.contains("dir=both"));
+        assert!(output.contains("\"ax\""));
+    }
+
+    #[test]
+    fn test_proof_net_marks_par_links_as_dashed() {
+        let premise = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::neg_atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::par(Formula::neg_atom("A"), Formula::neg_atom("B"))]),
+            rule: Rule::ParIntro,
+            premises: vec![premise],
+        };
+
+        let renderer = DotRenderer::new();
+        let output = renderer.render_proof_net(&proof);
+
+        assert!(output.contains("style=dashed"));
+    }
+
+    #[test]
+    fn test_proof_net_draws_tensor_links_as_plain_edges() {
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("B"), Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::neg_atom("A"),
+                Formula::neg_atom("B"),
+                Formula::tensor(Formula::atom("A"), Formula::atom("B")),
+            ]),
+            rule: Rule::TensorIntro,
+            premises: vec![left, right],
+        };
+
+        let renderer = DotRenderer::new();
+        let output = renderer.render_proof_net(&proof);
+
+        let label_line = output.lines().find(|line| line.contains("⊗")).unwrap();
+        let id = label_line.trim_start().split_whitespace().next().unwrap();
+        let edge_line = output
+            .lines()
+            .find(|line| line.trim_start().starts_with(&format!("{} ->", id)))
+            .unwrap();
+        assert!(!edge_line.contains("dir=both"));
+    }
+
+    #[test]
+    fn test_highlight_principal_formula() {
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::tensor(Formula::atom("A"), Formula::atom("B"))]),
+            rule: Rule::TensorIntro,
+            premises: vec![left, right],
+        };
+
+        let mut renderer = DotRenderer::new();
+        renderer.highlight = HighlightStyle::on();
+        let output = renderer.render(&proof);
+
+        assert!(output.contains(r#"<FONT COLOR="blue">"#));
+        // Highlighted labels use the HTML-like `label=<...>` form.
+        assert!(output.contains("label=<"));
+    }
+
+    #[test]
+    fn test_no_highlight_by_default() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::tensor(Formula::atom("A"), Formula::atom("B"))]),
+            rule: Rule::TensorIntro,
+            premises: vec![],
+        };
+
+        let renderer = DotRenderer::new();
+        let output = renderer.render(&proof);
+
+        assert!(!output.contains("FONT"));
+        assert!(output.contains(r#"label=""#));
+    }
 }
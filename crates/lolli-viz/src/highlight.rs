@@ -0,0 +1,98 @@
+//! Shared logic for picking out the principal and cut formulas of a proof
+//! node, so the LaTeX and DOT renderers can highlight them consistently.
+
+use lolli_core::{Formula, Rule};
+
+/// Controls whether highlighting is rendered, and which colors to use.
+/// Shared by every renderer in this crate so a proof looks the same way
+/// highlighted regardless of output format.
+#[derive(Debug, Clone)]
+pub struct HighlightStyle {
+    /// Whether highlighting is on at all.
+    pub enabled: bool,
+    /// Color for the principal formula of the rule being applied.
+    pub principal_color: String,
+    /// Color for the cut formula, shown in a `Cut` node's premises.
+    pub cut_color: String,
+}
+
+impl Default for HighlightStyle {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            principal_color: "blue".to_string(),
+            cut_color: "red".to_string(),
+        }
+    }
+}
+
+impl HighlightStyle {
+    /// A style with highlighting turned on, using the default colors.
+    pub fn on() -> Self {
+        Self {
+            enabled: true,
+            ..Self::default()
+        }
+    }
+}
+
+/// The index into `formulas` of the principal formula for `rule`, if any —
+/// the formula whose top-level connective the rule introduces (e.g. the
+/// `⊗` formula for `TensorIntro`, the `!`-formula for `OfCourseIntro`).
+pub fn principal_formula_index(formulas: &[Formula], rule: &Rule) -> Option<usize> {
+    formulas.iter().position(|f| is_principal_for(f, rule))
+}
+
+fn is_principal_for(formula: &Formula, rule: &Rule) -> bool {
+    match rule {
+        Rule::OneIntro => matches!(formula, Formula::One),
+        Rule::BottomIntro => matches!(formula, Formula::Bottom),
+        Rule::TopIntro => matches!(formula, Formula::Top),
+        Rule::TensorIntro => matches!(formula, Formula::Tensor(_, _)),
+        Rule::ParIntro => matches!(formula, Formula::Par(_, _)),
+        Rule::WithIntro => matches!(formula, Formula::With(_, _)),
+        Rule::PlusIntroLeft | Rule::PlusIntroRight => matches!(formula, Formula::Plus(_, _)),
+        Rule::OfCourseIntro => matches!(formula, Formula::OfCourse(_)),
+        Rule::WhyNotIntro => matches!(formula, Formula::WhyNot(_)),
+        _ => false,
+    }
+}
+
+/// The index into `formulas` of the cut formula for a `Rule::Cut`, matched
+/// by looking for the cut formula or its dual among `formulas` — used to
+/// highlight the formula being eliminated in each premise of a cut.
+pub fn cut_formula_index(formulas: &[Formula], rule: &Rule) -> Option<usize> {
+    match rule {
+        Rule::Cut(cut_formula) => formulas
+            .iter()
+            .position(|f| f == cut_formula || *f == cut_formula.negate()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_principal_tensor() {
+        let formulas = vec![
+            Formula::atom("C"),
+            Formula::tensor(Formula::atom("A"), Formula::atom("B")),
+        ];
+        assert_eq!(principal_formula_index(&formulas, &Rule::TensorIntro), Some(1));
+    }
+
+    #[test]
+    fn test_principal_absent_for_axiom() {
+        let formulas = vec![Formula::atom("A"), Formula::neg_atom("A")];
+        assert_eq!(principal_formula_index(&formulas, &Rule::Axiom), None);
+    }
+
+    #[test]
+    fn test_cut_formula_matches_either_polarity() {
+        let formulas = vec![Formula::atom("C"), Formula::neg_atom("A")];
+        let rule = Rule::Cut(Formula::atom("A"));
+        assert_eq!(cut_formula_index(&formulas, &rule), Some(1));
+    }
+}
@@ -1,15 +1,47 @@
-//! LaTeX proof rendering using bussproofs package.
+//! LaTeX proof rendering, selectable between the bussproofs, ebproof, and prftree packages.
 //!
 //! Generates LaTeX code for typesetting proofs.
 
-use lolli_core::Proof;
+use lolli_core::{Formula, Proof, Rule};
 
-/// LaTeX proof renderer using bussproofs package.
+use crate::highlight::{self, HighlightStyle};
+
+/// Which LaTeX package's macros to emit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LatexBackend {
+    /// The `bussproofs` package: `\AxiomC`, `\UnaryInfC`, `\BinaryInfC`, `\TrinaryInfC`.
+    Bussproofs,
+    /// The `ebproof` package: `\infer0[label]{seq}`, `\infer{n}[label]{seq}`.
+    Ebproof,
+    /// The `prftree` package: a single nested `\prftree` macro call.
+    Prftree,
+}
+
+impl LatexBackend {
+    fn package_name(self) -> &'static str {
+        match self {
+            LatexBackend::Bussproofs => "bussproofs",
+            LatexBackend::Ebproof => "ebproof",
+            LatexBackend::Prftree => "prftree",
+        }
+    }
+}
+
+/// LaTeX proof renderer.
 pub struct LatexRenderer {
     /// Include package imports
     pub include_preamble: bool,
     /// Use shorthand rule labels
     pub short_labels: bool,
+    /// Which LaTeX package to target.
+    pub backend: LatexBackend,
+    /// When set, every inference node gets a `\label{<prefix>-<path>}`, where
+    /// `<path>` is its dotted position in the premise tree (`0`, `0.1`, ...),
+    /// so individual steps of a typeset proof can be `\ref`'d from prose.
+    pub label_prefix: Option<String>,
+    /// Whether and how to highlight each node's principal formula, and the
+    /// cut formula in the premises of a `Cut`.
+    pub highlight: HighlightStyle,
 }
 
 impl Default for LatexRenderer {
@@ -17,30 +49,78 @@ impl Default for LatexRenderer {
         Self {
             include_preamble: false,
             short_labels: false,
+            backend: LatexBackend::Bussproofs,
+            label_prefix: None,
+            highlight: HighlightStyle::default(),
         }
     }
 }
 
+/// A proof fragment already typeset elsewhere under `name`. Wherever
+/// [`LatexRenderer::render_with_lemmas`] encounters this exact fragment
+/// (by reference identity) it emits a `\ref{name}` instead of re-typesetting
+/// the subtree, so a large proof can be split into lemma + reuse.
+pub struct Lemma<'a> {
+    /// The label the lemma was (or will be) `\label`led with at its own
+    /// typeset site.
+    pub name: &'a str,
+    /// The proof fragment this lemma stands for.
+    pub proof: &'a Proof,
+}
+
 impl LatexRenderer {
     /// Create a new LaTeX renderer.
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Create a renderer targeting a specific backend.
+    pub fn with_backend(backend: LatexBackend) -> Self {
+        Self {
+            backend,
+            ..Self::default()
+        }
+    }
+
+    fn preamble_lines(&self, proof: &Proof) -> Vec<String> {
+        let mut lines = vec![format!(r"\usepackage{{{}}}", self.backend.package_name())];
+        if self.backend == LatexBackend::Bussproofs && proof_has_wide_node(proof) {
+            // bussproofs can't typeset a 5+ premise node on its own; such nodes
+            // fall back to an ebproof-style `\infer{n}`, so pull that package in too.
+            lines.push(r"\usepackage{ebproof}".to_string());
+        }
+        lines.push(r"\usepackage{amsmath}".to_string());
+        lines.push(r"\usepackage{amssymb}".to_string());
+        lines
+    }
+
     /// Render a proof as LaTeX.
     pub fn render(&self, proof: &Proof) -> String {
+        self.render_with_lemmas(proof, &[])
+    }
+
+    /// Render a proof as LaTeX, replacing any subtree that is reference-equal
+    /// to a [`Lemma`] with a `\ref` to it instead of re-typesetting it.
+    pub fn render_with_lemmas(&self, proof: &Proof, lemmas: &[Lemma]) -> String {
         let mut lines = Vec::new();
 
         if self.include_preamble {
-            lines.push(r"\usepackage{bussproofs}".to_string());
-            lines.push(r"\usepackage{amsmath}".to_string());
-            lines.push(r"\usepackage{amssymb}".to_string());
+            lines.extend(self.preamble_lines(proof));
             lines.push(String::new());
         }
 
-        lines.push(r"\begin{prooftree}".to_string());
-        self.render_proof(proof, &mut lines);
-        lines.push(r"\end{prooftree}".to_string());
+        match self.backend {
+            LatexBackend::Bussproofs | LatexBackend::Ebproof => {
+                lines.push(r"\begin{prooftree}".to_string());
+                self.render_proof(proof, "0", lemmas, None, &mut lines);
+                lines.push(r"\end{prooftree}".to_string());
+            }
+            LatexBackend::Prftree => {
+                lines.push(r"\[".to_string());
+                lines.push(format!("  {}", self.render_prftree(proof, "0", lemmas, None)));
+                lines.push(r"\]".to_string());
+            }
+        }
 
         lines.join("\n")
     }
@@ -50,16 +130,23 @@ impl LatexRenderer {
         let mut lines = Vec::new();
 
         lines.push(r"\documentclass{article}".to_string());
-        lines.push(r"\usepackage{bussproofs}".to_string());
-        lines.push(r"\usepackage{amsmath}".to_string());
-        lines.push(r"\usepackage{amssymb}".to_string());
+        lines.extend(self.preamble_lines(proof));
         lines.push(String::new());
         lines.push(r"\begin{document}".to_string());
         lines.push(String::new());
 
-        lines.push(r"\begin{prooftree}".to_string());
-        self.render_proof(proof, &mut lines);
-        lines.push(r"\end{prooftree}".to_string());
+        match self.backend {
+            LatexBackend::Bussproofs | LatexBackend::Ebproof => {
+                lines.push(r"\begin{prooftree}".to_string());
+                self.render_proof(proof, "0", &[], None, &mut lines);
+                lines.push(r"\end{prooftree}".to_string());
+            }
+            LatexBackend::Prftree => {
+                lines.push(r"\[".to_string());
+                lines.push(format!("  {}", self.render_prftree(proof, "0", &[], None)));
+                lines.push(r"\]".to_string());
+            }
+        }
 
         lines.push(String::new());
         lines.push(r"\end{document}".to_string());
@@ -67,69 +154,241 @@ impl LatexRenderer {
         lines.join("\n")
     }
 
-    /// Render a proof recursively.
-    fn render_proof(&self, proof: &Proof, lines: &mut Vec<String>) {
-        // Render premises first
-        for premise in &proof.premises {
-            self.render_proof(premise, lines);
+    /// Render a proof as a named, citeable block: the typeset tree is wrapped
+    /// in `\begin{env}...\end{env}` carrying `\label{name}`, and every
+    /// inference node additionally gets a `\label{name-<path>}` so individual
+    /// steps can be `\ref`'d, not just the proof as a whole.
+    pub fn render_named(&self, env: &str, name: &str, proof: &Proof) -> String {
+        let labeled = LatexRenderer {
+            label_prefix: Some(name.to_string()),
+            include_preamble: self.include_preamble,
+            short_labels: self.short_labels,
+            backend: self.backend,
+            highlight: self.highlight.clone(),
+        };
+
+        let mut lines = vec![format!(r"\begin{{{}}}", env), format!(r"\label{{{}}}", name)];
+        lines.push(labeled.render(proof));
+        lines.push(format!(r"\end{{{}}}", env));
+        lines.join("\n")
+    }
+
+    /// Render a proof recursively (bussproofs and ebproof share the line-based,
+    /// premises-then-conclusion shape of the `prooftree` environment).
+    ///
+    /// `path` is this node's dotted position in the premise tree, used to
+    /// derive its `\label` when `label_prefix` is set. `cut_highlight` is the
+    /// cut formula of an enclosing `Cut` node, if this node is one of its
+    /// direct premises, so it can be picked out in this node's own sequent.
+    fn render_proof(
+        &self,
+        proof: &Proof,
+        path: &str,
+        lemmas: &[Lemma],
+        cut_highlight: Option<&Formula>,
+        lines: &mut Vec<String>,
+    ) {
+        if let Some(lemma) = find_lemma(proof, lemmas) {
+            let conclusion = self.format_sequent(proof, cut_highlight);
+            let lemma_ref = format!(r"\ref{{{}}}", lemma.name);
+            match self.backend {
+                LatexBackend::Bussproofs => self.render_bussproofs_node(&conclusion, &lemma_ref, 0, lines),
+                LatexBackend::Ebproof => self.render_ebproof_node(&conclusion, &lemma_ref, 0, lines),
+                LatexBackend::Prftree => unreachable!("prftree is rendered by render_prftree, not render_proof"),
+            }
+            self.push_label(path, lines);
+            return;
+        }
+
+        // Render premises first, passing down the cut formula if this node
+        // is a `Cut` so each premise can highlight it in its own sequent.
+        let premise_cut_highlight = match &proof.rule {
+            Rule::Cut(formula) => Some(formula),
+            _ => None,
+        };
+        for (i, premise) in proof.premises.iter().enumerate() {
+            self.render_proof(premise, &format!("{}.{}", path, i), lemmas, premise_cut_highlight, lines);
         }
 
         // Format the conclusion
-        let conclusion = self.format_sequent(proof);
+        let conclusion = self.format_sequent(proof, cut_highlight);
         let rule_label = self.format_rule(&proof.rule);
 
-        // Generate the appropriate inference command
-        match proof.premises.len() {
+        match self.backend {
+            LatexBackend::Bussproofs => self.render_bussproofs_node(&conclusion, &rule_label, proof.premises.len(), lines),
+            LatexBackend::Ebproof => self.render_ebproof_node(&conclusion, &rule_label, proof.premises.len(), lines),
+            LatexBackend::Prftree => unreachable!("prftree is rendered by render_prftree, not render_proof"),
+        }
+        self.push_label(path, lines);
+    }
+
+    /// Push the `\label{<prefix>-<path>}` line for a node, if `label_prefix`
+    /// is configured.
+    fn push_label(&self, path: &str, lines: &mut Vec<String>) {
+        if let Some(prefix) = &self.label_prefix {
+            lines.push(format!(r"  \label{{{}-{}}}", prefix, path));
+        }
+    }
+
+    fn render_bussproofs_node(&self, conclusion: &str, rule_label: &str, arity: usize, lines: &mut Vec<String>) {
+        match arity {
             0 => {
-                lines.push(format!(r"  \AxiomC{{$\vdash {}$}}", conclusion));
+                // bussproofs has no macro that labels a leaf the way
+                // `\RightLabel` labels an inference line (there's no bar
+                // to attach it to), so `rule_label` is folded into the
+                // typeset math itself as a small parenthesized gloss
+                // rather than a source-only `%` comment, which would have
+                // zero effect on the compiled PDF. This matters for
+                // `render_proof`'s lemma-citation path, which passes a
+                // `\ref{...}` here instead of a rule name — without a
+                // visible marker the citation is lost and a cited leaf
+                // becomes indistinguishable from a re-typeset one. The
+                // math closes before the gloss so a `rule_label` that is
+                // itself math (e.g. `$1$-intro`) still balances.
+                lines.push(format!(r"  \AxiomC{{${}$ {{\scriptsize ({})}}}}", conclusion, rule_label));
             }
             1 => {
-                lines.push(format!(
-                    r"  \RightLabel{{\scriptsize {}}}",
-                    rule_label
-                ));
-                lines.push(format!(r"  \UnaryInfC{{$\vdash {}$}}", conclusion));
+                lines.push(format!(r"  \RightLabel{{\scriptsize {}}}", rule_label));
+                lines.push(format!(r"  \UnaryInfC{{${}$}}", conclusion));
             }
             2 => {
-                lines.push(format!(
-                    r"  \RightLabel{{\scriptsize {}}}",
-                    rule_label
-                ));
-                lines.push(format!(r"  \BinaryInfC{{$\vdash {}$}}", conclusion));
+                lines.push(format!(r"  \RightLabel{{\scriptsize {}}}", rule_label));
+                lines.push(format!(r"  \BinaryInfC{{${}$}}", conclusion));
             }
             3 => {
-                lines.push(format!(
-                    r"  \RightLabel{{\scriptsize {}}}",
-                    rule_label
-                ));
-                lines.push(format!(r"  \TrinaryInfC{{$\vdash {}$}}", conclusion));
+                lines.push(format!(r"  \RightLabel{{\scriptsize {}}}", rule_label));
+                lines.push(format!(r"  \TrinaryInfC{{${}$}}", conclusion));
+            }
+            4 => {
+                lines.push(format!(r"  \RightLabel{{\scriptsize {}}}", rule_label));
+                lines.push(format!(r"  \QuaternaryInfC{{${}$}}", conclusion));
             }
-            _ => {
-                // For more than 3 premises, we'd need a different approach
-                lines.push(format!(
-                    r"  \RightLabel{{\scriptsize {}}}",
-                    rule_label
-                ));
-                lines.push(format!(r"  \QuaternaryInfC{{$\vdash {}$}}", conclusion));
+            n => {
+                // bussproofs ships nothing past `\QuaternaryInfC` (4
+                // premises), so a wider node is emitted as an ebproof-style
+                // `\infer{n}` carrying its real premise count; the preamble
+                // pulls in ebproof whenever the proof has such a node.
+                lines.push(format!(r"  \infer{{{}}}[\scriptsize {}]{{${}$}}", n, rule_label, conclusion));
             }
         }
     }
 
-    /// Format a sequent in LaTeX.
-    fn format_sequent(&self, proof: &Proof) -> String {
-        proof
-            .conclusion
-            .linear
+    /// Name of the bussproofs macro that closes off an inference with
+    /// exactly `arity` (1-4) premises already on the proof stack.
+    fn bussproofs_arity_macro(arity: usize) -> &'static str {
+        match arity {
+            1 => r"\UnaryInfC",
+            2 => r"\BinaryInfC",
+            3 => r"\TrinaryInfC",
+            4 => r"\QuaternaryInfC",
+            _ => unreachable!("bussproofs_arity_macro is only ever called with 1..=4"),
+        }
+    }
+
+    fn render_ebproof_node(&self, conclusion: &str, rule_label: &str, arity: usize, lines: &mut Vec<String>) {
+        if arity == 0 {
+            lines.push(format!(
+                r"  \infer0[\scriptsize {}]{{${}$}}",
+                rule_label, conclusion
+            ));
+        } else {
+            lines.push(format!(
+                r"  \infer{{{}}}[\scriptsize {}]{{${}$}}",
+                arity, rule_label, conclusion
+            ));
+        }
+    }
+
+    /// Render a proof as a single nested `\prftree` macro call.
+    fn render_prftree(&self, proof: &Proof, path: &str, lemmas: &[Lemma], cut_highlight: Option<&Formula>) -> String {
+        let conclusion = self.format_sequent(proof, cut_highlight);
+        let label = match &self.label_prefix {
+            Some(prefix) => format!(r"\label{{{}-{}}}", prefix, path),
+            None => String::new(),
+        };
+
+        if let Some(lemma) = find_lemma(proof, lemmas) {
+            return format!(
+                r"\prftree[\ref{{{}}}]{{${}${}}}",
+                lemma.name, conclusion, label
+            );
+        }
+
+        let rule_label = self.format_rule(&proof.rule);
+        let premise_cut_highlight = match &proof.rule {
+            Rule::Cut(formula) => Some(formula),
+            _ => None,
+        };
+
+        if proof.premises.is_empty() {
+            format!(r"\prftree[\scriptsize {}]{{${}${}}}", rule_label, conclusion, label)
+        } else {
+            let premises: String = proof
+                .premises
+                .iter()
+                .enumerate()
+                .map(|(i, p)| {
+                    format!(
+                        "{{{}}}",
+                        self.render_prftree(p, &format!("{}.{}", path, i), lemmas, premise_cut_highlight)
+                    )
+                })
+                .collect();
+            format!(
+                r"\prftree[\scriptsize {}]{}{{${}${}}}",
+                rule_label, premises, conclusion, label
+            )
+        }
+    }
+
+    /// Format a dyadic focusing sequent `Θ ; Γ`, where `Θ` is the unbounded
+    /// (`!`-) zone and `Γ` the linear zone, decorating the turnstile with the
+    /// focusing phase carried by the node's `Rule`: `\Downarrow` with the
+    /// focused formula for `FocusPositive`/`FocusNegative`, `\Uparrow` for the
+    /// inversion phase entered by `Blur`, and a bare turnstile otherwise.
+    ///
+    /// When `self.highlight` is enabled, the rule's principal formula (per
+    /// [`highlight::principal_formula_index`]) is wrapped in
+    /// `\textcolor{principal_color}{..}`, and `cut_highlight` — the cut
+    /// formula of an enclosing `Cut`, if any — is boxed in `cut_color`.
+    fn format_sequent(&self, proof: &Proof, cut_highlight: Option<&Formula>) -> String {
+        let linear = &proof.conclusion.linear;
+        let principal = highlight::principal_formula_index(linear, &proof.rule);
+        let cut_index = cut_highlight.and_then(|cf| highlight::cut_formula_index(linear, &Rule::Cut(cf.clone())));
+
+        let render_formula = |(i, f): (usize, &Formula)| {
+            let text = f.pretty_latex();
+            if !self.highlight.enabled {
+                return text;
+            }
+            if Some(i) == principal {
+                format!(r"\textcolor{{{}}}{{{}}}", self.highlight.principal_color, text)
+            } else if Some(i) == cut_index {
+                format!(r"\boxed{{\textcolor{{{}}}{{{}}}}}", self.highlight.cut_color, text)
+            } else {
+                text
+            }
+        };
+
+        let (theta, gamma): (Vec<(usize, &Formula)>, Vec<(usize, &Formula)>) = linear
             .iter()
-            .map(|f| f.pretty_latex())
-            .collect::<Vec<_>>()
-            .join(", ")
+            .enumerate()
+            .partition(|(_, f)| matches!(f, Formula::OfCourse(_)));
+
+        let theta_str = theta.into_iter().map(render_formula).collect::<Vec<_>>().join(", ");
+        let gamma_str = gamma.into_iter().map(render_formula).collect::<Vec<_>>().join(", ");
+
+        match &proof.rule {
+            Rule::FocusPositive(focused) | Rule::FocusNegative(focused) => {
+                format!(r"{} ; \vdash {} \Downarrow {}", theta_str, gamma_str, focused.pretty_latex())
+            }
+            Rule::Blur => format!(r"{} ; \vdash {} \Uparrow", theta_str, gamma_str),
+            _ => format!(r"{} ; \vdash {}", theta_str, gamma_str),
+        }
     }
 
     /// Format a rule name for LaTeX.
-    fn format_rule(&self, rule: &lolli_core::Rule) -> String {
-        use lolli_core::Rule;
-
+    fn format_rule(&self, rule: &Rule) -> String {
         if self.short_labels {
             match rule {
                 Rule::Axiom => "ax".to_string(),
@@ -176,10 +435,23 @@ impl LatexRenderer {
     }
 }
 
+/// True if any node in the proof tree has more premises than bussproofs'
+/// native `\QuaternaryInfC` (4) can express.
+fn proof_has_wide_node(proof: &Proof) -> bool {
+    proof.premises.len() > 4 || proof.premises.iter().any(proof_has_wide_node)
+}
+
+/// Find the lemma whose proof is this exact node, by reference identity
+/// rather than structural equality (two syntactically-equal but distinct
+/// subtrees should still be typeset in full).
+fn find_lemma<'a, 'b>(proof: &Proof, lemmas: &'b [Lemma<'a>]) -> Option<&'b Lemma<'a>> {
+    lemmas.iter().find(|lemma| std::ptr::eq(lemma.proof, proof))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use lolli_core::{Formula, Rule, Sequent};
+    use lolli_core::Sequent;
 
     #[test]
     fn test_render_axiom() {
@@ -268,4 +540,293 @@ mod tests {
         assert!(output.contains(r"\begin{document}"));
         assert!(output.contains(r"\end{document}"));
     }
+
+    #[test]
+    fn test_bussproofs_falls_back_past_quaternary() {
+        let premises: Vec<Proof> = (0..5)
+            .map(|i| Proof {
+                conclusion: Sequent::new(vec![Formula::atom(format!("A{}", i))]),
+                rule: Rule::Axiom,
+                premises: vec![],
+            })
+            .collect();
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("C")]),
+            rule: Rule::WithIntro,
+            premises,
+        };
+
+        let renderer = LatexRenderer::new();
+        let output = renderer.render(&proof);
+
+        // A true 5-premise count, not silently truncated to Quaternary (4).
+        assert!(output.contains(r"\infer{5}"));
+        assert!(!output.contains(r"\QuaternaryInfC"));
+
+        let mut renderer = renderer;
+        renderer.include_preamble = true;
+        let output = renderer.render(&proof);
+        assert!(output.contains(r"\usepackage{bussproofs}"));
+        assert!(output.contains(r"\usepackage{ebproof}"));
+    }
+
+    #[test]
+    fn test_ebproof_backend() {
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::tensor(Formula::atom("A"), Formula::atom("B"))]),
+            rule: Rule::TensorIntro,
+            premises: vec![left, right],
+        };
+
+        let renderer = LatexRenderer::with_backend(LatexBackend::Ebproof);
+        let output = renderer.render(&proof);
+
+        assert!(output.contains(r"\usepackage{ebproof}") == false); // preamble off by default
+        assert!(output.contains(r"\infer0"));
+        assert!(output.contains(r"\infer{2}"));
+
+        let mut renderer = renderer;
+        renderer.include_preamble = true;
+        let output = renderer.render(&proof);
+        assert!(output.contains(r"\usepackage{ebproof}"));
+    }
+
+    #[test]
+    fn test_prftree_backend() {
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::tensor(Formula::atom("A"), Formula::atom("B"))]),
+            rule: Rule::TensorIntro,
+            premises: vec![left, right],
+        };
+
+        let renderer = LatexRenderer::with_backend(LatexBackend::Prftree);
+        let output = renderer.render(&proof);
+
+        assert!(output.contains(r"\prftree"));
+        // No prooftree environment for prftree
+        assert!(!output.contains(r"\begin{prooftree}"));
+        // Nested premises appear as braced sub-calls
+        assert!(output.matches(r"\prftree").count() == 3);
+    }
+
+    #[test]
+    fn test_dyadic_sequent_zones() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::of_course(Formula::atom("A")),
+                Formula::atom("B"),
+            ]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+
+        let renderer = LatexRenderer::new();
+        let output = renderer.render(&proof);
+
+        // `!A` goes in the unbounded zone, `B` stays in the linear zone,
+        // separated by a semicolon.
+        assert!(output.contains(r"{!}A ; \vdash B"));
+    }
+
+    #[test]
+    fn test_focus_phase_arrows() {
+        let axiom = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let focused = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A")]),
+            rule: Rule::FocusPositive(Formula::atom("A")),
+            premises: vec![axiom],
+        };
+        let blurred = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A")]),
+            rule: Rule::Blur,
+            premises: vec![focused],
+        };
+
+        let renderer = LatexRenderer::new();
+        let output = renderer.render(&blurred);
+
+        assert!(output.contains(r"\Downarrow"));
+        assert!(output.contains(r"\Uparrow"));
+    }
+
+    #[test]
+    fn test_label_prefix_paths() {
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::tensor(Formula::atom("A"), Formula::atom("B"))]),
+            rule: Rule::TensorIntro,
+            premises: vec![left, right],
+        };
+
+        let mut renderer = LatexRenderer::new();
+        renderer.label_prefix = Some("ex".to_string());
+        let output = renderer.render(&proof);
+
+        assert!(output.contains(r"\label{ex-0.0}"));
+        assert!(output.contains(r"\label{ex-0.1}"));
+        assert!(output.contains(r"\label{ex-0}"));
+    }
+
+    #[test]
+    fn test_render_named_wraps_in_environment() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+
+        let renderer = LatexRenderer::new();
+        let output = renderer.render_named("lemma", "cut-elim", &proof);
+
+        assert!(output.contains(r"\begin{lemma}"));
+        assert!(output.contains(r"\label{cut-elim}"));
+        assert!(output.contains(r"\label{cut-elim-0}"));
+        assert!(output.contains(r"\end{lemma}"));
+    }
+
+    #[test]
+    fn test_render_with_lemmas_cites_instead_of_retypesetting() {
+        let a = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let b = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let inner = Proof {
+            conclusion: Sequent::new(vec![Formula::tensor(Formula::atom("A"), Formula::atom("B"))]),
+            rule: Rule::TensorIntro,
+            premises: vec![a, b],
+        };
+        let c = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("C")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let outer = Proof {
+            conclusion: Sequent::new(vec![Formula::par(
+                Formula::tensor(Formula::atom("A"), Formula::atom("B")),
+                Formula::atom("C"),
+            )]),
+            rule: Rule::ParIntro,
+            premises: vec![inner, c],
+        };
+
+        let lemmas = [Lemma {
+            name: "ax-tensor",
+            proof: &outer.premises[0],
+        }];
+
+        let renderer = LatexRenderer::new();
+        let output = renderer.render_with_lemmas(&outer, &lemmas);
+
+        assert!(output.contains(r"\ref{ax-tensor}"));
+        // `inner`'s own A/B axiom leaves must not be re-typeset, only `C`'s.
+        assert_eq!(output.matches(r"\AxiomC").count(), 2);
+        // Only the outer `Par` node is a binary inference; `inner`'s own
+        // `Tensor` node was replaced by the lemma reference, not re-typeset.
+        assert_eq!(output.matches(r"\BinaryInfC").count(), 1);
+    }
+
+    #[test]
+    fn test_highlight_principal_formula() {
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::tensor(Formula::atom("A"), Formula::atom("B"))]),
+            rule: Rule::TensorIntro,
+            premises: vec![left, right],
+        };
+
+        let mut renderer = LatexRenderer::new();
+        renderer.highlight = HighlightStyle::on();
+        let output = renderer.render(&proof);
+
+        assert!(output.contains(r"\textcolor{blue}"));
+    }
+
+    #[test]
+    fn test_highlight_off_by_default() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::tensor(Formula::atom("A"), Formula::atom("B"))]),
+            rule: Rule::TensorIntro,
+            premises: vec![],
+        };
+
+        let renderer = LatexRenderer::new();
+        let output = renderer.render(&proof);
+
+        assert!(!output.contains(r"\textcolor"));
+    }
+
+    #[test]
+    fn test_highlight_cut_formula_in_premises() {
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A"), Formula::atom("C")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("D")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("C"), Formula::atom("D")]),
+            rule: Rule::Cut(Formula::atom("A")),
+            premises: vec![left, right],
+        };
+
+        let mut renderer = LatexRenderer::new();
+        renderer.highlight = HighlightStyle::on();
+        let output = renderer.render(&proof);
+
+        // The cut formula `A` (and its dual) is boxed in each premise.
+        assert_eq!(output.matches(r"\boxed").count(), 2);
+        assert!(output.contains(r"\boxed{\textcolor{red}{A}}"));
+    }
 }
@@ -9,6 +9,7 @@
 //! - **ASCII/Unicode**: Terminal-friendly proof trees
 //! - **LaTeX**: Using bussproofs package
 //! - **DOT**: Graphviz format for graph visualization
+//! - **SVG**: Self-contained, no external renderer required
 //!
 //! ## Example
 //!
@@ -34,10 +35,16 @@ pub use lolli_core::{Formula, Proof, Rule, Sequent};
 mod ascii;
 mod latex;
 mod dot;
+mod highlight;
+mod net;
+mod svg;
 
 pub use ascii::TreeRenderer;
 pub use latex::LatexRenderer;
 pub use dot::DotRenderer;
+pub use highlight::HighlightStyle;
+pub use net::{CorrectnessError, NetEdge, NetError, NetNode, ProofNet};
+pub use svg::SvgRenderer;
 
 /// Render a proof as ASCII text.
 pub fn render_ascii(proof: &Proof) -> String {
@@ -56,7 +63,18 @@ pub fn render_latex(proof: &Proof) -> String {
     LatexRenderer::new().render(proof)
 }
 
+/// Render a proof as a standalone, compilable LaTeX document (bussproofs
+/// package), rather than a bare `prooftree` fragment.
+pub fn render_latex_document(proof: &Proof) -> String {
+    LatexRenderer::new().render_document(proof)
+}
+
 /// Render a proof as Graphviz DOT format.
 pub fn render_dot(proof: &Proof) -> String {
     DotRenderer::new().render(proof)
 }
+
+/// Render a proof as a self-contained SVG document.
+pub fn render_svg(proof: &Proof) -> String {
+    SvgRenderer::new().render(proof)
+}
@@ -0,0 +1,571 @@
+//! Multiplicative proof nets and the Danos–Regnier correctness criterion.
+//!
+//! A *proof net* represents a multiplicative linear logic proof (⊗, ⅋,
+//! axiom, cut) as a graph, abstracting away the bureaucratic ordering of
+//! rules that a sequent derivation carries. [`ProofNet::from_proof`] builds
+//! one from a [`Proof`] restricted to that fragment; every atomic occurrence
+//! is a node, an axiom link joins a formula to its dual, a cut link joins a
+//! cut formula to its dual, and a ⊗-/⅋-link is a binary node with two
+//! premise edges and (if consumed further up the proof) one conclusion edge.
+//!
+//! The Danos–Regnier criterion: a *switching* picks, for every ⅋-link,
+//! exactly one of its two premise edges to keep; the net is correct iff
+//! *every* switching yields an acyclic, connected graph (a spanning tree).
+//! [`ProofNet::is_correct`] checks this directly by enumerating all
+//! `2^(#par-links)` switchings; [`ProofNet::is_correct_by_contraction`]
+//! decides the same question in polynomial time by iteratively contracting
+//! the graph instead: a ⅋-link whose two premises already sit in the same
+//! component is doomed under every switching (return early), and a ⅋-link
+//! with one premise still reaching a fresh component can be contracted
+//! through it safely, since some switching is free to keep that premise.
+
+use lolli_core::{Formula, Proof, Rule};
+
+/// A node in a [`ProofNet`]: either an atomic formula occurrence or a link
+/// connecting occurrences together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetNode {
+    /// An occurrence of an atomic (possibly negated) formula.
+    Atom(Formula),
+    /// An axiom link, joining a formula to its dual.
+    Axiom,
+    /// A cut link, joining a formula to its dual.
+    Cut,
+    /// A ⊗-link, with two premise edges and a conclusion edge.
+    Tensor,
+    /// A ⅋-link, with two premise edges and a conclusion edge. The two
+    /// premise edges are switchable under the Danos–Regnier criterion.
+    Par,
+}
+
+/// An undirected edge between two [`ProofNet`] nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetEdge {
+    /// One endpoint.
+    pub a: usize,
+    /// The other endpoint.
+    pub b: usize,
+}
+
+/// Why a [`ProofNet`] could not be built from a [`Proof`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum NetError {
+    /// The proof uses a rule outside the multiplicative fragment (⊗, ⅋,
+    /// axiom, cut) that this module's proof nets support.
+    #[error("rule {0:?} is outside the multiplicative fragment (⊗, ⅋, axiom, cut)")]
+    UnsupportedRule(Rule),
+    /// A principal formula's occurrence could not be located in its
+    /// premise(s), so the net's connectivity could not be constructed.
+    #[error("could not locate a premise occurrence for {0}")]
+    MissingOccurrence(String),
+}
+
+/// Whether a failing switching produced a cycle or left the graph
+/// disconnected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorrectnessError {
+    /// The switching's graph contains a cycle.
+    Cycle,
+    /// The switching's graph is not connected.
+    Disconnected,
+}
+
+/// A multiplicative proof net built from a [`Proof`] via
+/// [`ProofNet::from_proof`].
+pub struct ProofNet {
+    nodes: Vec<NetNode>,
+    /// Edges present in every switching: axiom/cut links and both edges of
+    /// every ⊗-link.
+    fixed_edges: Vec<NetEdge>,
+    /// Each ⅋-link contributes one switchable pair: exactly one of the two
+    /// edges is kept in any given switching.
+    par_edges: Vec<(NetEdge, NetEdge)>,
+}
+
+impl ProofNet {
+    /// Build a proof net from a proof restricted to the multiplicative
+    /// fragment (axiom, cut, ⊗, ⅋).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NetError::UnsupportedRule`] if the proof uses any other
+    /// rule, or [`NetError::MissingOccurrence`] if a rule's principal
+    /// formula can't be located among its premises.
+    pub fn from_proof(proof: &Proof) -> Result<ProofNet, NetError> {
+        let mut net = ProofNet {
+            nodes: Vec::new(),
+            fixed_edges: Vec::new(),
+            par_edges: Vec::new(),
+        };
+        net.build(proof)?;
+        Ok(net)
+    }
+
+    /// The nodes of the net, for rendering.
+    pub fn nodes(&self) -> &[NetNode] {
+        &self.nodes
+    }
+
+    /// Edges present in every switching.
+    pub fn fixed_edges(&self) -> &[NetEdge] {
+        &self.fixed_edges
+    }
+
+    /// Each ⅋-link's switchable premise-edge pair.
+    pub fn par_edges(&self) -> &[(NetEdge, NetEdge)] {
+        &self.par_edges
+    }
+
+    fn push_node(&mut self, node: NetNode) -> usize {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    /// Recursively build the net for `proof`, returning the node id
+    /// representing each formula of `proof.conclusion.linear`, in order.
+    fn build(&mut self, proof: &Proof) -> Result<Vec<usize>, NetError> {
+        match &proof.rule {
+            Rule::Axiom => {
+                let linear = &proof.conclusion.linear;
+                if linear.len() != 2 {
+                    return Err(NetError::MissingOccurrence(proof.conclusion.pretty()));
+                }
+                let a = self.push_node(NetNode::Atom(linear[0].clone()));
+                let b = self.push_node(NetNode::Atom(linear[1].clone()));
+                let link = self.push_node(NetNode::Axiom);
+                self.fixed_edges.push(NetEdge { a: link, b: a });
+                self.fixed_edges.push(NetEdge { a: link, b });
+                Ok(vec![a, b])
+            }
+
+            Rule::TensorIntro => {
+                let left = self.build(&proof.premises[0])?;
+                let right = self.build(&proof.premises[1])?;
+                self.combine_tensor(proof, &left, &right)
+            }
+
+            Rule::ParIntro => {
+                let premise = self.build(&proof.premises[0])?;
+                self.combine_par(proof, &premise)
+            }
+
+            Rule::Cut(cut_formula) => {
+                let left = self.build(&proof.premises[0])?;
+                let right = self.build(&proof.premises[1])?;
+                let left_conclusion = &proof.premises[0].conclusion.linear;
+                let right_conclusion = &proof.premises[1].conclusion.linear;
+                let li = left_conclusion
+                    .iter()
+                    .position(|f| f == cut_formula)
+                    .ok_or_else(|| NetError::MissingOccurrence(cut_formula.pretty()))?;
+                let dual = cut_formula.negate();
+                let ri = right_conclusion
+                    .iter()
+                    .position(|f| *f == dual)
+                    .ok_or_else(|| NetError::MissingOccurrence(dual.pretty()))?;
+
+                let link = self.push_node(NetNode::Cut);
+                self.fixed_edges.push(NetEdge { a: link, b: left[li] });
+                self.fixed_edges.push(NetEdge { a: link, b: right[ri] });
+
+                let mut result: Vec<usize> = left
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != li)
+                    .map(|(_, id)| *id)
+                    .collect();
+                result.extend(right.iter().enumerate().filter(|(i, _)| *i != ri).map(|(_, id)| *id));
+                Ok(result)
+            }
+
+            other => Err(NetError::UnsupportedRule(other.clone())),
+        }
+    }
+
+    fn combine_tensor(
+        &mut self,
+        proof: &Proof,
+        left_ids: &[usize],
+        right_ids: &[usize],
+    ) -> Result<Vec<usize>, NetError> {
+        let conclusion = &proof.conclusion.linear;
+        let left_conclusion = &proof.premises[0].conclusion.linear;
+        let right_conclusion = &proof.premises[1].conclusion.linear;
+
+        let (principal_idx, a, b) = conclusion
+            .iter()
+            .enumerate()
+            .find_map(|(i, f)| match f {
+                Formula::Tensor(a, b) => Some((i, (**a).clone(), (**b).clone())),
+                _ => None,
+            })
+            .ok_or_else(|| NetError::MissingOccurrence("a ⊗ conclusion".to_string()))?;
+
+        let li = left_conclusion
+            .iter()
+            .position(|f| *f == a)
+            .ok_or_else(|| NetError::MissingOccurrence(a.pretty()))?;
+        let ri = right_conclusion
+            .iter()
+            .position(|f| *f == b)
+            .ok_or_else(|| NetError::MissingOccurrence(b.pretty()))?;
+
+        let link = self.push_node(NetNode::Tensor);
+        self.fixed_edges.push(NetEdge { a: link, b: left_ids[li] });
+        self.fixed_edges.push(NetEdge { a: link, b: right_ids[ri] });
+
+        let passthrough = left_ids
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != li)
+            .map(|(_, id)| *id)
+            .chain(right_ids.iter().enumerate().filter(|(i, _)| *i != ri).map(|(_, id)| *id));
+
+        Ok(interleave_at(conclusion.len(), principal_idx, link, passthrough))
+    }
+
+    fn combine_par(&mut self, proof: &Proof, premise_ids: &[usize]) -> Result<Vec<usize>, NetError> {
+        let conclusion = &proof.conclusion.linear;
+        let premise_conclusion = &proof.premises[0].conclusion.linear;
+
+        let (principal_idx, a, b) = conclusion
+            .iter()
+            .enumerate()
+            .find_map(|(i, f)| match f {
+                Formula::Par(a, b) => Some((i, (**a).clone(), (**b).clone())),
+                _ => None,
+            })
+            .ok_or_else(|| NetError::MissingOccurrence("a ⅋ conclusion".to_string()))?;
+
+        let ai = premise_conclusion
+            .iter()
+            .position(|f| *f == a)
+            .ok_or_else(|| NetError::MissingOccurrence(a.pretty()))?;
+        let bi = premise_conclusion
+            .iter()
+            .enumerate()
+            .find(|(i, f)| *i != ai && **f == b)
+            .map(|(i, _)| i)
+            .ok_or_else(|| NetError::MissingOccurrence(b.pretty()))?;
+
+        let link = self.push_node(NetNode::Par);
+        let edge_a = NetEdge { a: link, b: premise_ids[ai] };
+        let edge_b = NetEdge { a: link, b: premise_ids[bi] };
+        self.par_edges.push((edge_a, edge_b));
+
+        let passthrough = premise_ids
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != ai && *i != bi)
+            .map(|(_, id)| *id);
+
+        Ok(interleave_at(conclusion.len(), principal_idx, link, passthrough))
+    }
+
+    /// Check the Danos–Regnier criterion directly: the net is correct iff
+    /// every switching yields an acyclic, connected graph.
+    pub fn is_correct(&self) -> bool {
+        self.correctness_errors().is_empty()
+    }
+
+    /// Report the failure mode of every switching that isn't a spanning
+    /// tree (empty if the net is correct). Enumerates all
+    /// `2^(#par-links)` switchings.
+    pub fn correctness_errors(&self) -> Vec<CorrectnessError> {
+        let switches = self.par_edges.len();
+        let total = 1usize << switches;
+        let mut errors = Vec::new();
+        for mask in 0..total {
+            let mut edges = self.fixed_edges.clone();
+            for (i, (left, right)) in self.par_edges.iter().enumerate() {
+                edges.push(if mask & (1 << i) == 0 { *left } else { *right });
+            }
+            if let Some(err) = check_spanning_tree(self.nodes.len(), &edges) {
+                errors.push(err);
+            }
+        }
+        errors
+    }
+
+    /// Equivalent to [`ProofNet::is_correct`], decided by iterative
+    /// contraction instead of enumerating all `2^n` switchings.
+    ///
+    /// First the always-present fixed edges (axiom/cut/⊗) are unioned
+    /// together; a cycle there already dooms every switching, so we bail
+    /// out immediately. Otherwise we repeatedly scan the ⅋-links: one
+    /// whose two premises both already sit in the same component is
+    /// doomed no matter which a switching keeps, so the net is incorrect;
+    /// one with at least one premise still reaching a distinct component
+    /// can be contracted through that premise, since some switching is
+    /// free to make that choice. Ties (both premises still distinct) are
+    /// deferred to a later pass in case other contractions resolve them,
+    /// and are otherwise broken arbitrarily once no more forced
+    /// contractions remain. The net is correct iff this terminates with
+    /// every ⅋-link contracted and the whole graph collapsed to one
+    /// component.
+    pub fn is_correct_by_contraction(&self) -> bool {
+        let mut uf = UnionFind::new(self.nodes.len());
+        for edge in &self.fixed_edges {
+            if !uf.union(edge.a, edge.b) {
+                return false;
+            }
+        }
+
+        let mut remaining: Vec<usize> = (0..self.par_edges.len()).collect();
+        while !remaining.is_empty() {
+            let mut ambiguous = Vec::new();
+            let mut progressed = false;
+            for i in remaining.drain(..) {
+                let (left, right) = self.par_edges[i];
+                let left_distinct = uf.find(left.a) != uf.find(left.b);
+                let right_distinct = uf.find(right.a) != uf.find(right.b);
+                match (left_distinct, right_distinct) {
+                    (false, false) => return false,
+                    (true, false) => {
+                        uf.union(left.a, left.b);
+                        progressed = true;
+                    }
+                    (false, true) => {
+                        uf.union(right.a, right.b);
+                        progressed = true;
+                    }
+                    (true, true) => ambiguous.push(i),
+                }
+            }
+            if !progressed {
+                let i = ambiguous.remove(0);
+                let (left, _) = self.par_edges[i];
+                uf.union(left.a, left.b);
+            }
+            remaining = ambiguous;
+        }
+
+        if self.nodes.is_empty() {
+            return true;
+        }
+        let root = uf.find(0);
+        (0..self.nodes.len()).all(|n| uf.find(n) == root)
+    }
+}
+
+/// Minimal union-find with path compression, used by
+/// [`ProofNet::is_correct_by_contraction`] to track which nodes the
+/// contraction has already merged into one component.
+struct UnionFind {
+    parent: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(node_count: usize) -> Self {
+        UnionFind { parent: (0..node_count).collect() }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    /// Merge the components containing `a` and `b`. Returns `false` if
+    /// they were already the same component (the edge would close a
+    /// cycle).
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let ra = self.find(a);
+        let rb = self.find(b);
+        if ra == rb {
+            return false;
+        }
+        self.parent[ra] = rb;
+        true
+    }
+}
+
+/// Build the per-position result vector for a binary/unary connective
+/// introduction: `link` takes `principal_idx`, and `passthrough` fills every
+/// other position in order.
+fn interleave_at(
+    len: usize,
+    principal_idx: usize,
+    link: usize,
+    passthrough: impl Iterator<Item = usize>,
+) -> Vec<usize> {
+    let mut passthrough = passthrough;
+    (0..len)
+        .map(|i| {
+            if i == principal_idx {
+                link
+            } else {
+                passthrough.next().expect("passthrough exhausted before conclusion")
+            }
+        })
+        .collect()
+}
+
+fn check_spanning_tree(node_count: usize, edges: &[NetEdge]) -> Option<CorrectnessError> {
+    let mut parent: Vec<usize> = (0..node_count).collect();
+
+    fn find(parent: &mut [usize], x: usize) -> usize {
+        if parent[x] != x {
+            parent[x] = find(parent, parent[x]);
+        }
+        parent[x]
+    }
+
+    for edge in edges {
+        let ra = find(&mut parent, edge.a);
+        let rb = find(&mut parent, edge.b);
+        if ra == rb {
+            return Some(CorrectnessError::Cycle);
+        }
+        parent[ra] = rb;
+    }
+
+    if node_count == 0 {
+        return None;
+    }
+    let root = find(&mut parent, 0);
+    if (0..node_count).any(|n| find(&mut parent, n) != root) {
+        return Some(CorrectnessError::Disconnected);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lolli_core::Sequent;
+
+    fn axiom(a: &str) -> Proof {
+        Proof {
+            conclusion: Sequent::new(vec![Formula::atom(a), Formula::neg_atom(a)]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        }
+    }
+
+    #[test]
+    fn test_axiom_net_is_correct() {
+        let net = ProofNet::from_proof(&axiom("A")).unwrap();
+        assert_eq!(net.nodes().len(), 3); // two atoms + one axiom link
+        assert!(net.is_correct());
+    }
+
+    #[test]
+    fn test_tensor_of_two_axioms_is_correct() {
+        let left = axiom("A");
+        let right = axiom("B");
+        let proof = Proof {
+            conclusion: Sequent::new(vec![
+                Formula::neg_atom("A"),
+                Formula::neg_atom("B"),
+                Formula::tensor(Formula::atom("A"), Formula::atom("B")),
+            ]),
+            rule: Rule::TensorIntro,
+            premises: vec![left, right],
+        };
+
+        let net = ProofNet::from_proof(&proof).unwrap();
+        assert!(net.is_correct());
+        assert!(net.is_correct_by_contraction());
+    }
+
+    #[test]
+    fn test_cut_of_dual_axioms_is_correct() {
+        // ⊢ A⊥,A cut with ⊢ A⊥,A over A gives ⊢ A⊥,A — contrived but
+        // multiplicatively well-formed for this test.
+        let left = axiom("A");
+        let right = axiom("A");
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+            rule: Rule::Cut(Formula::atom("A")),
+            premises: vec![left, right],
+        };
+
+        let net = ProofNet::from_proof(&proof).unwrap();
+        assert!(net.is_correct());
+    }
+
+    #[test]
+    fn test_par_introduction_is_correct() {
+        // ⊢ A⊥,B⊥ justifies ⊢ A⊥ ⅋ B⊥ via a single ⅋-link with a switchable pair.
+        let premise = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::neg_atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        // Not a real axiom (A⊥ and B⊥ aren't duals) but exercises the ⅋
+        // construction shape.
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::par(Formula::neg_atom("A"), Formula::neg_atom("B"))]),
+            rule: Rule::ParIntro,
+            premises: vec![premise],
+        };
+
+        let net = ProofNet::from_proof(&proof).unwrap();
+        assert_eq!(net.par_edges().len(), 1);
+        assert!(net.is_correct());
+    }
+
+    #[test]
+    fn test_contraction_agrees_with_enumeration_on_three_par_links() {
+        // Three independent ⅋-links (each over a two-atom "axiom"), glued
+        // together by two ⊗-links. The fixed skeleton alone is acyclic, so
+        // this only exercises the contraction path genuinely — no ⅋-link
+        // is resolved by the fixed-edges-only cycle check.
+        fn par_leaf(a: &str, b: &str) -> Proof {
+            let premise = Proof {
+                conclusion: Sequent::new(vec![Formula::neg_atom(a), Formula::neg_atom(b)]),
+                rule: Rule::Axiom,
+                premises: vec![],
+            };
+            Proof {
+                conclusion: Sequent::new(vec![Formula::par(Formula::neg_atom(a), Formula::neg_atom(b))]),
+                rule: Rule::ParIntro,
+                premises: vec![premise],
+            }
+        }
+
+        fn tensor(left: Proof, right: Proof) -> Proof {
+            let a = left.conclusion.linear[0].clone();
+            let b = right.conclusion.linear[0].clone();
+            Proof {
+                conclusion: Sequent::new(vec![Formula::tensor(a, b)]),
+                rule: Rule::TensorIntro,
+                premises: vec![left, right],
+            }
+        }
+
+        let proof = tensor(tensor(par_leaf("A", "X"), par_leaf("B", "Y")), par_leaf("C", "Z"));
+
+        let net = ProofNet::from_proof(&proof).unwrap();
+        assert_eq!(net.par_edges().len(), 3);
+        assert!(net.is_correct());
+        assert!(net.is_correct_by_contraction());
+    }
+
+    #[test]
+    fn test_disconnected_net_is_rejected() {
+        // Build a net by hand with two lone atom nodes — never joined by
+        // any edge — to exercise the disconnection branch.
+        let mut net = ProofNet {
+            nodes: vec![NetNode::Atom(Formula::atom("A")), NetNode::Atom(Formula::atom("B"))],
+            fixed_edges: Vec::new(),
+            par_edges: Vec::new(),
+        };
+        assert!(!net.is_correct());
+        assert_eq!(net.correctness_errors(), vec![CorrectnessError::Disconnected]);
+        net.fixed_edges.push(NetEdge { a: 0, b: 1 });
+        assert!(net.is_correct());
+    }
+
+    #[test]
+    fn test_unsupported_rule_is_rejected() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::One]),
+            rule: Rule::OneIntro,
+            premises: vec![],
+        };
+        assert!(matches!(ProofNet::from_proof(&proof), Err(NetError::UnsupportedRule(_))));
+    }
+}
@@ -0,0 +1,229 @@
+//! SVG proof-tree rendering.
+//!
+//! Lays out a proof the same way [`crate::TreeRenderer`] and
+//! [`crate::DotRenderer`] do — premises stacked above their conclusion —
+//! but emits a self-contained `<svg>` document instead of terminal text or
+//! a Graphviz `.dot` file, so a proof can be dropped straight into a web
+//! page or README with no external renderer required.
+
+use lolli_core::Proof;
+
+/// Horizontal space, in pixels, a single character of sequent text takes up.
+/// There's no real font metrics available here, so this is a monospace
+/// estimate good enough to size inference boxes without clipping text.
+const CHAR_WIDTH: f64 = 7.2;
+/// Height, in pixels, of a single sequent's text row.
+const ROW_HEIGHT: f64 = 24.0;
+/// Vertical space, in pixels, between a conclusion and its premises' row.
+const ROW_GAP: f64 = 40.0;
+/// Horizontal space, in pixels, between two sibling premises.
+const SIBLING_GAP: f64 = 24.0;
+/// Margin, in pixels, around the whole diagram.
+const MARGIN: f64 = 16.0;
+/// How far, in pixels, an inference bar extends past the centres of its
+/// outermost premises.
+const BAR_OVERHANG: f64 = 12.0;
+
+/// SVG proof-tree renderer.
+pub struct SvgRenderer {
+    /// Font family for sequent text and rule labels.
+    pub font: String,
+    /// Font size, in pixels, for sequent text.
+    pub font_size: f64,
+}
+
+impl Default for SvgRenderer {
+    fn default() -> Self {
+        Self {
+            font: "monospace".to_string(),
+            font_size: 14.0,
+        }
+    }
+}
+
+impl SvgRenderer {
+    /// Create a new SVG renderer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render a proof as a self-contained SVG document.
+    pub fn render(&self, proof: &Proof) -> String {
+        let tree = self.layout(proof);
+        let width = tree.width + 2.0 * MARGIN;
+        let height = tree.depth as f64 * ROW_GAP + ROW_HEIGHT + 2.0 * MARGIN;
+
+        let mut body = String::new();
+        self.draw(&tree, MARGIN, height - MARGIN - ROW_HEIGHT, &mut body);
+
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:.0}\" height=\"{:.0}\" \
+             viewBox=\"0 0 {:.0} {:.0}\" font-family=\"{}\" font-size=\"{:.0}\">\n{}</svg>",
+            width, height, width, height, self.font, self.font_size, body
+        )
+    }
+
+    /// Recursively measure each sequent's `pretty()` text to size its
+    /// inference box, and the subtree's overall width as the widest of its
+    /// own label and its premises laid out side by side.
+    fn layout(&self, proof: &Proof) -> LayoutNode {
+        let label = proof
+            .conclusion
+            .linear
+            .iter()
+            .map(|f| f.pretty())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let label_width = (label.chars().count() as f64 + 2.0) * CHAR_WIDTH;
+
+        let premises: Vec<LayoutNode> = proof.premises.iter().map(|p| self.layout(p)).collect();
+        let children_width = row_width(&premises);
+
+        LayoutNode {
+            label,
+            rule: format!("{:?}", proof.rule),
+            width: label_width.max(children_width),
+            depth: 1 + premises.iter().map(|p| p.depth).max().unwrap_or(0),
+            premises,
+        }
+    }
+
+    /// Draw `node` and its premises, with `node`'s conclusion box's top-left
+    /// corner at `(x, y)`, recursing upward (decreasing `y`) for premises.
+    fn draw(&self, node: &LayoutNode, x: f64, y: f64, out: &mut String) {
+        let children_width = row_width(&node.premises);
+        let mut child_x = x + (node.width - children_width) / 2.0;
+        let mut child_centers = Vec::new();
+
+        for premise in &node.premises {
+            self.draw(premise, child_x, y - ROW_GAP, out);
+            child_centers.push(child_x + premise.width / 2.0);
+            child_x += premise.width + SIBLING_GAP;
+        }
+
+        let center = x + node.width / 2.0;
+
+        if !node.premises.is_empty() {
+            let bar_left = child_centers.first().copied().unwrap_or(center) - BAR_OVERHANG;
+            let bar_right = child_centers.last().copied().unwrap_or(center) + BAR_OVERHANG;
+            let bar_y = y;
+            out.push_str(&format!(
+                "  <line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke=\"black\" stroke-width=\"1\" />\n",
+                bar_left, bar_y, bar_right, bar_y
+            ));
+            out.push_str(&format!(
+                "  <text x=\"{:.1}\" y=\"{:.1}\" font-size=\"{:.0}\">{}</text>\n",
+                bar_right + 6.0,
+                bar_y + 4.0,
+                self.font_size * 0.75,
+                escape(&node.rule)
+            ));
+        }
+
+        out.push_str(&format!(
+            "  <text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}</text>\n",
+            center,
+            y + ROW_HEIGHT - 6.0,
+            escape(&format!("⊢ {}", node.label))
+        ));
+    }
+}
+
+/// The total width of a row of sibling premises laid out side by side with
+/// [`SIBLING_GAP`] between them, or `0` for a leaf (axiom) node.
+fn row_width(premises: &[LayoutNode]) -> f64 {
+    if premises.is_empty() {
+        0.0
+    } else {
+        premises.iter().map(|p| p.width).sum::<f64>() + SIBLING_GAP * (premises.len() as f64 - 1.0)
+    }
+}
+
+/// A measured proof node, ready to be drawn.
+struct LayoutNode {
+    label: String,
+    rule: String,
+    width: f64,
+    depth: usize,
+    premises: Vec<LayoutNode>,
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lolli_core::{Formula, Rule, Sequent};
+
+    #[test]
+    fn test_render_axiom() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+
+        let svg = SvgRenderer::new().render(&proof);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>"));
+        assert!(svg.contains("<text"));
+        assert!(!svg.contains("<line"));
+    }
+
+    #[test]
+    fn test_render_connects_premises_to_conclusion() {
+        let left = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let right = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::tensor(Formula::atom("A"), Formula::atom("B"))]),
+            rule: Rule::TensorIntro,
+            premises: vec![left, right],
+        };
+
+        let svg = SvgRenderer::new().render(&proof);
+
+        assert!(svg.contains("<line"));
+        assert!(svg.contains("TensorIntro"));
+    }
+
+    #[test]
+    fn test_taller_proof_is_taller_than_a_leaf() {
+        let leaf = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let leaf_svg = SvgRenderer::new().render(&leaf);
+
+        let premise = Proof {
+            conclusion: Sequent::new(vec![Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+        let taller = Proof {
+            conclusion: Sequent::new(vec![Formula::of_course(Formula::atom("A"))]),
+            rule: Rule::OfCourseIntro,
+            premises: vec![premise],
+        };
+        let taller_svg = SvgRenderer::new().render(&taller);
+
+        let height_of = |svg: &str| -> f64 {
+            let needle = "height=\"";
+            let start = svg.find(needle).unwrap() + needle.len();
+            svg[start..].split('"').next().unwrap().parse().unwrap()
+        };
+
+        assert!(height_of(&taller_svg) > height_of(&leaf_svg));
+    }
+}
@@ -0,0 +1,394 @@
+//! Structural XML decoding, the inverse of [`crate::encode`].
+//!
+//! Decoding a proof never trusts its input: [`decode_proof_checked`] always
+//! re-runs the result through [`lolli_prove::verify_proof`] and confirms
+//! that re-extracting a term from the decoded proof matches the term that
+//! was encoded alongside it, so a transported proof stays sound.
+
+use std::rc::Rc;
+
+use lolli_core::{FolTerm, Formula, Proof, Rule, Sequent, Term};
+use lolli_extract::extract_term;
+use lolli_prove::{verify_proof, ProofError};
+
+use crate::xml::XmlNode;
+
+/// Error decoding an [`XmlNode`] back into a [`Formula`], [`Term`], [`Rule`],
+/// or [`Proof`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DecodeError {
+    /// The node's tag did not match any known constructor for the type
+    /// being decoded.
+    #[error("unrecognized tag <{0}>")]
+    UnknownTag(String),
+
+    /// A required attribute was missing from a node, or a `<proof>` lacked
+    /// its `conclusion` or `premises` child.
+    #[error("missing attribute or child {attr:?} on <{tag}>")]
+    MissingAttr {
+        /// The element that should have had the attribute or child.
+        tag: String,
+        /// The missing attribute's or child element's name.
+        attr: String,
+    },
+
+    /// A node had the wrong number of children for its tag.
+    #[error("<{tag}> expected {expected} children, got {got}")]
+    WrongChildCount {
+        /// The element with the wrong child count.
+        tag: String,
+        /// The number of children it should have had.
+        expected: usize,
+        /// The number of children it actually had.
+        got: usize,
+    },
+
+    /// Decoding succeeded structurally, but the proof failed verification.
+    #[error("decoded proof failed verification: {0}")]
+    Unsound(#[from] ProofError),
+
+    /// Decoding succeeded, but re-extracting a term from the decoded proof
+    /// did not match the term that was encoded alongside it.
+    #[error("re-extracted term {got:?} does not match encoded term {expected:?}")]
+    TermMismatch {
+        /// The term that was encoded alongside the proof.
+        expected: String,
+        /// The term re-extracted from the decoded proof.
+        got: String,
+    },
+}
+
+fn children(node: &XmlNode, n: usize) -> Result<&[XmlNode], DecodeError> {
+    if node.children.len() != n {
+        return Err(DecodeError::WrongChildCount {
+            tag: node.tag.clone(),
+            expected: n,
+            got: node.children.len(),
+        });
+    }
+    Ok(&node.children)
+}
+
+fn attr(node: &XmlNode, key: &str) -> Result<String, DecodeError> {
+    node.attr(key).map(str::to_string).ok_or_else(|| DecodeError::MissingAttr {
+        tag: node.tag.clone(),
+        attr: key.to_string(),
+    })
+}
+
+/// Decode an [`XmlNode`] back into a [`Formula`].
+pub fn decode_formula(node: &XmlNode) -> Result<Formula, DecodeError> {
+    match node.tag.as_str() {
+        "atom" => Ok(Formula::Atom(attr(node, "name")?)),
+        "neg-atom" => Ok(Formula::NegAtom(attr(node, "name")?)),
+        "predicate" => Ok(Formula::Predicate(attr(node, "name")?, decode_fol_term_list(node)?)),
+        "neg-predicate" => Ok(Formula::NegPredicate(attr(node, "name")?, decode_fol_term_list(node)?)),
+        "forall" => {
+            let [body] = children(node, 1)? else { unreachable!() };
+            Ok(Formula::Forall(attr(node, "var")?, Box::new(decode_formula(body)?)))
+        }
+        "exists" => {
+            let [body] = children(node, 1)? else { unreachable!() };
+            Ok(Formula::Exists(attr(node, "var")?, Box::new(decode_formula(body)?)))
+        }
+        "tensor" => decode_binary_formula(node, Formula::Tensor),
+        "par" => decode_binary_formula(node, Formula::Par),
+        "with" => decode_binary_formula(node, Formula::With),
+        "plus" => decode_binary_formula(node, Formula::Plus),
+        "lolli" => decode_binary_formula(node, Formula::Lolli),
+        "one" => Ok(Formula::One),
+        "bottom" => Ok(Formula::Bottom),
+        "top" => Ok(Formula::Top),
+        "zero" => Ok(Formula::Zero),
+        "of-course" => {
+            let [inner] = children(node, 1)? else { unreachable!() };
+            Ok(Formula::OfCourse(Box::new(decode_formula(inner)?)))
+        }
+        "why-not" => {
+            let [inner] = children(node, 1)? else { unreachable!() };
+            Ok(Formula::WhyNot(Box::new(decode_formula(inner)?)))
+        }
+        other => Err(DecodeError::UnknownTag(other.to_string())),
+    }
+}
+
+fn decode_binary_formula(
+    node: &XmlNode,
+    ctor: fn(Box<Formula>, Box<Formula>) -> Formula,
+) -> Result<Formula, DecodeError> {
+    let [a, b] = children(node, 2)? else { unreachable!() };
+    Ok(ctor(Box::new(decode_formula(a)?), Box::new(decode_formula(b)?)))
+}
+
+fn decode_fol_term_list(node: &XmlNode) -> Result<Vec<FolTerm>, DecodeError> {
+    node.children.iter().map(decode_fol_term).collect()
+}
+
+/// Decode an [`XmlNode`] back into a [`FolTerm`].
+pub fn decode_fol_term(node: &XmlNode) -> Result<FolTerm, DecodeError> {
+    match node.tag.as_str() {
+        "fol-var" => Ok(FolTerm::Var(attr(node, "name")?)),
+        "fol-app" => Ok(FolTerm::App(attr(node, "name")?, decode_fol_term_list(node)?)),
+        other => Err(DecodeError::UnknownTag(other.to_string())),
+    }
+}
+
+/// Decode an [`XmlNode`] back into a [`Term`].
+pub fn decode_term(node: &XmlNode) -> Result<Term, DecodeError> {
+    match node.tag.as_str() {
+        "var" => Ok(Term::Var(attr(node, "name")?)),
+        "unit" => Ok(Term::Unit),
+        "trivial" => Ok(Term::Trivial),
+        "abs" => {
+            let [body] = children(node, 1)? else { unreachable!() };
+            Ok(Term::Abs(attr(node, "var")?, Rc::new(decode_term(body)?)))
+        }
+        "app" => {
+            let [f, arg] = children(node, 2)? else { unreachable!() };
+            Ok(Term::App(Rc::new(decode_term(f)?), Rc::new(decode_term(arg)?)))
+        }
+        "pair" => {
+            let [a, b] = children(node, 2)? else { unreachable!() };
+            Ok(Term::Pair(Rc::new(decode_term(a)?), Rc::new(decode_term(b)?)))
+        }
+        "let-pair" => {
+            let [producer, consumer] = children(node, 2)? else { unreachable!() };
+            Ok(Term::LetPair(
+                attr(node, "x")?,
+                attr(node, "y")?,
+                Rc::new(decode_term(producer)?),
+                Rc::new(decode_term(consumer)?),
+            ))
+        }
+        "inl" => {
+            let [inner] = children(node, 1)? else { unreachable!() };
+            Ok(Term::Inl(Rc::new(decode_term(inner)?)))
+        }
+        "inr" => {
+            let [inner] = children(node, 1)? else { unreachable!() };
+            Ok(Term::Inr(Rc::new(decode_term(inner)?)))
+        }
+        "case" => {
+            let [subject, left, right] = children(node, 3)? else { unreachable!() };
+            Ok(Term::Case(
+                Rc::new(decode_term(subject)?),
+                attr(node, "x")?,
+                Rc::new(decode_term(left)?),
+                attr(node, "y")?,
+                Rc::new(decode_term(right)?),
+            ))
+        }
+        "promote" => {
+            let [inner] = children(node, 1)? else { unreachable!() };
+            Ok(Term::Promote(Rc::new(decode_term(inner)?)))
+        }
+        "derelict" => {
+            let [inner] = children(node, 1)? else { unreachable!() };
+            Ok(Term::Derelict(Rc::new(decode_term(inner)?)))
+        }
+        "copy" => {
+            let [src, body] = children(node, 2)? else { unreachable!() };
+            Ok(Term::Copy(
+                Rc::new(decode_term(src)?),
+                attr(node, "x")?,
+                attr(node, "y")?,
+                Rc::new(decode_term(body)?),
+            ))
+        }
+        "discard" => {
+            let [value, body] = children(node, 2)? else { unreachable!() };
+            Ok(Term::Discard(Rc::new(decode_term(value)?), Rc::new(decode_term(body)?)))
+        }
+        "fst" => {
+            let [pair] = children(node, 1)? else { unreachable!() };
+            Ok(Term::Fst(Rc::new(decode_term(pair)?)))
+        }
+        "snd" => {
+            let [pair] = children(node, 1)? else { unreachable!() };
+            Ok(Term::Snd(Rc::new(decode_term(pair)?)))
+        }
+        "abort" => {
+            let [inner] = children(node, 1)? else { unreachable!() };
+            Ok(Term::Abort(Rc::new(decode_term(inner)?)))
+        }
+        other => Err(DecodeError::UnknownTag(other.to_string())),
+    }
+}
+
+/// Decode an [`XmlNode`] back into a [`Rule`].
+pub fn decode_rule(node: &XmlNode) -> Result<Rule, DecodeError> {
+    match node.tag.as_str() {
+        "axiom" => Ok(Rule::Axiom),
+        "one-intro" => Ok(Rule::OneIntro),
+        "bottom-intro" => Ok(Rule::BottomIntro),
+        "top-intro" => Ok(Rule::TopIntro),
+        "tensor-intro" => Ok(Rule::TensorIntro),
+        "par-intro" => Ok(Rule::ParIntro),
+        "with-intro" => Ok(Rule::WithIntro),
+        "plus-intro-left" => Ok(Rule::PlusIntroLeft),
+        "plus-intro-right" => Ok(Rule::PlusIntroRight),
+        "of-course-intro" => Ok(Rule::OfCourseIntro),
+        "why-not-intro" => Ok(Rule::WhyNotIntro),
+        "weakening" => Ok(Rule::Weakening),
+        "contraction" => Ok(Rule::Contraction),
+        "dereliction" => Ok(Rule::Dereliction),
+        "blur" => Ok(Rule::Blur),
+        "cut" => {
+            let [formula] = children(node, 1)? else { unreachable!() };
+            Ok(Rule::Cut(decode_formula(formula)?))
+        }
+        "focus-positive" => {
+            let [formula] = children(node, 1)? else { unreachable!() };
+            Ok(Rule::FocusPositive(decode_formula(formula)?))
+        }
+        "focus-negative" => {
+            let [formula] = children(node, 1)? else { unreachable!() };
+            Ok(Rule::FocusNegative(decode_formula(formula)?))
+        }
+        other => Err(DecodeError::UnknownTag(other.to_string())),
+    }
+}
+
+/// Decode an [`XmlNode`] back into a [`Proof`], with no re-verification —
+/// use [`decode_proof_checked`] to also validate the result.
+pub fn decode_proof(node: &XmlNode) -> Result<Proof, DecodeError> {
+    let conclusion_node = node.child("conclusion").ok_or_else(|| DecodeError::MissingAttr {
+        tag: node.tag.clone(),
+        attr: "conclusion".to_string(),
+    })?;
+    let rule_node = node.children.get(1).ok_or_else(|| DecodeError::WrongChildCount {
+        tag: node.tag.clone(),
+        expected: 3,
+        got: node.children.len(),
+    })?;
+    let premises_node = node.child("premises").ok_or_else(|| DecodeError::MissingAttr {
+        tag: node.tag.clone(),
+        attr: "premises".to_string(),
+    })?;
+
+    let linear = conclusion_node
+        .children
+        .iter()
+        .map(decode_formula)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let premises = premises_node
+        .children
+        .iter()
+        .map(decode_proof)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Proof {
+        conclusion: Sequent::new(linear),
+        rule: decode_rule(rule_node)?,
+        premises,
+    })
+}
+
+/// Decode an encoded `(Proof, Term)` bundle, re-verifying the proof and
+/// confirming that a fresh extraction from the decoded proof matches the
+/// decoded term, so an imported proof is validated rather than trusted.
+pub fn decode_proof_checked(node: &XmlNode) -> Result<Proof, DecodeError> {
+    let [proof_node, term_node] = children(node, 2)? else { unreachable!() };
+
+    let proof = decode_proof(proof_node)?;
+    verify_proof(&proof)?;
+
+    let expected_term = decode_term(term_node)?;
+    let re_extracted = extract_term(&proof);
+    if re_extracted != expected_term {
+        return Err(DecodeError::TermMismatch {
+            expected: expected_term.pretty(),
+            got: re_extracted.pretty(),
+        });
+    }
+
+    Ok(proof)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode::{encode_formula, encode_proof_with_term, encode_rule, encode_term};
+    use lolli_core::Rule;
+
+    #[test]
+    fn test_formula_round_trips() {
+        let formula = Formula::with(
+            Formula::tensor(Formula::atom("A"), Formula::of_course(Formula::atom("B"))),
+            Formula::plus(Formula::Top, Formula::Zero),
+        );
+
+        let decoded = decode_formula(&encode_formula(&formula)).unwrap();
+        assert_eq!(decoded, formula);
+    }
+
+    #[test]
+    fn test_term_round_trips() {
+        let term = Term::Copy(
+            Rc::new(Term::Var("src".to_string())),
+            "a".to_string(),
+            "b".to_string(),
+            Rc::new(Term::Pair(Rc::new(Term::Var("a".to_string())), Rc::new(Term::Var("b".to_string())))),
+        );
+
+        let decoded = decode_term(&encode_term(&term)).unwrap();
+        assert_eq!(decoded, term);
+    }
+
+    #[test]
+    fn test_rule_round_trips() {
+        let rule = Rule::FocusPositive(Formula::atom("A"));
+        let decoded = decode_rule(&encode_rule(&rule)).unwrap();
+        assert_eq!(decoded, rule);
+    }
+
+    #[test]
+    fn test_unknown_tag_is_rejected() {
+        let bogus = XmlNode::new("not-a-formula");
+        assert!(matches!(decode_formula(&bogus), Err(DecodeError::UnknownTag(_))));
+    }
+
+    #[test]
+    fn test_decode_proof_checked_accepts_valid_bundle() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+
+        let bundle = encode_proof_with_term(&proof);
+        let decoded = decode_proof_checked(&bundle).unwrap();
+        assert_eq!(decoded, proof);
+    }
+
+    #[test]
+    fn test_decode_proof_checked_rejects_tampered_term() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+
+        let mut bundle = encode_proof_with_term(&proof);
+        // Swap in a term that doesn't match what the proof actually extracts to.
+        bundle.children[1] = encode_term(&Term::Unit);
+
+        let result = decode_proof_checked(&bundle);
+        assert!(matches!(result, Err(DecodeError::TermMismatch { .. })));
+    }
+
+    #[test]
+    fn test_decode_proof_checked_rejects_unsound_proof() {
+        // Axiom with mismatched atom names is structurally well-formed XML
+        // but not a valid proof.
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("B")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+
+        let bundle = encode_proof_with_term(&proof);
+        assert!(matches!(decode_proof_checked(&bundle), Err(DecodeError::Unsound(_))));
+    }
+}
@@ -0,0 +1,228 @@
+//! Structural XML encoding of formulas, terms, rules, and proofs.
+
+use lolli_core::{Formula, FolTerm, Proof, Rule, Term};
+use lolli_extract::extract_term;
+
+use crate::xml::XmlNode;
+
+/// Encode a [`Formula`] as an [`XmlNode`] tree, one element per connective.
+pub fn encode_formula(formula: &Formula) -> XmlNode {
+    match formula {
+        Formula::Atom(name) => XmlNode::new("atom").with_attr("name", name.clone()),
+        Formula::NegAtom(name) => XmlNode::new("neg-atom").with_attr("name", name.clone()),
+        Formula::Predicate(name, args) => encode_predicate("predicate", name, args),
+        Formula::NegPredicate(name, args) => encode_predicate("neg-predicate", name, args),
+        Formula::Forall(var, body) => XmlNode::new("forall")
+            .with_attr("var", var.clone())
+            .with_child(encode_formula(body)),
+        Formula::Exists(var, body) => XmlNode::new("exists")
+            .with_attr("var", var.clone())
+            .with_child(encode_formula(body)),
+        Formula::Tensor(a, b) => encode_binary("tensor", a, b),
+        Formula::Par(a, b) => encode_binary("par", a, b),
+        Formula::With(a, b) => encode_binary("with", a, b),
+        Formula::Plus(a, b) => encode_binary("plus", a, b),
+        Formula::Lolli(a, b) => encode_binary("lolli", a, b),
+        Formula::One => XmlNode::new("one"),
+        Formula::Bottom => XmlNode::new("bottom"),
+        Formula::Top => XmlNode::new("top"),
+        Formula::Zero => XmlNode::new("zero"),
+        Formula::OfCourse(a) => XmlNode::new("of-course").with_child(encode_formula(a)),
+        Formula::WhyNot(a) => XmlNode::new("why-not").with_child(encode_formula(a)),
+    }
+}
+
+fn encode_binary(tag: &str, a: &Formula, b: &Formula) -> XmlNode {
+    XmlNode::new(tag)
+        .with_child(encode_formula(a))
+        .with_child(encode_formula(b))
+}
+
+fn encode_predicate(tag: &str, name: &str, args: &[FolTerm]) -> XmlNode {
+    let mut node = XmlNode::new(tag).with_attr("name", name.to_string());
+    for arg in args {
+        node = node.with_child(encode_fol_term(arg));
+    }
+    node
+}
+
+/// Encode a first-order [`FolTerm`] as an [`XmlNode`].
+pub fn encode_fol_term(term: &FolTerm) -> XmlNode {
+    match term {
+        FolTerm::Var(name) => XmlNode::new("fol-var").with_attr("name", name.clone()),
+        FolTerm::App(name, args) => {
+            let mut node = XmlNode::new("fol-app").with_attr("name", name.clone());
+            for arg in args {
+                node = node.with_child(encode_fol_term(arg));
+            }
+            node
+        }
+    }
+}
+
+/// Encode a [`Term`] as an [`XmlNode`] tree, one element per constructor.
+pub fn encode_term(term: &Term) -> XmlNode {
+    match term {
+        Term::Var(name) => XmlNode::new("var").with_attr("name", name.clone()),
+        Term::Unit => XmlNode::new("unit"),
+        Term::Trivial => XmlNode::new("trivial"),
+        Term::Abs(var, body) => XmlNode::new("abs")
+            .with_attr("var", var.clone())
+            .with_child(encode_term(body)),
+        Term::App(f, arg) => XmlNode::new("app")
+            .with_child(encode_term(f))
+            .with_child(encode_term(arg)),
+        Term::Pair(a, b) => XmlNode::new("pair")
+            .with_child(encode_term(a))
+            .with_child(encode_term(b)),
+        Term::LetPair(x, y, producer, consumer) => XmlNode::new("let-pair")
+            .with_attr("x", x.clone())
+            .with_attr("y", y.clone())
+            .with_child(encode_term(producer))
+            .with_child(encode_term(consumer)),
+        Term::Inl(inner) => XmlNode::new("inl").with_child(encode_term(inner)),
+        Term::Inr(inner) => XmlNode::new("inr").with_child(encode_term(inner)),
+        Term::Case(subject, x, left, y, right) => XmlNode::new("case")
+            .with_attr("x", x.clone())
+            .with_attr("y", y.clone())
+            .with_child(encode_term(subject))
+            .with_child(encode_term(left))
+            .with_child(encode_term(right)),
+        Term::Promote(inner) => XmlNode::new("promote").with_child(encode_term(inner)),
+        Term::Derelict(inner) => XmlNode::new("derelict").with_child(encode_term(inner)),
+        Term::Copy(src, x, y, body) => XmlNode::new("copy")
+            .with_attr("x", x.clone())
+            .with_attr("y", y.clone())
+            .with_child(encode_term(src))
+            .with_child(encode_term(body)),
+        Term::Discard(value, body) => XmlNode::new("discard")
+            .with_child(encode_term(value))
+            .with_child(encode_term(body)),
+        Term::Fst(pair) => XmlNode::new("fst").with_child(encode_term(pair)),
+        Term::Snd(pair) => XmlNode::new("snd").with_child(encode_term(pair)),
+        Term::Abort(inner) => XmlNode::new("abort").with_child(encode_term(inner)),
+    }
+}
+
+/// Encode a [`Rule`] as an [`XmlNode`], including the cut/focus formula for
+/// the rules that carry one.
+pub fn encode_rule(rule: &Rule) -> XmlNode {
+    match rule {
+        Rule::Axiom => XmlNode::new("axiom"),
+        Rule::OneIntro => XmlNode::new("one-intro"),
+        Rule::BottomIntro => XmlNode::new("bottom-intro"),
+        Rule::TopIntro => XmlNode::new("top-intro"),
+        Rule::TensorIntro => XmlNode::new("tensor-intro"),
+        Rule::ParIntro => XmlNode::new("par-intro"),
+        Rule::WithIntro => XmlNode::new("with-intro"),
+        Rule::PlusIntroLeft => XmlNode::new("plus-intro-left"),
+        Rule::PlusIntroRight => XmlNode::new("plus-intro-right"),
+        Rule::OfCourseIntro => XmlNode::new("of-course-intro"),
+        Rule::WhyNotIntro => XmlNode::new("why-not-intro"),
+        Rule::Weakening => XmlNode::new("weakening"),
+        Rule::Contraction => XmlNode::new("contraction"),
+        Rule::Dereliction => XmlNode::new("dereliction"),
+        Rule::Blur => XmlNode::new("blur"),
+        Rule::Cut(formula) => XmlNode::new("cut").with_child(encode_formula(formula)),
+        Rule::FocusPositive(formula) => XmlNode::new("focus-positive").with_child(encode_formula(formula)),
+        Rule::FocusNegative(formula) => XmlNode::new("focus-negative").with_child(encode_formula(formula)),
+    }
+}
+
+/// Encode a [`Proof`], including its conclusion, rule, and premises, as an
+/// [`XmlNode`] tree.
+pub fn encode_proof(proof: &Proof) -> XmlNode {
+    let conclusion = proof
+        .conclusion
+        .linear
+        .iter()
+        .fold(XmlNode::new("conclusion"), |node, formula| node.with_child(encode_formula(formula)));
+
+    let premises = proof
+        .premises
+        .iter()
+        .fold(XmlNode::new("premises"), |node, premise| node.with_child(encode_proof(premise)));
+
+    XmlNode::new("proof")
+        .with_child(conclusion)
+        .with_child(encode_rule(&proof.rule))
+        .with_child(premises)
+}
+
+/// Encode `proof` together with its extracted term, so the bundle can later
+/// be decoded with [`crate::decode::decode_proof_checked`], which confirms
+/// that re-extracting from the decoded proof yields this same term.
+pub fn encode_proof_with_term(proof: &Proof) -> XmlNode {
+    let term = extract_term(proof);
+    XmlNode::new("proof-bundle")
+        .with_child(encode_proof(proof))
+        .with_child(encode_term(&term))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lolli_core::Sequent;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_encode_formula_tensor() {
+        let formula = Formula::tensor(Formula::atom("A"), Formula::atom("B"));
+        let node = encode_formula(&formula);
+
+        assert_eq!(node.tag, "tensor");
+        assert_eq!(node.children[0].tag, "atom");
+        assert_eq!(node.children[0].attr("name"), Some("A"));
+    }
+
+    #[test]
+    fn test_encode_predicate_with_fol_terms() {
+        let formula = Formula::predicate("P", vec![FolTerm::var("x"), FolTerm::app("f", vec![FolTerm::var("y")])]);
+        let node = encode_formula(&formula);
+
+        assert_eq!(node.tag, "predicate");
+        assert_eq!(node.attr("name"), Some("P"));
+        assert_eq!(node.children[1].tag, "fol-app");
+    }
+
+    #[test]
+    fn test_encode_term_case() {
+        let term = Term::Case(
+            Rc::new(Term::Inl(Rc::new(Term::Unit))),
+            "x".to_string(),
+            Rc::new(Term::Var("x".to_string())),
+            "y".to_string(),
+            Rc::new(Term::Var("y".to_string())),
+        );
+
+        let node = encode_term(&term);
+        assert_eq!(node.tag, "case");
+        assert_eq!(node.attr("x"), Some("x"));
+        assert_eq!(node.children[0].tag, "inl");
+    }
+
+    #[test]
+    fn test_encode_rule_cut_carries_formula() {
+        let node = encode_rule(&Rule::Cut(Formula::atom("A")));
+        assert_eq!(node.tag, "cut");
+        assert_eq!(node.children[0].tag, "atom");
+    }
+
+    #[test]
+    fn test_encode_proof_with_term_bundles_both() {
+        let proof = Proof {
+            conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+            rule: Rule::Axiom,
+            premises: vec![],
+        };
+
+        let bundle = encode_proof_with_term(&proof);
+        assert_eq!(bundle.tag, "proof-bundle");
+        assert!(bundle.child("proof").is_some());
+        assert!(bundle.child("term").is_none());
+        // The term node's tag is the constructor's own name, not "term" — it
+        // is the bundle's second child. An axiom on `⊢ A⊥, A` extracts to
+        // the variable `a`.
+        assert_eq!(bundle.children[1].tag, "var");
+    }
+}
@@ -0,0 +1,41 @@
+//! # lolli-xml
+//!
+//! XML import/export for the Lolli linear logic workbench.
+//!
+//! This crate serializes proofs and their extracted terms to a structural
+//! XML representation, mirroring Isabelle's `Proofterm.encode`/`decode`
+//! proof-term format, so they can be persisted and exchanged. Decoding
+//! never trusts its input: [`decode_proof_checked`] re-runs the decoded
+//! proof through [`lolli_prove::verify_proof`] and confirms that
+//! re-extracting a term from it matches the term that was encoded
+//! alongside it.
+//!
+//! ## Example
+//!
+//! ```
+//! use lolli_xml::{encode_proof_with_term, decode_proof_checked};
+//! use lolli_core::{Formula, Proof, Rule, Sequent};
+//!
+//! let proof = Proof {
+//!     conclusion: Sequent::new(vec![Formula::neg_atom("A"), Formula::atom("A")]),
+//!     rule: Rule::Axiom,
+//!     premises: vec![],
+//! };
+//!
+//! let bundle = encode_proof_with_term(&proof);
+//! let decoded = decode_proof_checked(&bundle).expect("sound, round-trippable proof");
+//! assert_eq!(decoded, proof);
+//! ```
+
+#![warn(missing_docs)]
+#![warn(clippy::all)]
+
+mod decode;
+mod encode;
+mod xml;
+
+pub use decode::{
+    decode_formula, decode_fol_term, decode_proof, decode_proof_checked, decode_rule, decode_term, DecodeError,
+};
+pub use encode::{encode_fol_term, encode_formula, encode_proof, encode_proof_with_term, encode_rule, encode_term};
+pub use xml::XmlNode;
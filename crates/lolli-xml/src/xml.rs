@@ -0,0 +1,112 @@
+//! A minimal XML tree, general enough to represent both proofs and terms.
+
+/// A single XML element: a tag, its attributes, and its children.
+///
+/// This is a plain data tree, not a full XML parser/writer — it's only
+/// meant to carry the structured encodings in this crate, not arbitrary
+/// documents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XmlNode {
+    /// Element tag name.
+    pub tag: String,
+    /// Attribute name/value pairs, in insertion order.
+    pub attrs: Vec<(String, String)>,
+    /// Child nodes, in document order.
+    pub children: Vec<XmlNode>,
+}
+
+impl XmlNode {
+    /// Create a childless, attribute-less node.
+    pub fn new(tag: impl Into<String>) -> Self {
+        Self {
+            tag: tag.into(),
+            attrs: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Attach an attribute, builder-style.
+    pub fn with_attr(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attrs.push((key.into(), value.into()));
+        self
+    }
+
+    /// Attach a child node, builder-style.
+    pub fn with_child(mut self, child: XmlNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// The value of an attribute, if present.
+    pub fn attr(&self, key: &str) -> Option<&str> {
+        self.attrs.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    /// The single child named `tag`, if there is exactly one.
+    pub fn child(&self, tag: &str) -> Option<&XmlNode> {
+        let mut matches = self.children.iter().filter(|c| c.tag == tag);
+        let first = matches.next()?;
+        if matches.next().is_some() {
+            None
+        } else {
+            Some(first)
+        }
+    }
+
+    /// Render as an indented XML string, for display or storage.
+    pub fn to_xml_string(&self) -> String {
+        let mut out = String::new();
+        self.write(&mut out, 0);
+        out
+    }
+
+    fn write(&self, out: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth);
+        out.push_str(&indent);
+        out.push('<');
+        out.push_str(&self.tag);
+        for (key, value) in &self.attrs {
+            out.push_str(&format!(" {}=\"{}\"", key, value));
+        }
+        if self.children.is_empty() {
+            out.push_str("/>\n");
+        } else {
+            out.push_str(">\n");
+            for child in &self.children {
+                child.write(out, depth + 1);
+            }
+            out.push_str(&indent);
+            out.push_str("</");
+            out.push_str(&self.tag);
+            out.push_str(">\n");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_and_lookup() {
+        let node = XmlNode::new("atom")
+            .with_attr("name", "A")
+            .with_child(XmlNode::new("child"));
+
+        assert_eq!(node.attr("name"), Some("A"));
+        assert!(node.child("child").is_some());
+        assert_eq!(node.attr("missing"), None);
+    }
+
+    #[test]
+    fn test_to_xml_string() {
+        let node = XmlNode::new("tensor")
+            .with_child(XmlNode::new("atom").with_attr("name", "A"))
+            .with_child(XmlNode::new("atom").with_attr("name", "B"));
+
+        let xml = node.to_xml_string();
+        assert!(xml.starts_with("<tensor>"));
+        assert!(xml.contains("<atom name=\"A\"/>"));
+        assert!(xml.trim_end().ends_with("</tensor>"));
+    }
+}